@@ -18,11 +18,16 @@ impl Default for Cli {
 
 impl Cli {
     pub async fn exec(self) -> miette::Result<()> {
+        // Completions only need the command tree, not the database.
+        if let Some(cmd::Cmd::Completions(args)) = self.cmd {
+            return args.exec();
+        }
+
         let services = Services::bootstrap().await?;
 
         match self.cmd {
             Some(cmd) => cmd.exec(&services).await,
-            None => crate::tui::run(services).await,
+            None => crate::tui::run(services, None).await,
         }
     }
 }
@@ -1,5 +1,11 @@
-use crate::service::Services;
-use miette::bail;
+use std::io::BufRead;
+
+use crate::service::{
+    Services,
+    recurrence::Recurrence,
+    todo::{MovePlacement, NewTodo},
+};
+use miette::{IntoDiagnostic, bail};
 use uuid::Uuid;
 
 /// Add a new todo
@@ -17,8 +23,32 @@ pub struct Args {
     #[clap(short, long)]
     project: Option<String>,
 
+    /// Template to expand (see `mach templates`)
+    #[clap(short, long)]
+    template: Option<String>,
+
+    /// Link to an issue, doc or page
+    #[clap(long)]
+    url: Option<String>,
+
+    /// Reminder time (YYYY-MM-DD HH:MM or an offset like +2h)
+    #[clap(long)]
+    remind: Option<String>,
+
+    /// Tag the todo (repeatable; `#` optional)
+    #[clap(long = "tag")]
+    tags: Vec<String>,
+
+    /// Repeat once done: daily, weekly, weekdays or every:<n>d / every:<n>w
+    #[clap(long, conflicts_with = "some_day")]
+    repeat: Option<String>,
+
+    /// Read titles from stdin, one per line
+    #[clap(long, default_value = "false", conflicts_with = "title")]
+    stdin: bool,
+
     /// Title of the todo (quoted or space separated)
-    #[clap(required = true)]
+    #[clap(required_unless_present = "stdin")]
     title: Vec<String>,
 }
 
@@ -30,19 +60,94 @@ impl Args {
             Some(services.today())
         };
 
+        let template = match &self.template {
+            Some(name) => Some(
+                services
+                    .config
+                    .load_template(name)
+                    .await?
+                    .ok_or_else(|| miette::miette!("template '{}' not found", name))?,
+            ),
+            None => None,
+        };
+
+        let template = template.unwrap_or_default();
+
+        // An explicit --project wins over the template's default.
+        let project_arg = self.project.as_deref().or(template.project.as_deref());
+
         let (workspace_id, project_id) =
-            resolve_workspace_project(services, self.workspace.as_deref(), self.project.as_deref())
-                .await?;
+            resolve_workspace_project(services, self.workspace.as_deref(), project_arg).await?;
 
-        let todo = services
-            .todos
-            .add(self.title(), scheduled_for, None, workspace_id, project_id)
-            .await?;
+        let remind_at = match &self.remind {
+            Some(remind) => super::reminders::parse_remind_at(remind)?,
+            None => None,
+        };
+
+        let recurrence = self.repeat.as_deref().map(Recurrence::parse).transpose()?;
 
         let date_label = scheduled_for
             .map(|d| d.to_string())
             .unwrap_or_else(|| "Someday".into());
 
+        if self.stdin {
+            let titles = read_stdin_titles()?;
+
+            for title in &titles {
+                let todo = services
+                    .todos
+                    .add_with_placement(
+                        &template.expand_title(title),
+                        scheduled_for,
+                        MovePlacement::Bottom,
+                        NewTodo {
+                            notes: template.notes.clone(),
+                            workspace_id,
+                            project_id,
+                            recurrence,
+                            url: self.url.clone(),
+                        },
+                    )
+                    .await?;
+
+                if remind_at.is_some() {
+                    services.todos.update_remind_at(todo.id, remind_at).await?;
+                }
+
+                if !self.tags.is_empty() {
+                    services.todos.set_tags(todo.id, &self.tags).await?;
+                }
+            }
+
+            println!("Added {} todos -> {}", titles.len(), date_label);
+
+            return Ok(());
+        }
+
+        let todo = services
+            .todos
+            .add_with_placement(
+                template.expand_title(&self.title()),
+                scheduled_for,
+                MovePlacement::Top,
+                NewTodo {
+                    notes: template.notes.clone(),
+                    workspace_id,
+                    project_id,
+                    recurrence,
+                    url: self.url.clone(),
+                },
+            )
+            .await?;
+
+        if remind_at.is_some() {
+            services.todos.update_remind_at(todo.id, remind_at).await?;
+        }
+
+        if !self.tags.is_empty() {
+            services.todos.set_tags(todo.id, &self.tags).await?;
+        }
+
         println!("Added todo '{}' -> {}", todo.title, date_label);
 
         Ok(())
@@ -53,6 +158,22 @@ impl Args {
     }
 }
 
+/// Collect non-blank lines from stdin as todo titles, in input order.
+fn read_stdin_titles() -> miette::Result<Vec<String>> {
+    let mut titles = Vec::new();
+
+    for line in std::io::stdin().lock().lines() {
+        let line = line.into_diagnostic()?;
+        let title = line.trim();
+
+        if !title.is_empty() {
+            titles.push(title.to_string());
+        }
+    }
+
+    Ok(titles)
+}
+
 async fn resolve_workspace_project(
     services: &Services,
     workspace_arg: Option<&str>,
@@ -0,0 +1,51 @@
+use crate::service::{Services, config::ARCHIVE_LIST_SCOPE};
+
+/// Archive a todo, or list archived todos
+#[derive(clap::Args)]
+pub struct Args {
+    /// List archived todos, most recently archived first
+    #[clap(short, long, default_value = "false", conflicts_with = "reference")]
+    list: bool,
+
+    /// Include the id column when listing
+    #[clap(short, long, default_value = "false", requires = "list")]
+    id: bool,
+
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Todo id or title
+    #[clap(required_unless_present = "list")]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        if self.list {
+            let todos = services.todos.list_archived().await?;
+
+            if todos.is_empty() {
+                println!("No archived todos.");
+
+                return Ok(());
+            }
+
+            return super::list::print_table(services, todos, ARCHIVE_LIST_SCOPE, self.id, false)
+                .await;
+        }
+
+        let reference = self.reference.join(" ");
+
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
+
+        let archived = services.todos.archive(todo.id).await?;
+
+        println!(
+            "Archived '{}' (bring it back with `mach restore {}`)",
+            archived.title, archived.id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use crate::Cli;
+
+/// Print a shell completion script
+#[derive(clap::Args)]
+pub struct Args {
+    /// Shell to generate completions for
+    #[clap(value_enum)]
+    shell: Shell,
+}
+
+impl Args {
+    pub fn exec(self) -> miette::Result<()> {
+        let mut command = Cli::command();
+
+        clap_complete::generate(self.shell, &mut command, "mach", &mut std::io::stdout());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{Parser, ValueEnum};
+
+    use super::*;
+
+    #[test]
+    fn the_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn every_shell_gets_a_script() {
+        for shell in Shell::value_variants() {
+            let mut script = Vec::new();
+
+            clap_complete::generate(*shell, &mut Cli::command(), "mach", &mut script);
+
+            let script = String::from_utf8(script).unwrap();
+
+            assert!(script.contains("mach"), "{shell}");
+        }
+    }
+
+    #[test]
+    fn unknown_shells_are_refused() {
+        assert!(Cli::try_parse_from(["mach", "completions", "bash"]).is_ok());
+        assert!(Cli::try_parse_from(["mach", "completions", "tcsh"]).is_err());
+    }
+}
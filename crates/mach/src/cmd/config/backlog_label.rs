@@ -0,0 +1,35 @@
+use crate::service::Services;
+
+/// Title a backlog column
+#[derive(clap::Args)]
+pub struct Args {
+    /// Backlog column (1-4)
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=4))]
+    column: u8,
+
+    /// Title shown above the column; "" goes back to `Column N`
+    label: String,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let col = self.column as usize - 1;
+
+        services.config.save_backlog_label(col, &self.label).await?;
+
+        if self.label.trim().is_empty() {
+            println!(
+                "Reset backlog column {} to 'Column {}'",
+                self.column, self.column
+            );
+        } else {
+            println!(
+                "Titled backlog column {} '{}'",
+                self.column,
+                self.label.trim()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use crate::service::Services;
+
+/// Print a setting's stored value
+#[derive(clap::Args)]
+pub struct Args {
+    /// Setting key, e.g. week_start or confirm_deletes
+    key: String,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        match services.config.get(&self.key).await? {
+            Some(value) => println!("{value}"),
+            None => miette::bail!("'{}' is not set", self.key),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+pub mod backlog_label;
+pub mod get;
+pub mod set;
+
+/// Read and write raw settings
+#[derive(clap::Subcommand)]
+pub enum Cmd {
+    /// Print a setting's stored value
+    Get(get::Args),
+    /// Store a setting
+    Set(set::Args),
+    /// Title a backlog column
+    SetBacklogLabel(backlog_label::Args),
+}
+
+impl Cmd {
+    pub async fn exec(self, services: &crate::service::Services) -> miette::Result<()> {
+        match self {
+            Cmd::Get(args) => args.exec(services).await,
+            Cmd::Set(args) => args.exec(services).await,
+            Cmd::SetBacklogLabel(args) => args.exec(services).await,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::service::Services;
+
+/// Store a setting
+#[derive(clap::Args)]
+pub struct Args {
+    /// Setting key, e.g. week_start or confirm_deletes
+    key: String,
+
+    /// Value; JSON like `true` or `20` is stored as that type, anything else
+    /// as text
+    value: String,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        services.config.set(&self.key, &self.value).await?;
+
+        println!("Set '{}'", self.key);
+
+        Ok(())
+    }
+}
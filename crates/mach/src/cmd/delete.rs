@@ -1,8 +1,12 @@
 use crate::service::Services;
 
-/// Delete a todo
+/// Delete a todo permanently
 #[derive(clap::Args)]
 pub struct Args {
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
     /// Todo id or title
     #[clap(required = true)]
     reference: Vec<String>,
@@ -12,11 +16,7 @@ impl Args {
     pub async fn exec(self, services: &Services) -> miette::Result<()> {
         let reference = self.reference.join(" ");
 
-        let todo = services
-            .todos
-            .find_by_title_or_id(&reference)
-            .await?
-            .ok_or_else(|| miette::miette!("todo '{}' not found", reference))?;
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
 
         let title = todo.title.clone();
 
@@ -0,0 +1,32 @@
+use crate::service::Services;
+
+/// Copy a todo into a new pending one
+#[derive(clap::Args)]
+pub struct Args {
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Todo id or title
+    #[clap(required = true)]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let reference = self.reference.join(" ");
+
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
+
+        let copy = services.todos.duplicate(todo.id).await?;
+
+        let place = copy
+            .scheduled_for
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "the backlog".to_string());
+
+        println!("Duplicated '{}' on {} ({})", copy.title, place, copy.id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::service::{Services, todo::rolled_over_from};
+
+/// Show why a todo sits where it does
+#[derive(clap::Args)]
+pub struct Args {
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Todo id or title
+    #[clap(required = true)]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let reference = self.reference.join(" ");
+
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
+
+        let (position, total) = services.todos.column_position(&todo).await?;
+
+        let scope = match todo.scheduled_for {
+            Some(date) => date.format("%a %Y-%m-%d").to_string(),
+            None => format!("backlog (column {})", todo.backlog_column + 1),
+        };
+
+        let rolled_over = match rolled_over_from(&todo) {
+            Some(from) => format!("yes, originally {}", from.format("%a %Y-%m-%d")),
+            None => "no".to_string(),
+        };
+
+        println!("{} ({})", todo.title, todo.id);
+        println!("  scope:       {scope}");
+        println!("  status:      {}", todo.status);
+        println!("  order_index: {}", todo.order_index);
+        println!(
+            "  position:    {} of {total} {}",
+            ordinal(position),
+            todo.status
+        );
+        println!("  rolled over: {rolled_over}");
+
+        if let Some(at) = todo.completed_at {
+            println!(
+                "  completed:   {}",
+                at.with_timezone(&chrono::Local).format("%a %Y-%m-%d %H:%M")
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// `1` -> `1st`, `12` -> `12th`, `23` -> `23rd`.
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{n}{suffix}")
+}
@@ -0,0 +1,138 @@
+use std::path::PathBuf;
+
+use miette::IntoDiagnostic;
+
+use crate::entity::todo;
+use crate::service::Services;
+
+use super::stats::csv_row;
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// Export todos for backup or analysis
+#[derive(clap::Args)]
+pub struct Args {
+    /// Output format
+    #[clap(short, long, value_enum, default_value = "json")]
+    format: ExportFormat,
+
+    /// Only todos in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Include completed todos
+    #[clap(short, long, default_value = "false")]
+    done: bool,
+
+    /// Write to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let workspace = match self.scope.as_deref() {
+            Some(ws) => Some(
+                services
+                    .workspaces
+                    .find_by_name_or_id(ws)
+                    .await?
+                    .ok_or_else(|| miette::miette!("workspace '{}' not found", ws))?
+                    .id,
+            ),
+            None => None,
+        };
+
+        let todos = services.todos.list_all(self.done, workspace).await?;
+
+        let content = match self.format {
+            ExportFormat::Json => {
+                let mut json = serde_json::to_string_pretty(&todos).into_diagnostic()?;
+                json.push('\n');
+                json
+            }
+            ExportFormat::Csv => to_csv(&todos),
+        };
+
+        match &self.output {
+            Some(path) => {
+                std::fs::write(path, content).into_diagnostic()?;
+
+                eprintln!("Exported {} todos to {}", todos.len(), path.display());
+            }
+            None => print!("{content}"),
+        }
+
+        Ok(())
+    }
+}
+
+const CSV_HEADER: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "scheduled_for",
+    "order_index",
+    "backlog_column",
+    "notes",
+    "url",
+    "workspace_id",
+    "project_id",
+    "priority",
+    "tags",
+    "recurrence",
+    "remind_at",
+    "metadata",
+    "created_at",
+    "updated_at",
+    "completed_at",
+    "archived_at",
+];
+
+fn to_csv(todos: &[todo::Model]) -> String {
+    let header: Vec<String> = CSV_HEADER.iter().map(|h| h.to_string()).collect();
+
+    let mut out = csv_row(&header);
+    out.push('\n');
+
+    for todo in todos {
+        let row = [
+            todo.id.to_string(),
+            todo.title.clone(),
+            todo.status.clone(),
+            optional(todo.scheduled_for),
+            todo.order_index.to_string(),
+            todo.backlog_column.to_string(),
+            todo.notes.clone().unwrap_or_default(),
+            todo.url.clone().unwrap_or_default(),
+            optional(todo.workspace_id),
+            optional(todo.project_id),
+            todo.priority.to_string(),
+            todo.tags.clone().unwrap_or_default(),
+            todo.recurrence.clone().unwrap_or_default(),
+            optional(todo.remind_at.map(|at| at.to_rfc3339())),
+            if todo.metadata.is_null() {
+                String::new()
+            } else {
+                todo.metadata.to_string()
+            },
+            todo.created_at.to_rfc3339(),
+            todo.updated_at.to_rfc3339(),
+            optional(todo.completed_at.map(|at| at.to_rfc3339())),
+            optional(todo.archived_at.map(|at| at.to_rfc3339())),
+        ];
+
+        out.push_str(&csv_row(&row));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn optional(value: Option<impl ToString>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
@@ -0,0 +1,70 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use miette::IntoDiagnostic;
+
+use crate::entity::todo;
+use crate::service::{Services, todo::ImportMode};
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum ImportFormat {
+    Json,
+}
+
+/// Restore todos from `mach export` output
+#[derive(clap::Args)]
+pub struct Args {
+    /// File to read; stdin when omitted
+    file: Option<PathBuf>,
+
+    /// Input format
+    #[clap(short, long, value_enum, default_value = "json")]
+    format: ImportFormat,
+
+    /// Keep existing todos and skip ones whose id already exists (default)
+    #[clap(long, default_value = "false", conflicts_with = "replace")]
+    merge: bool,
+
+    /// Delete all existing todos before importing
+    #[clap(long, default_value = "false")]
+    replace: bool,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let content = match &self.file {
+            Some(path) => std::fs::read_to_string(path).into_diagnostic()?,
+            None => {
+                let mut buf = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buf)
+                    .into_diagnostic()?;
+                buf
+            }
+        };
+
+        let todos: Vec<todo::Model> = match self.format {
+            ImportFormat::Json => serde_json::from_str(&content)
+                .map_err(|err| miette::miette!("not a mach export: {err}"))?,
+        };
+
+        // --merge and --replace conflict, and merging is the default.
+        let mode = match (self.merge, self.replace) {
+            (false, true) => ImportMode::Replace,
+            _ => ImportMode::Merge,
+        };
+
+        let summary = services.todos.import(todos, mode).await?;
+
+        if summary.skipped > 0 {
+            println!(
+                "Imported {} todos, skipped {} already present",
+                summary.imported, summary.skipped
+            );
+        } else {
+            println!("Imported {} todos", summary.imported);
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,21 @@
+use chrono::{Duration as ChronoDuration, NaiveDate};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::entity::todo;
 use crate::service::{
     Services,
-    todo::{ListOptions, ListScope},
+    todo::{ListOptions, ListScope, ProjectFilter, SortKey, TagFilter, normalize_tags, tags},
 };
 
+use super::parse_date;
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum ListSort {
+    Manual,
+    Priority,
+}
+
 /// List all todos in a table
 #[derive(clap::Args)]
 pub struct Args {
@@ -14,25 +27,131 @@ pub struct Args {
     #[clap(short, long, default_value = "false")]
     done: bool,
 
+    /// Only todos completed today
+    #[clap(long, default_value = "false", conflicts_with_all = ["some_day", "done"])]
+    completed_today: bool,
+
+    /// List todos scheduled from this day on (YYYY-MM-DD, `tomorrow`, `fri`,
+    /// `+3`, ...; defaults to today)
+    #[clap(long, allow_hyphen_values = true, conflicts_with_all = ["some_day", "completed_today"])]
+    from: Option<String>,
+
+    /// List todos scheduled up to and including this day (same forms as
+    /// --from; defaults to a week after --from)
+    #[clap(long, allow_hyphen_values = true, conflicts_with_all = ["some_day", "completed_today"])]
+    to: Option<String>,
+
     /// Include the id column
     #[clap(short, long, default_value = "false")]
     id: bool,
+
+    /// Print the todos as a JSON array instead of a table (ids included)
+    #[clap(long, default_value = "false")]
+    json: bool,
+
+    /// Show each todo's notes under its row
+    #[clap(short, long, default_value = "false")]
+    notes: bool,
+
+    /// Only todos in this project (name or UUID); "" or "none" for todos
+    /// without one
+    #[clap(short, long)]
+    project: Option<String>,
+
+    /// Only todos with this tag (`#` optional, any case)
+    #[clap(long)]
+    tag: Option<String>,
+
+    /// Order within each group: column order or most urgent first
+    #[clap(long, value_enum, default_value = "manual")]
+    sort: ListSort,
 }
 
 impl Args {
     pub async fn exec(self, services: &Services) -> miette::Result<()> {
         let scope = if self.some_day {
             ListScope::Backlog
+        } else if self.from.is_some() || self.to.is_some() {
+            let from = match &self.from {
+                Some(s) => parse_date(s, services)?,
+                None => services.today(),
+            };
+
+            let to = match &self.to {
+                Some(s) => parse_date(s, services)?,
+                None => from + ChronoDuration::days(6),
+            };
+
+            ListScope::Range(from, to)
         } else {
             ListScope::Day(services.today())
         };
 
+        // Each kind of listing keeps its own numbering; see `resolve_todo`.
+        let list_scope = if self.completed_today {
+            format!("done:{}", services.today())
+        } else {
+            match scope {
+                ListScope::Backlog => "backlog".to_string(),
+                ListScope::Day(day) => day.to_string(),
+                ListScope::Range(from, to) => format!("{from}..{to}"),
+            }
+        };
+
+        let project = match self.project.as_deref() {
+            None => ProjectFilter::Any,
+            Some(input) if ProjectFilter::is_no_project(input) => ProjectFilter::IsNull,
+            Some(input) => {
+                let project = services
+                    .projects
+                    .find_by_name_or_id(input)
+                    .await?
+                    .ok_or_else(|| miette::miette!("project '{}' not found", input))?;
+
+                ProjectFilter::Equals(project.id)
+            }
+        };
+
+        let tag = match self.tag.as_deref().map(|tag| normalize_tags(&[tag])) {
+            None => TagFilter::Any,
+            Some(tags) => match tags.as_slice() {
+                [tag] => TagFilter::Has(tag.clone()),
+                _ => miette::bail!("--tag takes a single tag, e.g. --tag urgent"),
+            },
+        };
+
         let opts = ListOptions {
             scope,
             include_done: self.done,
+            project,
+            tag,
+            sort: match self.sort {
+                ListSort::Manual => SortKey::Manual,
+                ListSort::Priority => SortKey::Priority,
+            },
         };
 
-        let todos = services.todos.list(opts).await?;
+        let todos = if self.completed_today {
+            let mut todos = services.todos.completed_on(services.today()).await?;
+
+            if let ProjectFilter::Equals(id) = opts.project {
+                todos.retain(|todo| todo.project_id == Some(id));
+            } else if opts.project == ProjectFilter::IsNull {
+                todos.retain(|todo| todo.project_id.is_none());
+            }
+
+            if let TagFilter::Has(tag) = &opts.tag {
+                todos.retain(|todo| tags(todo).contains(tag));
+            }
+
+            todos
+        } else {
+            services.todos.list(opts).await?
+        };
+
+        if self.json {
+            return print_json(services, todos, &list_scope).await;
+        }
 
         if todos.is_empty() {
             println!("No todos found.");
@@ -40,65 +159,171 @@ impl Args {
             return Ok(());
         }
 
-        if self.id {
+        print_table(services, todos, &list_scope, self.id, self.notes).await
+    }
+}
+
+/// One todo in `mach list --json`: the table's columns plus the notes.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListItem {
+    id: Uuid,
+    title: String,
+    status: &'static str,
+    workspace: Option<String>,
+    project: Option<String>,
+    /// `null` for backlog todos.
+    scheduled_for: Option<NaiveDate>,
+    notes: Option<String>,
+}
+
+/// Print `todos` as a JSON array, numbered for `mach done 3` and friends
+/// like the table.
+async fn print_json(
+    services: &Services,
+    todos: Vec<todo::Model>,
+    scope: &str,
+) -> miette::Result<()> {
+    let ids: Vec<_> = todos.iter().map(|todo| todo.id).collect();
+
+    services.config.save_last_list(scope, &ids).await?;
+
+    let mut items = Vec::with_capacity(todos.len());
+
+    for todo in todos {
+        let (workspace, project) = scope_names(services, &todo).await?;
+
+        items.push(ListItem {
+            id: todo.id,
+            title: todo.title,
+            status: status_label(&todo.status),
+            workspace,
+            project,
+            scheduled_for: todo.scheduled_for,
+            notes: todo.notes.filter(|notes| !notes.trim().is_empty()),
+        });
+    }
+
+    let json = serde_json::to_string_pretty(&items)
+        .map_err(|e| miette::miette!("failed to serialize todos: {e}"))?;
+
+    println!("{json}");
+
+    Ok(())
+}
+
+fn status_label(status: &str) -> &'static str {
+    if status == "done" { "done" } else { "pending" }
+}
+
+/// Names of the todo's workspace and project, if it has them.
+async fn scope_names(
+    services: &Services,
+    todo: &todo::Model,
+) -> miette::Result<(Option<String>, Option<String>)> {
+    let workspace = match todo.workspace_id {
+        Some(id) => services.workspaces.get(id).await?.map(|w| w.name),
+        None => None,
+    };
+
+    let project = match todo.project_id {
+        Some(id) => services.projects.get(id).await?.map(|p| p.name),
+        None => None,
+    };
+
+    Ok((workspace, project))
+}
+
+/// Print `todos` as the list table, with an id column when `show_id` and each
+/// todo's notes under its row when `show_notes`. Rows are numbered, and the
+/// numbering is saved under `scope` so `mach done 3` and friends can refer to
+/// a row.
+pub async fn print_table(
+    services: &Services,
+    todos: Vec<todo::Model>,
+    scope: &str,
+    show_id: bool,
+    show_notes: bool,
+) -> miette::Result<()> {
+    let ids: Vec<_> = todos.iter().map(|todo| todo.id).collect();
+
+    services.config.save_last_list(scope, &ids).await?;
+
+    if show_id {
+        println!(
+            "{:>3}  {:<38} {:<30} {:<8} {:<15} {:<15} {:<12}",
+            "#", "Id", "Title", "Status", "Workspace", "Project", "Day"
+        );
+        println!("{}", "-".repeat(130));
+    } else {
+        println!(
+            "{:>3}  {:<30} {:<8} {:<15} {:<15} {:<12}",
+            "#", "Title", "Status", "Workspace", "Project", "Day"
+        );
+        println!("{}", "-".repeat(90));
+    }
+
+    for (row, todo) in todos.into_iter().enumerate() {
+        let row = row + 1;
+
+        let day = todo
+            .scheduled_for
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "Someday".to_string());
+
+        let status = status_label(&todo.status);
+
+        let (workspace_name, project_name) = scope_names(services, &todo).await?;
+        let workspace_name = workspace_name.unwrap_or_else(|| "-".to_string());
+        let project_name = project_name.unwrap_or_else(|| "-".to_string());
+
+        if show_id {
             println!(
-                "{:<38} {:<30} {:<8} {:<15} {:<15} {:<12}",
-                "Id", "Title", "Status", "Workspace", "Project", "Day"
+                "{:>3}  {:<38} {:<30} {:<8} {:<15} {:<15} {:<12}",
+                row, todo.id, todo.title, status, workspace_name, project_name, day
             );
-            println!("{}", "-".repeat(125));
         } else {
             println!(
-                "{:<30} {:<8} {:<15} {:<15} {:<12}",
-                "Title", "Status", "Workspace", "Project", "Day"
+                "{:>3}  {:<30} {:<8} {:<15} {:<15} {:<12}",
+                row, todo.title, status, workspace_name, project_name, day
             );
-            println!("{}", "-".repeat(85));
         }
 
-        for todo in todos {
-            let day = todo
-                .scheduled_for
-                .map(|d| d.to_string())
-                .unwrap_or_else(|| "Someday".to_string());
+        if show_notes {
+            print_notes(&todo);
+        }
+    }
 
-            let status = if todo.status == "done" {
-                "done"
-            } else {
-                "pending"
-            };
+    Ok(())
+}
 
-            let workspace_name = match todo.workspace_id {
-                Some(id) => services
-                    .workspaces
-                    .get(id)
-                    .await?
-                    .map(|w| w.name)
-                    .unwrap_or_else(|| "-".to_string()),
-                None => "-".to_string(),
-            };
+/// Lines of notes shown per todo before the rest is cut off.
+const NOTES_MAX_LINES: usize = 3;
 
-            let project_name = match todo.project_id {
-                Some(id) => services
-                    .projects
-                    .get(id)
-                    .await?
-                    .map(|p| p.name)
-                    .unwrap_or_else(|| "-".to_string()),
-                None => "-".to_string(),
-            };
+/// Characters shown per notes line before it is cut off.
+const NOTES_MAX_WIDTH: usize = 80;
 
-            if self.id {
-                println!(
-                    "{:<38} {:<30} {:<8} {:<15} {:<15} {:<12}",
-                    todo.id, todo.title, status, workspace_name, project_name, day
-                );
-            } else {
-                println!(
-                    "{:<30} {:<8} {:<15} {:<15} {:<12}",
-                    todo.title, status, workspace_name, project_name, day
-                );
-            }
+fn print_notes(todo: &todo::Model) {
+    let Some(notes) = todo.notes.as_deref().filter(|n| !n.trim().is_empty()) else {
+        return;
+    };
+
+    let lines: Vec<&str> = notes.trim_end().lines().collect();
+    let mut truncated = lines.len() > NOTES_MAX_LINES;
+
+    for line in lines.iter().take(NOTES_MAX_LINES) {
+        if line.chars().count() > NOTES_MAX_WIDTH {
+            let cut: String = line.chars().take(NOTES_MAX_WIDTH - 1).collect();
+
+            println!("    {cut}…");
+
+            truncated = true;
+        } else {
+            println!("    {line}");
         }
+    }
 
-        Ok(())
+    if truncated {
+        println!("    … (see `mach open {}` for the full notes)", todo.id);
     }
 }
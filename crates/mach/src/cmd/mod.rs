@@ -1,9 +1,24 @@
 pub mod add;
+pub mod archive;
+pub mod completions;
+pub mod config;
 pub mod delete;
 pub mod done;
+pub mod duplicate;
+pub mod explain;
+pub mod export;
+pub mod import;
 pub mod list;
+pub mod move_all;
+pub mod next;
+pub mod open;
 pub mod projects;
+pub mod reminders;
 pub mod reopen;
+pub mod restore;
+pub mod search;
+pub mod stats;
+pub mod templates;
 pub mod update;
 pub mod workspaces;
 
@@ -13,15 +28,50 @@ pub enum Cmd {
     Add(add::Args),
     #[clap(visible_alias = "l")]
     List(list::Args),
+    #[clap(visible_alias = "n")]
+    Next(next::Args),
     #[clap(visible_alias = "d")]
     Done(done::Args),
     #[clap(visible_alias = "r")]
     Reopen(reopen::Args),
     #[clap(visible_alias = "u")]
     Update(update::Args),
-    /// Delete a todo
+    /// Find todos by title
+    #[clap(visible_alias = "s")]
+    Search(search::Args),
+    /// Delete a todo permanently
     #[clap(visible_alias = "rm")]
     Delete(delete::Args),
+    /// Copy a todo into a new pending one
+    Duplicate(duplicate::Args),
+    /// Archive a todo, or list archived todos
+    Archive(archive::Args),
+    /// Bring an archived todo back
+    Restore(restore::Args),
+    /// Move every pending todo from one day (or the backlog) to another
+    MoveAll(move_all::Args),
+    /// Open the TUI on a todo's details
+    #[clap(visible_alias = "o")]
+    Open(open::Args),
+    /// Show why a todo sits where it does
+    Explain(explain::Args),
+    /// Show daily completion statistics
+    Stats(stats::Args),
+    /// Export todos as JSON or CSV
+    Export(export::Args),
+    /// Import todos from a JSON export
+    Import(import::Args),
+    /// List todos with reminders
+    Reminders(reminders::Args),
+    /// Manage todo templates
+    #[clap(visible_alias = "template")]
+    #[command(subcommand)]
+    Templates(templates::Cmd),
+    /// Read and write raw settings
+    #[command(subcommand)]
+    Config(config::Cmd),
+    /// Print a shell completion script
+    Completions(completions::Args),
     /// Manage workspaces
     #[clap(visible_alias = "w")]
     #[command(subcommand)]
@@ -37,12 +87,305 @@ impl Cmd {
         match self {
             Cmd::Add(args) => args.exec(services).await,
             Cmd::List(args) => args.exec(services).await,
+            Cmd::Next(args) => args.exec(services).await,
             Cmd::Done(args) => args.exec(services).await,
             Cmd::Reopen(args) => args.exec(services).await,
             Cmd::Update(args) => args.exec(services).await,
+            Cmd::Search(args) => args.exec(services).await,
             Cmd::Delete(args) => args.exec(services).await,
+            Cmd::Duplicate(args) => args.exec(services).await,
+            Cmd::Archive(args) => args.exec(services).await,
+            Cmd::Restore(args) => args.exec(services).await,
+            Cmd::MoveAll(args) => args.exec(services).await,
+            Cmd::Open(args) => args.exec(services).await,
+            Cmd::Explain(args) => args.exec(services).await,
+            Cmd::Stats(args) => args.exec(services).await,
+            Cmd::Export(args) => args.exec(services).await,
+            Cmd::Import(args) => args.exec(services).await,
+            Cmd::Reminders(args) => args.exec(services).await,
+            Cmd::Templates(cmd) => cmd.exec(services).await,
+            Cmd::Config(cmd) => cmd.exec(services).await,
+            Cmd::Completions(args) => args.exec(),
             Cmd::Workspaces(cmd) => cmd.exec(services).await,
             Cmd::Projects(cmd) => cmd.exec(services).await,
         }
     }
 }
+
+/// A day given as `YYYY-MM-DD` or relative to today: `tomorrow`, `fri`,
+/// `next mon`, `+3` and the other forms the TUI's date prompt accepts.
+pub fn parse_date(
+    s: &str,
+    services: &crate::service::Services,
+) -> miette::Result<chrono::NaiveDate> {
+    let today = services.today();
+
+    crate::service::dates::parse_date_input(s, today, services.week_start()).ok_or_else(|| {
+        miette::miette!(
+            "invalid date '{}', use YYYY-MM-DD, today, tomorrow, a weekday or +N",
+            s.trim()
+        )
+    })
+}
+
+/// Resolve a todo reference for commands that act on one todo. A number is
+/// a row from the last todo listing, and `<scope>:N` a row from the last
+/// listing of that scope (`backlog`, a day, `search`, `next`, ...). Anything
+/// else, or a row whose todo is gone or archived, goes through title/UUID
+/// lookup. With `scope` (a workspace name or UUID) title matches in that
+/// workspace win, and a note is printed when the match had to come from
+/// elsewhere.
+pub async fn resolve_todo(
+    services: &crate::service::Services,
+    reference: &str,
+    scope: Option<&str>,
+) -> miette::Result<crate::entity::todo::Model> {
+    let mut stale_row = false;
+
+    if let Some(id) = listed_row(services, reference).await? {
+        match services.todos.get(id).await {
+            Ok(todo) if todo.archived_at.is_none() => return Ok(todo),
+            _ => stale_row = true,
+        }
+    }
+
+    let workspace = match scope {
+        Some(ws) => Some(
+            services
+                .workspaces
+                .find_by_name_or_id(ws)
+                .await?
+                .ok_or_else(|| miette::miette!("workspace '{}' not found", ws))?,
+        ),
+        None => None,
+    };
+
+    let todo = services
+        .todos
+        .find_by_title_or_id(reference, workspace.as_ref().map(|ws| ws.id))
+        .await?
+        .ok_or_else(|| {
+            if stale_row {
+                miette::miette!(
+                    "row {} from the last list was deleted or archived; run `mach list` again",
+                    reference.trim()
+                )
+            } else {
+                miette::miette!("todo '{}' not found", reference)
+            }
+        })?;
+
+    if let Some(workspace) = workspace
+        && todo.workspace_id != Some(workspace.id)
+    {
+        eprintln!(
+            "note: no todo '{}' in workspace '{}', using the match outside it",
+            reference, workspace.name
+        );
+    }
+
+    Ok(todo)
+}
+
+/// Id of the listed row `reference` names, if it names one.
+async fn listed_row(
+    services: &crate::service::Services,
+    reference: &str,
+) -> miette::Result<Option<uuid::Uuid>> {
+    let reference = reference.trim();
+
+    let (list_scope, row) = match reference.rsplit_once(':') {
+        Some((list_scope, row)) => (Some(list_scope.trim().to_string()), row.trim()),
+        None => (services.config.last_list_scope().await?, reference),
+    };
+
+    let (Some(list_scope), Ok(row)) = (list_scope, row.parse::<usize>()) else {
+        return Ok(None);
+    };
+
+    if row == 0 {
+        return Ok(None);
+    }
+
+    let ids = services.config.load_last_list(&list_scope).await?;
+
+    Ok(ids.get(row - 1).copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{Services, config::ARCHIVE_LIST_SCOPE, connection::init_memory_database};
+
+    async fn services() -> Services {
+        Services::from_connection(init_memory_database().await.unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn add(services: &Services, title: &str) -> crate::entity::todo::Model {
+        services
+            .todos
+            .add(title, None, None, None, None, None)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rows_map_to_the_last_list_in_order() {
+        let services = services().await;
+
+        let first = add(&services, "first").await;
+        let second = add(&services, "second").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[second.id, first.id])
+            .await
+            .unwrap();
+
+        let row_one = resolve_todo(&services, "1", None).await.unwrap();
+        let row_two = resolve_todo(&services, " 2 ", None).await.unwrap();
+
+        assert_eq!(row_one.id, second.id);
+        assert_eq!(row_two.id, first.id);
+    }
+
+    #[tokio::test]
+    async fn rows_outside_the_list_are_looked_up_as_titles() {
+        let services = services().await;
+
+        let first = add(&services, "first").await;
+        let numbered = add(&services, "3").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[first.id])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resolve_todo(&services, "3", None).await.unwrap().id,
+            numbered.id
+        );
+        assert!(resolve_todo(&services, "0", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn a_stale_row_says_to_list_again() {
+        let services = services().await;
+
+        let gone = add(&services, "gone").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[gone.id])
+            .await
+            .unwrap();
+        services.todos.delete(gone.id).await.unwrap();
+
+        let err = resolve_todo(&services, "1", None).await.unwrap_err();
+
+        assert!(err.to_string().contains("deleted or archived"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn a_stale_row_still_matches_a_title() {
+        let services = services().await;
+
+        let gone = add(&services, "gone").await;
+        let numbered = add(&services, "1").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[gone.id])
+            .await
+            .unwrap();
+        services.todos.delete(gone.id).await.unwrap();
+
+        assert_eq!(
+            resolve_todo(&services, "1", None).await.unwrap().id,
+            numbered.id
+        );
+    }
+
+    #[tokio::test]
+    async fn an_archived_row_is_stale() {
+        let services = services().await;
+
+        let shelved = add(&services, "shelved").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[shelved.id])
+            .await
+            .unwrap();
+        services.todos.archive(shelved.id).await.unwrap();
+
+        let err = resolve_todo(&services, "1", None).await.unwrap_err();
+
+        assert!(err.to_string().contains("deleted or archived"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn each_scope_keeps_its_own_numbering() {
+        let services = services().await;
+
+        let planned = add(&services, "planned").await;
+        let found = add(&services, "found").await;
+
+        services
+            .config
+            .save_last_list("backlog", &[planned.id])
+            .await
+            .unwrap();
+        services
+            .config
+            .save_last_list("search", &[found.id])
+            .await
+            .unwrap();
+
+        // A bare number follows the latest listing; a scoped one its own.
+        assert_eq!(
+            resolve_todo(&services, "1", None).await.unwrap().id,
+            found.id
+        );
+        assert_eq!(
+            resolve_todo(&services, "backlog:1", None).await.unwrap().id,
+            planned.id
+        );
+    }
+
+    #[tokio::test]
+    async fn listing_the_archive_keeps_the_bare_numbering() {
+        let services = services().await;
+
+        let planned = add(&services, "planned").await;
+        let shelved = add(&services, "shelved").await;
+
+        services.todos.archive(shelved.id).await.unwrap();
+        services
+            .config
+            .save_last_list("backlog", &[planned.id])
+            .await
+            .unwrap();
+        services
+            .config
+            .save_last_list(ARCHIVE_LIST_SCOPE, &[shelved.id])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resolve_todo(&services, "1", None).await.unwrap().id,
+            planned.id
+        );
+        assert_eq!(
+            services
+                .config
+                .load_last_list(ARCHIVE_LIST_SCOPE)
+                .await
+                .unwrap(),
+            [shelved.id]
+        );
+    }
+}
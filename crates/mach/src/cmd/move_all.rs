@@ -0,0 +1,69 @@
+use crate::service::{
+    Services,
+    todo::{ListScope, MovePlacement},
+};
+
+use super::parse_date;
+
+/// Move every pending todo from one day (or the backlog) to another
+#[derive(clap::Args)]
+pub struct Args {
+    /// Where the todos are now: `backlog`, or a day (YYYY-MM-DD, `today`,
+    /// `fri`, `-1`, ...)
+    #[clap(long, allow_hyphen_values = true)]
+    from: String,
+
+    /// Where they go: `backlog`, or a day in the same forms as --from
+    #[clap(long, allow_hyphen_values = true)]
+    to: String,
+
+    /// Only move todos in this backlog column (1-4)
+    #[clap(long, value_parser = clap::value_parser!(i64).range(1..=4))]
+    column: Option<i64>,
+
+    /// Put the moved todos at the bottom of the target instead of the top
+    #[clap(long, default_value = "false")]
+    bottom: bool,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let from = parse_scope(&self.from, services)?;
+        let to = parse_scope(&self.to, services)?;
+
+        if self.column.is_some() && from != ListScope::Backlog {
+            miette::bail!("--column only applies when moving from the backlog");
+        }
+
+        let placement = if self.bottom {
+            MovePlacement::Bottom
+        } else {
+            MovePlacement::Top
+        };
+
+        let moved = services
+            .todos
+            .move_all(from, to, self.column.map(|col| col - 1), placement)
+            .await?;
+
+        let target = match to {
+            ListScope::Day(date) => date.to_string(),
+            _ => "the backlog".to_string(),
+        };
+
+        match moved.len() {
+            0 => println!("Nothing to move."),
+            1 => println!("Moved 1 todo to {target}"),
+            n => println!("Moved {n} todos to {target}"),
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_scope(input: &str, services: &Services) -> miette::Result<ListScope> {
+    match input.trim().to_lowercase().as_str() {
+        "backlog" | "someday" => Ok(ListScope::Backlog),
+        _ => Ok(ListScope::Day(parse_date(input, services)?)),
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use chrono::Duration as ChronoDuration;
+
+use crate::service::{
+    Services,
+    todo::{ListOptions, ListScope, rolled_over_from},
+};
+
+/// Show what's left for today, or the next few days
+#[derive(clap::Args)]
+pub struct Args {
+    /// Show this many days starting today, grouped by date
+    #[clap(short, long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..=31))]
+    days: u32,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let today = services.today();
+        let last = today + ChronoDuration::days(i64::from(self.days) - 1);
+
+        let todos = services
+            .todos
+            .list(ListOptions {
+                scope: ListScope::Range(today, last),
+                ..ListOptions::today(today)
+            })
+            .await?;
+
+        let ids: Vec<_> = todos.iter().map(|todo| todo.id).collect();
+
+        services.config.save_last_list("next", &ids).await?;
+
+        if todos.is_empty() {
+            if self.days == 1 {
+                println!("Nothing left for today.");
+            } else {
+                println!("Nothing left for the next {} days.", self.days);
+            }
+
+            return Ok(());
+        }
+
+        let mut project_names = HashMap::new();
+        let mut current_day = None;
+        let mut overdue = 0;
+
+        for (row, todo) in todos.iter().enumerate() {
+            if self.days > 1 && current_day != todo.scheduled_for {
+                current_day = todo.scheduled_for;
+
+                if let Some(day) = current_day {
+                    println!("{}", day.format("%a %Y-%m-%d"));
+                }
+            }
+
+            // Rollover already moved anything overdue onto today; the
+            // metadata remembers where it came from.
+            let glyph = if rolled_over_from(todo).is_some() {
+                overdue += 1;
+
+                "↻"
+            } else {
+                "○"
+            };
+
+            let project = match todo.project_id {
+                Some(id) => {
+                    // The lookup is async, so it can't run inside `or_insert_with`.
+                    let cached = match project_names.entry(id) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            entry.insert(services.projects.get(id).await?.map(|p| p.name))
+                        }
+                    };
+
+                    cached
+                        .as_ref()
+                        .map(|name| format!(" [{name}]"))
+                        .unwrap_or_default()
+                }
+                None => String::new(),
+            };
+
+            let priority = "!".repeat(todo.priority.max(0) as usize);
+            let priority = if priority.is_empty() {
+                priority
+            } else {
+                format!("{priority} ")
+            };
+
+            println!("{:>3} {glyph} {priority}{}{project}", row + 1, todo.title);
+        }
+
+        if overdue > 0 {
+            println!(
+                "{overdue} overdue, carried over from earlier days (marked ↻; `mach explain <n>` says from when)"
+            );
+        }
+
+        Ok(())
+    }
+}
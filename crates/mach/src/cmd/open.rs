@@ -0,0 +1,23 @@
+use crate::service::Services;
+
+/// Open the TUI on a todo's details
+#[derive(clap::Args)]
+pub struct Args {
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Todo id or title
+    #[clap(required = true)]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let reference = self.reference.join(" ");
+
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
+
+        crate::tui::run(services.clone(), Some(todo.id)).await
+    }
+}
@@ -0,0 +1,87 @@
+use crate::service::Services;
+
+/// Delete a project, reassigning or clearing its todos
+#[derive(clap::Args)]
+pub struct Args {
+    /// Move the project's todos to this project (name or UUID) first
+    #[clap(short, long, conflicts_with = "clear")]
+    reassign: Option<String>,
+
+    /// Leave the project's todos without a project
+    #[clap(short, long, default_value = "false")]
+    clear: bool,
+
+    /// Project id or name
+    #[clap(required = true)]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let reference = self.reference.join(" ");
+
+        let project = services
+            .projects
+            .find_by_name_or_id(&reference)
+            .await?
+            .ok_or_else(|| miette::miette!("project '{}' not found", reference))?;
+
+        let target = match &self.reassign {
+            Some(other) => {
+                let target = services
+                    .projects
+                    .find_by_name_or_id(other)
+                    .await?
+                    .ok_or_else(|| miette::miette!("project '{}' not found", other))?;
+
+                if target.id == project.id {
+                    miette::bail!("cannot reassign todos to the project being deleted");
+                }
+
+                Some(target)
+            }
+            None => None,
+        };
+
+        let count = services.todos.count_by_project(project.id).await?;
+
+        if count > 0 && target.is_none() && !self.clear {
+            miette::bail!(
+                "project '{}' still has {} todo{}; pass --reassign <project> or --clear",
+                project.name,
+                count,
+                if count == 1 { "" } else { "s" }
+            );
+        }
+
+        let moved = if count > 0 {
+            services
+                .todos
+                .reassign_project(project.id, target.as_ref())
+                .await?
+        } else {
+            0
+        };
+
+        services.projects.delete(project.id).await?;
+
+        match (&target, moved) {
+            (_, 0) => println!("Deleted project '{}'", project.name),
+            (Some(target), n) => println!(
+                "Deleted project '{}' and moved {} todo{} to '{}'",
+                project.name,
+                n,
+                if n == 1 { "" } else { "s" },
+                target.name
+            ),
+            (None, n) => println!(
+                "Deleted project '{}' and cleared it from {} todo{}",
+                project.name,
+                n,
+                if n == 1 { "" } else { "s" }
+            ),
+        }
+
+        Ok(())
+    }
+}
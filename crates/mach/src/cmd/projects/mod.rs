@@ -1,6 +1,8 @@
 pub mod create;
+pub mod delete;
 pub mod done;
 pub mod list;
+pub mod rename;
 pub mod reopen;
 pub mod update;
 
@@ -39,6 +41,10 @@ pub enum Cmd {
     /// Reopen a project (set status to pending)
     #[clap(visible_alias = "r")]
     Reopen(reopen::Args),
+    /// Rename a project
+    Rename(rename::Args),
+    /// Delete a project, reassigning or clearing its todos
+    Delete(delete::Args),
 }
 
 impl Cmd {
@@ -49,6 +55,8 @@ impl Cmd {
             Cmd::Update(args) => args.exec(services).await,
             Cmd::Done(args) => args.exec(services).await,
             Cmd::Reopen(args) => args.exec(services).await,
+            Cmd::Rename(args) => args.exec(services).await,
+            Cmd::Delete(args) => args.exec(services).await,
         }
     }
 }
@@ -0,0 +1,38 @@
+use crate::service::Services;
+
+/// Rename a project
+#[derive(clap::Args)]
+pub struct Args {
+    /// Project id or name
+    reference: String,
+
+    /// New name (quoted or space separated)
+    #[clap(required = true)]
+    name: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let project = services
+            .projects
+            .find_by_name_or_id(&self.reference)
+            .await?
+            .ok_or_else(|| miette::miette!("project '{}' not found", self.reference))?;
+
+        let (renamed, templates) = services
+            .rename_project(project.id, self.name.join(" "))
+            .await?;
+
+        println!("Renamed project '{}' to '{}'", project.name, renamed.name);
+
+        if templates > 0 {
+            println!(
+                "Updated {} template{} that used it",
+                templates,
+                if templates == 1 { "" } else { "s" }
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -33,7 +33,7 @@ impl Args {
         let mut updated = project;
 
         if let Some(name) = self.name {
-            updated = services.projects.update_name(updated.id, name).await?;
+            (updated, _) = services.rename_project(updated.id, name).await?;
         }
 
         if let Some(status) = self.status {
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDateTime, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::service::Services;
+
+/// List todos with reminders
+#[derive(clap::Args)]
+pub struct Args {
+    /// Only show reminders whose time has passed
+    #[clap(short, long, default_value = "false")]
+    due: bool,
+
+    /// Print reminders as JSON
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReminderItem {
+    id: uuid::Uuid,
+    title: String,
+    remind_at: DateTime<Utc>,
+    due: bool,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let now = Utc::now();
+        let due_by = if self.due { Some(now) } else { None };
+
+        let todos = services.todos.list_reminders(due_by).await?;
+
+        let items: Vec<ReminderItem> = todos
+            .into_iter()
+            .filter_map(|todo| {
+                let remind_at = todo.remind_at?;
+
+                Some(ReminderItem {
+                    id: todo.id,
+                    title: todo.title,
+                    remind_at,
+                    due: remind_at <= now,
+                })
+            })
+            .collect();
+
+        if self.json {
+            let json = serde_json::to_string_pretty(&items)
+                .map_err(|e| miette::miette!("failed to serialize reminders: {e}"))?;
+
+            println!("{json}");
+
+            return Ok(());
+        }
+
+        if items.is_empty() {
+            println!("No reminders found.");
+
+            return Ok(());
+        }
+
+        println!("{:<30} {:<18} {:<8}", "Title", "Remind at", "Status");
+        println!("{}", "-".repeat(58));
+
+        for item in items {
+            let remind_at = item
+                .remind_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string();
+
+            let status = if item.due { "due" } else { "upcoming" };
+
+            println!("{:<30} {:<18} {:<8}", item.title, remind_at, status);
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a reminder time: `YYYY-MM-DD HH:MM` in local time, or an offset from
+/// now such as `+30m`, `+2h` or `+1d`. `none` clears the reminder.
+pub fn parse_remind_at(s: &str) -> miette::Result<Option<DateTime<Utc>>> {
+    let s = s.trim().to_lowercase();
+
+    if s == "none" {
+        return Ok(None);
+    }
+
+    if let Some(offset) = s.strip_prefix('+') {
+        let Some(unit) = offset.chars().last() else {
+            miette::bail!("invalid reminder offset '{s}'");
+        };
+
+        let amount: i64 = offset[..offset.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| miette::miette!("invalid reminder offset '{s}'"))?;
+
+        let offset = match unit {
+            'm' => ChronoDuration::minutes(amount),
+            'h' => ChronoDuration::hours(amount),
+            'd' => ChronoDuration::days(amount),
+            _ => miette::bail!("invalid reminder offset '{s}', use m, h or d"),
+        };
+
+        return Ok(Some(Utc::now() + offset));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(&s, "%Y-%m-%dt%H:%M"))
+        .map_err(|_| {
+            miette::miette!("invalid reminder, use YYYY-MM-DD HH:MM or an offset like +2h")
+        })?;
+
+    let local = Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| miette::miette!("reminder time does not exist in the local timezone"))?;
+
+    Ok(Some(local.with_timezone(&Utc)))
+}
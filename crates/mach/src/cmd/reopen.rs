@@ -3,6 +3,14 @@ use crate::service::Services;
 /// Mark a todo as pending (reopen)
 #[derive(clap::Args)]
 pub struct Args {
+    /// Move the todo to the top of its column instead of its previous position
+    #[clap(long, default_value = "false")]
+    top: bool,
+
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
     /// Todo id or title
     #[clap(required = true)]
     reference: Vec<String>,
@@ -12,13 +20,9 @@ impl Args {
     pub async fn exec(self, services: &Services) -> miette::Result<()> {
         let reference = self.reference.join(" ");
 
-        let todo = services
-            .todos
-            .find_by_title_or_id(&reference)
-            .await?
-            .ok_or_else(|| miette::miette!("todo '{}' not found", reference))?;
+        let todo = super::resolve_todo(services, &reference, self.scope.as_deref()).await?;
 
-        let updated = services.todos.mark_pending(todo.id).await?;
+        let updated = services.todos.mark_pending(todo.id, !self.top).await?;
 
         println!("Reopened '{}'", updated.title);
 
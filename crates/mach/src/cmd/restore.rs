@@ -0,0 +1,50 @@
+use crate::service::{Services, config::ARCHIVE_LIST_SCOPE};
+
+/// Bring an archived todo back
+#[derive(clap::Args)]
+pub struct Args {
+    /// Row from the last `mach archive --list`, or an archived todo's id or
+    /// title
+    #[clap(required = true)]
+    reference: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let reference = self.reference.join(" ");
+
+        let row_id = match reference.trim().parse::<usize>() {
+            Ok(row) if row > 0 => services
+                .config
+                .load_last_list(ARCHIVE_LIST_SCOPE)
+                .await?
+                .get(row - 1)
+                .copied(),
+            _ => None,
+        };
+
+        let todo = match row_id {
+            Some(id) => services.todos.get(id).await?,
+            None => services
+                .todos
+                .find_archived(&reference)
+                .await?
+                .ok_or_else(|| miette::miette!("no archived todo '{}'", reference))?,
+        };
+
+        if todo.archived_at.is_none() {
+            miette::bail!("'{}' is not archived", todo.title);
+        }
+
+        let restored = services.todos.unarchive(todo.id).await?;
+
+        let place = restored
+            .scheduled_for
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "the backlog".to_string());
+
+        println!("Restored '{}' to {}", restored.title, place);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::service::{
+    Services,
+    todo::{ProjectFilter, SearchOptions},
+};
+
+/// Find todos by a word or phrase in their title
+#[derive(clap::Args)]
+pub struct Args {
+    /// Include completed todos
+    #[clap(short, long, default_value = "false")]
+    done: bool,
+
+    /// Only todos in this project (name or UUID); "" or "none" for todos
+    /// without one
+    #[clap(short, long)]
+    project: Option<String>,
+
+    /// Only todos in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
+    /// Search note bodies too
+    #[clap(short, long, default_value = "false")]
+    notes: bool,
+
+    /// Show at most this many matches, best first
+    #[clap(short, long, default_value = "50")]
+    limit: u64,
+
+    /// Include the id column
+    #[clap(short, long, default_value = "false")]
+    id: bool,
+
+    /// Text to look for (quoted or space separated)
+    #[clap(required = true)]
+    query: Vec<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let query = self.query.join(" ");
+
+        let project = match self.project.as_deref() {
+            None => ProjectFilter::Any,
+            Some(input) if ProjectFilter::is_no_project(input) => ProjectFilter::IsNull,
+            Some(input) => {
+                let project = services
+                    .projects
+                    .find_by_name_or_id(input)
+                    .await?
+                    .ok_or_else(|| miette::miette!("project '{}' not found", input))?;
+
+                ProjectFilter::Equals(project.id)
+            }
+        };
+
+        let workspace = match self.scope.as_deref() {
+            Some(ws) => Some(
+                services
+                    .workspaces
+                    .find_by_name_or_id(ws)
+                    .await?
+                    .ok_or_else(|| miette::miette!("workspace '{}' not found", ws))?
+                    .id,
+            ),
+            None => None,
+        };
+
+        let opts = SearchOptions {
+            include_done: self.done,
+            project,
+            workspace,
+            notes: self.notes,
+            // One extra tells us whether anything was left out.
+            limit: Some(self.limit + 1),
+        };
+
+        let mut todos = services.todos.search(&query, opts).await?;
+
+        if todos.is_empty() {
+            println!("No matches.");
+
+            return Ok(());
+        }
+
+        let truncated = todos.len() as u64 > self.limit;
+
+        todos.truncate(self.limit as usize);
+
+        super::list::print_table(services, todos, "search", self.id, false).await?;
+
+        if truncated {
+            println!(
+                "Showing the first {} matches; narrow the search or raise --limit.",
+                self.limit
+            );
+        }
+
+        Ok(())
+    }
+}
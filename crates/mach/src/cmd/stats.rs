@@ -0,0 +1,172 @@
+use chrono::{Duration as ChronoDuration, NaiveDate};
+use serde::Serialize;
+
+use crate::service::{Services, config::WeekStart, todo::DayStats};
+
+use super::parse_date;
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum StatsFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum StatsPeriod {
+    Day,
+    Week,
+}
+
+/// Show daily completion statistics
+#[derive(clap::Args)]
+pub struct Args {
+    /// First day to include (YYYY-MM-DD or relative like `-14`, defaults to
+    /// 6 days before --to)
+    #[clap(long, allow_hyphen_values = true)]
+    from: Option<String>,
+
+    /// Last day to include (YYYY-MM-DD or relative like `yesterday`,
+    /// defaults to today)
+    #[clap(long, allow_hyphen_values = true)]
+    to: Option<String>,
+
+    /// Output format
+    #[clap(short, long, value_enum, default_value = "table")]
+    format: StatsFormat,
+
+    /// One row per day, or per week (starting on your week start day)
+    #[clap(long, value_enum, default_value = "day")]
+    by: StatsPeriod,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsRow {
+    date: NaiveDate,
+    created: u64,
+    completed: u64,
+    carried_over: u64,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let to = match &self.to {
+            Some(s) => parse_date(s, services)?,
+            None => services.today(),
+        };
+
+        let from = match &self.from {
+            Some(s) => parse_date(s, services)?,
+            None => to - ChronoDuration::days(6),
+        };
+
+        let mut stats = services.todos.daily_stats(from, to).await?;
+
+        if let StatsPeriod::Week = self.by {
+            stats = by_week(&stats, services.week_start());
+        }
+
+        match self.format {
+            StatsFormat::Table => print_table(&stats, &self.by),
+            StatsFormat::Csv => print_csv(&stats),
+            StatsFormat::Json => print_json(&stats)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Sum per-day rows into one row per week, dated by the week's first day.
+/// Days carried over are summed too, so a todo left pending all week counts
+/// once for each day it sat there.
+fn by_week(stats: &[DayStats], week_start: WeekStart) -> Vec<DayStats> {
+    let mut weeks: Vec<DayStats> = Vec::new();
+
+    for day in stats {
+        let week = week_start.week_of(day.date);
+
+        match weeks.last_mut() {
+            Some(last) if last.date == week => {
+                last.created += day.created;
+                last.completed += day.completed;
+                last.carried_over += day.carried_over;
+            }
+            _ => weeks.push(DayStats { date: week, ..*day }),
+        }
+    }
+
+    weeks
+}
+
+fn print_table(stats: &[DayStats], by: &StatsPeriod) {
+    let label = match by {
+        StatsPeriod::Day => "date",
+        StatsPeriod::Week => "week of",
+    };
+
+    println!(
+        "{:<12} {:>8} {:>10} {:>13}",
+        label, "created", "completed", "carried_over"
+    );
+    println!("{}", "-".repeat(46));
+
+    for day in stats {
+        println!(
+            "{:<12} {:>8} {:>10} {:>13}",
+            day.date.to_string(),
+            day.created,
+            day.completed,
+            day.carried_over
+        );
+    }
+}
+
+fn print_csv(stats: &[DayStats]) {
+    println!("date,created,completed,carried_over");
+
+    for day in stats {
+        let row = [
+            day.date.to_string(),
+            day.created.to_string(),
+            day.completed.to_string(),
+            day.carried_over.to_string(),
+        ];
+
+        println!("{}", csv_row(&row));
+    }
+}
+
+fn print_json(stats: &[DayStats]) -> miette::Result<()> {
+    let rows: Vec<StatsRow> = stats
+        .iter()
+        .map(|day| StatsRow {
+            date: day.date,
+            created: day.created,
+            completed: day.completed,
+            carried_over: day.carried_over,
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&rows)
+        .map_err(|e| miette::miette!("failed to serialize stats: {e}"))?;
+
+    println!("{json}");
+
+    Ok(())
+}
+
+/// Join fields into a CSV row, quoting any field that needs it.
+pub fn csv_row(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,40 @@
+use crate::service::Services;
+
+/// List templates
+#[derive(clap::Args)]
+pub struct Args {}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let templates = services.config.load_templates().await?;
+
+        if templates.is_empty() {
+            println!("No templates found");
+            return Ok(());
+        }
+
+        println!(
+            "{:<16} {:<24} {:<20} {:>6}",
+            "name", "prefix", "project", "notes"
+        );
+        println!("{}", "-".repeat(69));
+
+        for (name, template) in templates {
+            let notes = template
+                .notes
+                .as_deref()
+                .map(|n| n.lines().count())
+                .unwrap_or(0);
+
+            println!(
+                "{:<16} {:<24} {:<20} {:>6}",
+                name,
+                template.title_prefix.as_deref().unwrap_or("-"),
+                template.project.as_deref().unwrap_or("-"),
+                notes
+            );
+        }
+
+        Ok(())
+    }
+}
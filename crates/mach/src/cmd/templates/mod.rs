@@ -0,0 +1,27 @@
+pub mod list;
+pub mod remove;
+pub mod set;
+
+/// Manage todo templates
+#[derive(clap::Subcommand)]
+pub enum Cmd {
+    /// Create or replace a template
+    #[clap(visible_alias = "s")]
+    Set(set::Args),
+    /// List templates
+    #[clap(visible_alias = "l")]
+    List(list::Args),
+    /// Remove a template
+    #[clap(visible_alias = "rm")]
+    Remove(remove::Args),
+}
+
+impl Cmd {
+    pub async fn exec(self, services: &crate::service::Services) -> miette::Result<()> {
+        match self {
+            Cmd::Set(args) => args.exec(services).await,
+            Cmd::List(args) => args.exec(services).await,
+            Cmd::Remove(args) => args.exec(services).await,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::service::Services;
+
+/// Remove a template
+#[derive(clap::Args)]
+pub struct Args {
+    /// Template name
+    name: String,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        if !services.config.remove_template(&self.name).await? {
+            miette::bail!("template '{}' not found", self.name);
+        }
+
+        println!("Removed template '{}'", self.name);
+
+        Ok(())
+    }
+}
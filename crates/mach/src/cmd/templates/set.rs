@@ -0,0 +1,44 @@
+use crate::service::{Services, config::TodoTemplate};
+
+/// Create or replace a template
+#[derive(clap::Args)]
+pub struct Args {
+    /// Template name (a single word)
+    name: String,
+
+    /// Text put in front of the todo title
+    #[clap(short = 't', long)]
+    prefix: Option<String>,
+
+    /// Notes skeleton for new todos
+    #[clap(short, long)]
+    notes: Option<String>,
+
+    /// Default project name or UUID
+    #[clap(short, long)]
+    project: Option<String>,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        if let Some(project) = &self.project {
+            services
+                .projects
+                .find_by_name_or_id(project)
+                .await?
+                .ok_or_else(|| miette::miette!("project '{}' not found", project))?;
+        }
+
+        let template = TodoTemplate {
+            title_prefix: self.prefix.filter(|p| !p.trim().is_empty()),
+            notes: self.notes.filter(|n| !n.trim().is_empty()),
+            project: self.project,
+        };
+
+        services.config.save_template(&self.name, template).await?;
+
+        println!("Saved template '{}'", self.name.trim().to_lowercase());
+
+        Ok(())
+    }
+}
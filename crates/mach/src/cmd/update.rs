@@ -1,4 +1,4 @@
-use crate::service::Services;
+use crate::service::{Services, recurrence::Recurrence};
 
 /// Update a todo
 #[derive(clap::Args)]
@@ -6,18 +6,52 @@ pub struct Args {
     /// Todo id or title
     reference: String,
 
+    /// Prefer title matches in this workspace (name or UUID)
+    #[clap(long)]
+    scope: Option<String>,
+
     /// New title
     #[clap(short, long)]
     title: Option<String>,
 
-    /// New scheduled date (YYYY-MM-DD or "none"/"someday" to clear)
-    #[clap(short, long)]
+    /// New scheduled date: YYYY-MM-DD, `today`, `tomorrow`, a weekday,
+    /// `next mon`, `+3`/`-1`, or "none"/"someday" to clear
+    #[clap(short, long, allow_hyphen_values = true)]
     day: Option<String>,
 
     /// New notes
     #[clap(short, long)]
     notes: Option<String>,
 
+    /// Link to an issue, doc or page (empty to clear)
+    #[clap(long)]
+    url: Option<String>,
+
+    /// Reminder time (YYYY-MM-DD HH:MM, an offset like +2h, or "none" to clear)
+    #[clap(long)]
+    remind: Option<String>,
+
+    /// Add a tag (repeatable; `#` optional)
+    #[clap(long = "tag")]
+    tags: Vec<String>,
+
+    /// Remove a tag (repeatable)
+    #[clap(long = "untag")]
+    untags: Vec<String>,
+
+    /// Backlog column (1-4); the todo must be in the backlog or be moved
+    /// there with --day someday
+    #[clap(long, value_parser = clap::value_parser!(i64).range(1..=4))]
+    column: Option<i64>,
+
+    /// Priority from 0 (none) to 3 (most urgent)
+    #[clap(long)]
+    priority: Option<i32>,
+
+    /// Repeat rule (daily, weekly, weekdays, every:<n>d) or "none" to stop
+    #[clap(long)]
+    repeat: Option<String>,
+
     /// Workspace name or UUID
     #[clap(short, long)]
     workspace: Option<String>,
@@ -29,11 +63,20 @@ pub struct Args {
 
 impl Args {
     pub async fn exec(self, services: &Services) -> miette::Result<()> {
-        let todo = services
-            .todos
-            .find_by_title_or_id(&self.reference)
-            .await?
-            .ok_or_else(|| miette::miette!("todo '{}' not found", self.reference))?;
+        let todo = super::resolve_todo(services, &self.reference, self.scope.as_deref()).await?;
+
+        let day = self
+            .day
+            .map(|day| parse_scheduled_for(&day, services))
+            .transpose()?;
+
+        // Checked before anything changes, so a bad --column updates nothing.
+        if self.column.is_some() && day.unwrap_or(todo.scheduled_for).is_some() {
+            miette::bail!(
+                "'{}' is scheduled; add --day someday to move it to the backlog",
+                todo.title
+            );
+        }
 
         let mut updated = todo.clone();
 
@@ -41,19 +84,60 @@ impl Args {
             updated = services.todos.update_title(updated.id, title).await?;
         }
 
-        if let Some(day) = self.day {
-            let date = parse_scheduled_for(&day)?;
+        if let Some(date) = day {
             updated = services
                 .todos
                 .update_scheduled_for(updated.id, date)
                 .await?;
         }
 
+        if let Some(column) = self.column {
+            updated = services
+                .todos
+                .set_backlog_column(updated.id, column - 1)
+                .await?;
+        }
+
         if let Some(notes) = self.notes {
             let notes = if notes.is_empty() { None } else { Some(notes) };
             updated = services.todos.update_notes(updated.id, notes).await?;
         }
 
+        if let Some(url) = self.url {
+            updated = services.todos.update_url(updated.id, Some(url)).await?;
+        }
+
+        if let Some(remind) = self.remind {
+            let remind_at = super::reminders::parse_remind_at(&remind)?;
+            updated = services
+                .todos
+                .update_remind_at(updated.id, remind_at)
+                .await?;
+        }
+
+        for tag in &self.tags {
+            updated = services.todos.add_tag(updated.id, tag).await?;
+        }
+
+        for tag in &self.untags {
+            updated = services.todos.remove_tag(updated.id, tag).await?;
+        }
+
+        if let Some(priority) = self.priority {
+            updated = services.todos.update_priority(updated.id, priority).await?;
+        }
+
+        if let Some(repeat) = self.repeat {
+            let recurrence = match repeat.trim().to_lowercase().as_str() {
+                "none" => None,
+                rule => Some(Recurrence::parse(rule)?),
+            };
+            updated = services
+                .todos
+                .update_recurrence(updated.id, recurrence)
+                .await?;
+        }
+
         if self.workspace.is_some() || self.project.is_some() {
             let (workspace_id, project_id) = resolve_workspace_project(
                 services,
@@ -73,14 +157,12 @@ impl Args {
     }
 }
 
-fn parse_scheduled_for(s: &str) -> miette::Result<Option<chrono::NaiveDate>> {
+fn parse_scheduled_for(s: &str, services: &Services) -> miette::Result<Option<chrono::NaiveDate>> {
     let s = s.trim().to_lowercase();
     if s == "none" || s == "someday" {
         return Ok(None);
     }
-    chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d")
-        .map(Some)
-        .map_err(|_| miette::miette!("invalid date format, use YYYY-MM-DD"))
+    super::parse_date(&s, services).map(Some)
 }
 
 async fn resolve_workspace_project(
@@ -0,0 +1,48 @@
+use crate::service::Services;
+
+/// Delete a workspace
+#[derive(clap::Args)]
+pub struct Args {
+    /// Also delete the workspace's projects
+    #[clap(long, default_value = "false")]
+    cascade: bool,
+
+    /// Workspace id or name
+    reference: String,
+}
+
+impl Args {
+    pub async fn exec(self, services: &Services) -> miette::Result<()> {
+        let workspace = services
+            .workspaces
+            .find_by_name_or_id(&self.reference)
+            .await?
+            .ok_or_else(|| miette::miette!("workspace '{}' not found", self.reference))?;
+
+        let deleted = services
+            .workspaces
+            .delete(workspace.id, self.cascade)
+            .await
+            .map_err(|err| miette::miette!("cannot delete '{}': {err}", workspace.name))?;
+
+        println!("Deleted workspace '{}'", workspace.name);
+
+        if deleted.projects > 0 {
+            println!(
+                "Deleted {} project{}",
+                deleted.projects,
+                if deleted.projects == 1 { "" } else { "s" }
+            );
+        }
+
+        if deleted.todos > 0 {
+            println!(
+                "Cleared the workspace from {} todo{}",
+                deleted.todos,
+                if deleted.todos == 1 { "" } else { "s" }
+            );
+        }
+
+        Ok(())
+    }
+}
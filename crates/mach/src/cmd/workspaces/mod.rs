@@ -1,4 +1,5 @@
 pub mod create;
+pub mod delete;
 pub mod list;
 pub mod update;
 
@@ -14,6 +15,8 @@ pub enum Cmd {
     /// Update a workspace
     #[clap(visible_alias = "u")]
     Update(update::Args),
+    /// Delete a workspace
+    Delete(delete::Args),
 }
 
 impl Cmd {
@@ -22,6 +25,7 @@ impl Cmd {
             Cmd::Create(args) => args.exec(services).await,
             Cmd::List(args) => args.exec(services).await,
             Cmd::Update(args) => args.exec(services).await,
+            Cmd::Delete(args) => args.exec(services).await,
         }
     }
 }
@@ -22,11 +22,27 @@ pub struct Model {
     pub backlog_column: i64,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
+    /// When the todo was last marked done; cleared when it is reopened.
+    /// Todos finished before this column existed have none.
+    pub completed_at: Option<DateTimeUtc>,
+    /// Set while the todo is archived: kept in the database but left out of
+    /// every list until it is unarchived.
+    pub archived_at: Option<DateTimeUtc>,
     pub notes: Option<String>,
+    pub url: Option<String>,
     #[sea_orm(column_type = "JsonBinary")]
     pub metadata: JsonValue,
     pub workspace_id: Option<Uuid>,
     pub project_id: Option<Uuid>,
+    pub remind_at: Option<DateTimeUtc>,
+    /// 0 for none, up to `MAX_PRIORITY` for the most urgent.
+    #[sea_orm(default_value = 0)]
+    pub priority: i32,
+    /// Lowercase tags separated by single spaces, without the leading `#`.
+    pub tags: Option<String>,
+    /// Repeat rule (see `service::recurrence`); done todos hand it to the
+    /// copy spawned for the next occurrence.
+    pub recurrence: Option<String>,
     #[sea_orm(belongs_to, from = "workspace_id", to = "id")]
     pub workspace: HasOne<super::workspace::Entity>,
     #[sea_orm(belongs_to, from = "project_id", to = "id")]
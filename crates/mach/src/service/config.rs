@@ -1,11 +1,46 @@
-use crate::entity::config;
-use chrono::Utc;
+use crate::entity::{config, project};
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, Utc};
 use miette::IntoDiagnostic;
 use sea_orm::{
     ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
     sea_query::OnConflict,
 };
-use serde_json::json;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Value as JsonValue, json};
+use uuid::Uuid;
+
+use super::retry::retry_busy;
+use super::todo::BACKLOG_COLUMNS;
+
+const WEEK_START_KEY: &str = "week_start";
+const SELECTION_PULSE_KEY: &str = "selection_pulse";
+const COLLAPSE_DONE_KEY: &str = "collapse_done";
+const SHOW_DONE_KEY: &str = "show_done";
+const WRAP_TITLES_KEY: &str = "wrap_titles";
+const SHOW_NOTE_PREVIEW_KEY: &str = "show_note_preview";
+const WEEKLY_CAPACITY_KEY: &str = "weekly_capacity";
+const DAILY_CAPACITY_KEY: &str = "daily_capacity";
+const CONFIRM_DELETES_KEY: &str = "confirm_deletes";
+const DONE_BACKLOG_COLUMN_KEY: &str = "done_backlog_column";
+const ONBOARDED_KEY: &str = "onboarded";
+const TEMPLATES_KEY: &str = "templates";
+/// Scope `mach archive --list` saves its rows under, for `mach restore`.
+pub const ARCHIVE_LIST_SCOPE: &str = "archive";
+
+/// Scope of the most recent todo listing, the one bare row numbers refer to.
+const LAST_LIST_SCOPE_KEY: &str = "last_list_scope";
+/// Followed by a listing's scope: `last_list:backlog`, `last_list:search`, ...
+const LAST_LIST_KEY_PREFIX: &str = "last_list:";
+const VIEW_SPAN_KEY: &str = "view_span";
+const RESTORE_SESSION_KEY: &str = "restore_session";
+const LAST_VIEW_KEY: &str = "last_view";
+const KEYMAP_KEY: &str = "keymap";
+const THEME_KEY: &str = "theme";
+const THEME_COLORS_KEY: &str = "theme_colors";
+/// Followed by the column number from 0: `backlog_col_0` to `backlog_col_3`.
+const BACKLOG_LABEL_KEY_PREFIX: &str = "backlog_col_";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeekStart {
@@ -20,6 +55,16 @@ impl WeekStart {
             WeekStart::Monday => WeekStart::Sunday,
         }
     }
+
+    /// First day of the week that holds `date`.
+    pub fn week_of(self, date: NaiveDate) -> NaiveDate {
+        let offset = match self {
+            WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+            WeekStart::Monday => date.weekday().num_days_from_monday(),
+        };
+
+        date - ChronoDuration::days(offset as i64)
+    }
 }
 
 impl From<&str> for WeekStart {
@@ -40,6 +85,105 @@ impl WeekStart {
     }
 }
 
+/// Which days the board shows as columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ViewSpan {
+    /// Seven days from the preferred week start.
+    #[default]
+    Week,
+    /// Monday to Friday; paging jumps over the weekend.
+    Weekdays,
+    /// This many days (1 to 7) from today, paged that many at a time.
+    Days(u8),
+}
+
+impl ViewSpan {
+    /// The next span offered by the settings screen.
+    pub fn cycle(self) -> Self {
+        match self {
+            ViewSpan::Week => ViewSpan::Weekdays,
+            ViewSpan::Weekdays => ViewSpan::Days(3),
+            ViewSpan::Days(_) => ViewSpan::Week,
+        }
+    }
+
+    /// Columns on screen.
+    pub fn day_count(self) -> usize {
+        match self {
+            ViewSpan::Week => 7,
+            ViewSpan::Weekdays => 5,
+            ViewSpan::Days(days) => days as usize,
+        }
+    }
+
+    /// Days one page left or right moves the board.
+    pub fn page_days(self) -> i64 {
+        match self {
+            ViewSpan::Week | ViewSpan::Weekdays => 7,
+            ViewSpan::Days(days) => days as i64,
+        }
+    }
+
+    /// Parse `week`, `weekdays` or `days:<1-7>`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "week" => Some(ViewSpan::Week),
+            "weekdays" => Some(ViewSpan::Weekdays),
+            other => other
+                .strip_prefix("days:")
+                .and_then(|days| days.parse::<u8>().ok())
+                .filter(|days| (1..=7).contains(days))
+                .map(ViewSpan::Days),
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            ViewSpan::Week => "week".to_string(),
+            ViewSpan::Weekdays => "weekdays".to_string(),
+            ViewSpan::Days(days) => format!("days:{days}"),
+        }
+    }
+}
+
+/// Capacity used when the weekly meter is first switched on.
+pub const DEFAULT_WEEKLY_CAPACITY: u32 = 20;
+
+/// Limit used when the daily capacity check is first switched on.
+pub const DEFAULT_DAILY_CAPACITY: u32 = 5;
+
+/// A named todo shape expanded by `mach add --template` and `/name` in the
+/// TUI add prompt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TodoTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Project name or id, resolved when the template is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl TodoTemplate {
+    pub fn expand_title(&self, title: &str) -> String {
+        match self.title_prefix.as_deref().map(str::trim_end) {
+            Some(prefix) if !prefix.is_empty() => {
+                format!("{prefix} {}", title.trim()).trim_end().to_string()
+            }
+            _ => title.trim().to_string(),
+        }
+    }
+}
+
+/// Where the board was when the TUI last quit. Dates rather than an offset
+/// from today, so a later launch lands on the same days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastView {
+    pub week_start: NaiveDate,
+    pub focus: NaiveDate,
+}
+
 #[derive(Clone)]
 pub struct ConfigService {
     db: DatabaseConnection,
@@ -51,14 +195,8 @@ impl ConfigService {
     }
 
     pub async fn load_week_start(&self) -> miette::Result<WeekStart> {
-        let result = config::Entity::find()
-            .filter(config::Column::Key.eq("week_start"))
-            .one(&self.db)
-            .await
-            .into_diagnostic()?;
-
-        if let Some(model) = result
-            && let Some(value) = model.value.as_str()
+        if let Some(value) = self.load_value(WEEK_START_KEY).await?
+            && let Some(value) = value.as_str()
         {
             return Ok(WeekStart::from(value));
         }
@@ -67,24 +205,440 @@ impl ConfigService {
     }
 
     pub async fn save_week_start(&self, week_start: WeekStart) -> miette::Result<()> {
+        self.save_value(WEEK_START_KEY, json!(week_start.as_str()))
+            .await
+    }
+
+    /// Whether the drag-mode selection pulses on each tick (on by default).
+    pub async fn load_selection_pulse(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(SELECTION_PULSE_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(true))
+    }
+
+    pub async fn save_selection_pulse(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(SELECTION_PULSE_KEY, json!(enabled)).await
+    }
+
+    /// Whether day columns fold their done todos into one count line (off by
+    /// default).
+    pub async fn load_collapse_done(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(COLLAPSE_DONE_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn save_collapse_done(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(COLLAPSE_DONE_KEY, json!(enabled)).await
+    }
+
+    /// Whether the board and backlog list done todos at all (on by default).
+    pub async fn load_show_done(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(SHOW_DONE_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(true))
+    }
+
+    pub async fn save_show_done(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(SHOW_DONE_KEY, json!(enabled)).await
+    }
+
+    /// Whether long titles wrap onto more lines in board and backlog columns
+    /// (on by default) rather than being cut to one line.
+    pub async fn load_wrap_titles(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(WRAP_TITLES_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(true))
+    }
+
+    pub async fn save_wrap_titles(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(WRAP_TITLES_KEY, json!(enabled)).await
+    }
+
+    /// Whether board and backlog rows show the first line of a todo's notes
+    /// under its title (off by default).
+    pub async fn load_show_note_preview(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(SHOW_NOTE_PREVIEW_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn save_show_note_preview(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(SHOW_NOTE_PREVIEW_KEY, json!(enabled)).await
+    }
+
+    /// Keys remapped by the user, as action name to key names, e.g.
+    /// `{"archive": ["D"], "move_left": ["h", "left"]}`. A single key may be
+    /// given as a plain string. Empty (all defaults) when unset.
+    pub async fn load_keymap(&self) -> miette::Result<BTreeMap<String, Vec<String>>> {
+        let Some(JsonValue::Object(entries)) = self.load_value(KEYMAP_KEY).await? else {
+            return Ok(BTreeMap::new());
+        };
+
+        Ok(entries
+            .into_iter()
+            .map(|(action, keys)| {
+                let keys = match keys {
+                    JsonValue::String(key) => vec![key],
+                    JsonValue::Array(keys) => keys
+                        .into_iter()
+                        .filter_map(|key| key.as_str().map(str::to_string))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+
+                (action, keys)
+            })
+            .collect())
+    }
+
+    /// Name of the TUI color theme; `None` for the default.
+    pub async fn load_theme(&self) -> miette::Result<Option<String>> {
+        Ok(self
+            .load_value(THEME_KEY)
+            .await?
+            .and_then(|value| value.as_str().map(str::to_string)))
+    }
+
+    /// Colors overriding the theme's, as role name to color, e.g.
+    /// `{"focus": "cyan", "border": "#444444"}`. Empty when unset.
+    pub async fn load_theme_colors(&self) -> miette::Result<BTreeMap<String, String>> {
+        let Some(JsonValue::Object(entries)) = self.load_value(THEME_COLORS_KEY).await? else {
+            return Ok(BTreeMap::new());
+        };
+
+        Ok(entries
+            .into_iter()
+            .filter_map(|(role, color)| color.as_str().map(|color| (role, color.to_string())))
+            .collect())
+    }
+
+    /// Pending todos a week is planned to hold; `None` hides the board's
+    /// capacity meter (the default).
+    pub async fn load_weekly_capacity(&self) -> miette::Result<Option<u32>> {
+        Ok(self
+            .load_value(WEEKLY_CAPACITY_KEY)
+            .await?
+            .and_then(|value| value.as_u64())
+            .map(|value| value.min(u32::MAX as u64) as u32))
+    }
+
+    pub async fn save_weekly_capacity(&self, capacity: Option<u32>) -> miette::Result<()> {
+        self.save_value(WEEKLY_CAPACITY_KEY, json!(capacity)).await
+    }
+
+    /// Pending todos a single day should hold; `None` (the default) turns
+    /// off the "day is full" prompt when scheduling onto a day.
+    pub async fn load_daily_capacity(&self) -> miette::Result<Option<u32>> {
+        Ok(self
+            .load_value(DAILY_CAPACITY_KEY)
+            .await?
+            .and_then(|value| value.as_u64())
+            .map(|value| value.min(u32::MAX as u64) as u32))
+    }
+
+    pub async fn save_daily_capacity(&self, capacity: Option<u32>) -> miette::Result<()> {
+        self.save_value(DAILY_CAPACITY_KEY, json!(capacity)).await
+    }
+
+    /// Whether `dd` in the TUI asks before deleting. On by default.
+    pub async fn load_confirm_deletes(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(CONFIRM_DELETES_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(true))
+    }
+
+    pub async fn save_confirm_deletes(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(CONFIRM_DELETES_KEY, json!(enabled)).await
+    }
+
+    pub async fn load_view_span(&self) -> miette::Result<ViewSpan> {
+        Ok(self
+            .load_value(VIEW_SPAN_KEY)
+            .await?
+            .and_then(|value| value.as_str().and_then(ViewSpan::parse))
+            .unwrap_or_default())
+    }
+
+    pub async fn save_view_span(&self, span: ViewSpan) -> miette::Result<()> {
+        self.save_value(VIEW_SPAN_KEY, json!(span.as_string()))
+            .await
+    }
+
+    /// Whether the TUI reopens on the week and day it was left on (off by
+    /// default, starting on today).
+    pub async fn load_restore_session(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(RESTORE_SESSION_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn save_restore_session(&self, enabled: bool) -> miette::Result<()> {
+        self.save_value(RESTORE_SESSION_KEY, json!(enabled)).await
+    }
+
+    pub async fn load_last_view(&self) -> miette::Result<Option<LastView>> {
+        self.get_as(LAST_VIEW_KEY).await
+    }
+
+    pub async fn save_last_view(&self, view: LastView) -> miette::Result<()> {
+        self.set_as(LAST_VIEW_KEY, &view).await
+    }
+
+    /// Backlog lane that `s` files done todos into; `None` (the default)
+    /// leaves done todos where they are.
+    pub async fn load_done_backlog_column(&self) -> miette::Result<Option<usize>> {
+        Ok(self
+            .load_value(DONE_BACKLOG_COLUMN_KEY)
+            .await?
+            .and_then(|value| value.as_u64())
+            .map(|value| value as usize))
+    }
+
+    pub async fn save_done_backlog_column(&self, column: Option<usize>) -> miette::Result<()> {
+        self.save_value(DONE_BACKLOG_COLUMN_KEY, json!(column))
+            .await
+    }
+
+    /// Titles of the backlog columns, in order. Unset or blank labels read
+    /// as `Column N`.
+    pub async fn load_backlog_labels(&self) -> miette::Result<Vec<String>> {
+        let mut labels = Vec::with_capacity(BACKLOG_COLUMNS);
+
+        for col in 0..BACKLOG_COLUMNS {
+            let label = match self.load_value(&backlog_label_key(col)).await? {
+                Some(JsonValue::String(text)) => text.trim().to_string(),
+                // `mach config set backlog_col_0 2026` stores a number.
+                Some(JsonValue::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+
+            labels.push(if label.is_empty() {
+                format!("Column {}", col + 1)
+            } else {
+                label
+            });
+        }
+
+        Ok(labels)
+    }
+
+    /// Title backlog column `col` (from 0); a blank label goes back to
+    /// `Column N`.
+    pub async fn save_backlog_label(&self, col: usize, label: &str) -> miette::Result<()> {
+        if col >= BACKLOG_COLUMNS {
+            miette::bail!(
+                "backlog column {} is out of range (1-{BACKLOG_COLUMNS})",
+                col + 1
+            );
+        }
+
+        self.save_value(&backlog_label_key(col), json!(label.trim()))
+            .await
+    }
+
+    /// Trade the titles of backlog columns `a` and `b`, so a title follows
+    /// its lane when the lane moves. Unset titles stay unset.
+    pub async fn swap_backlog_labels(&self, a: usize, b: usize) -> miette::Result<()> {
+        let label_a = self.load_value(&backlog_label_key(a)).await?;
+        let label_b = self.load_value(&backlog_label_key(b)).await?;
+
+        self.save_value(&backlog_label_key(a), label_b.unwrap_or(JsonValue::Null))
+            .await?;
+        self.save_value(&backlog_label_key(b), label_a.unwrap_or(JsonValue::Null))
+            .await
+    }
+
+    /// Whether the first-run welcome has been dismissed.
+    pub async fn load_onboarded(&self) -> miette::Result<bool> {
+        Ok(self
+            .load_value(ONBOARDED_KEY)
+            .await?
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn save_onboarded(&self) -> miette::Result<()> {
+        self.save_value(ONBOARDED_KEY, json!(true)).await
+    }
+
+    pub async fn load_templates(&self) -> miette::Result<BTreeMap<String, TodoTemplate>> {
+        let Some(value) = self.load_value(TEMPLATES_KEY).await? else {
+            return Ok(BTreeMap::new());
+        };
+
+        serde_json::from_value(value).into_diagnostic()
+    }
+
+    pub async fn load_template(&self, name: &str) -> miette::Result<Option<TodoTemplate>> {
+        Ok(self.load_templates().await?.remove(&name.to_lowercase()))
+    }
+
+    /// Create or replace the template called `name` (case-insensitive).
+    pub async fn save_template(&self, name: &str, template: TodoTemplate) -> miette::Result<()> {
+        let name = name.trim().to_lowercase();
+
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            miette::bail!("template names must be a single word");
+        }
+
+        let mut templates = self.load_templates().await?;
+        templates.insert(name, template);
+
+        self.save_value(TEMPLATES_KEY, json!(templates)).await
+    }
+
+    /// Returns `false` when no template had that name.
+    pub async fn remove_template(&self, name: &str) -> miette::Result<bool> {
+        let mut templates = self.load_templates().await?;
+
+        if templates.remove(&name.to_lowercase()).is_none() {
+            return Ok(false);
+        }
+
+        self.save_value(TEMPLATES_KEY, json!(templates)).await?;
+
+        Ok(true)
+    }
+
+    /// Point templates that name project `old` at its new name `new`.
+    /// Returns how many templates changed.
+    pub async fn rename_template_project(
+        &self,
+        old: &project::Model,
+        new: &str,
+    ) -> miette::Result<usize> {
+        let mut templates = self.load_templates().await?;
+        let mut renamed = 0;
+
+        // Templates that name the project by id keep working as they are.
+        for template in templates.values_mut() {
+            if let Some(project) = &template.project
+                && project.trim().eq_ignore_ascii_case(&old.name)
+            {
+                template.project = Some(new.to_string());
+                renamed += 1;
+            }
+        }
+
+        if renamed > 0 {
+            self.save_value(TEMPLATES_KEY, json!(templates)).await?;
+        }
+
+        Ok(renamed)
+    }
+
+    /// Ids of the rows last printed for `scope` (see `save_last_list`), in
+    /// row order.
+    pub async fn load_last_list(&self, scope: &str) -> miette::Result<Vec<Uuid>> {
+        Ok(self
+            .get_as(&format!("{LAST_LIST_KEY_PREFIX}{scope}"))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Remember the rows a listing printed, under its `scope` (`backlog`, a
+    /// day, `search`, ...). Every scope but `ARCHIVE_LIST_SCOPE` also becomes
+    /// the one bare row numbers refer to, since archived rows can't be acted
+    /// on.
+    pub async fn save_last_list(&self, scope: &str, ids: &[Uuid]) -> miette::Result<()> {
+        self.set_as(&format!("{LAST_LIST_KEY_PREFIX}{scope}"), &ids)
+            .await?;
+
+        if scope != ARCHIVE_LIST_SCOPE {
+            self.save_value(LAST_LIST_SCOPE_KEY, json!(scope)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Scope of the most recent todo listing, if there has been one.
+    pub async fn last_list_scope(&self) -> miette::Result<Option<String>> {
+        self.get_as(LAST_LIST_SCOPE_KEY).await
+    }
+
+    /// Any stored setting as text: strings as they are, other values as
+    /// JSON (`true`, `20`, `null`).
+    pub async fn get(&self, key: &str) -> miette::Result<Option<String>> {
+        Ok(self.load_value(key).await?.map(|value| match value {
+            JsonValue::String(text) => text,
+            other => other.to_string(),
+        }))
+    }
+
+    /// Store `value` under `key`. Text that parses as JSON is stored as that
+    /// value, so `set("confirm_deletes", "false")` writes the same boolean
+    /// `save_confirm_deletes(false)` does; anything else is stored as a
+    /// string.
+    pub async fn set(&self, key: &str, value: &str) -> miette::Result<()> {
+        let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+
+        self.save_value(key, value).await
+    }
+
+    /// A setting decoded as `T`; `None` when it is unset or has another shape.
+    pub async fn get_as<T: DeserializeOwned>(&self, key: &str) -> miette::Result<Option<T>> {
+        Ok(self
+            .load_value(key)
+            .await?
+            .and_then(|value| serde_json::from_value(value).ok()))
+    }
+
+    pub async fn set_as<T: Serialize>(&self, key: &str, value: &T) -> miette::Result<()> {
+        let value = serde_json::to_value(value).into_diagnostic()?;
+
+        self.save_value(key, value).await
+    }
+
+    async fn load_value(&self, key: &str) -> miette::Result<Option<JsonValue>> {
+        let result = config::Entity::find()
+            .filter(config::Column::Key.eq(key))
+            .one(&self.db)
+            .await
+            .into_diagnostic()?;
+
+        Ok(result.map(|model| model.value))
+    }
+
+    async fn save_value(&self, key: &str, value: JsonValue) -> miette::Result<()> {
         let now = Utc::now();
         let model = config::ActiveModel {
-            key: Set("week_start".to_string()),
-            value: Set(json!(week_start.as_str())),
+            key: Set(key.to_string()),
+            value: Set(value),
             created_at: Set(now),
             updated_at: Set(now),
         };
 
-        config::Entity::insert(model)
-            .on_conflict(
-                OnConflict::column(config::Column::Key)
-                    .update_columns([config::Column::Value, config::Column::UpdatedAt])
-                    .to_owned(),
-            )
-            .exec(&self.db)
-            .await
-            .into_diagnostic()?;
+        retry_busy(|| {
+            config::Entity::insert(model.clone())
+                .on_conflict(
+                    OnConflict::column(config::Column::Key)
+                        .update_columns([config::Column::Value, config::Column::UpdatedAt])
+                        .to_owned(),
+                )
+                .exec(&self.db)
+        })
+        .await
+        .into_diagnostic()?;
 
         Ok(())
     }
 }
+
+fn backlog_label_key(col: usize) -> String {
+    format!("{BACKLOG_LABEL_KEY_PREFIX}{col}")
+}
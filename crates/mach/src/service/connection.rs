@@ -1,10 +1,19 @@
 use std::path::Path;
 
-use miette::{Context, IntoDiagnostic};
-use sea_orm::{Database, DatabaseConnection};
+use miette::{Context, IntoDiagnostic, bail};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbBackend, EntityTrait,
+    IdenStatic, Iterable, Statement,
+};
 use tokio::fs;
 use tokio::fs::OpenOptions;
 
+use crate::entity::{config, project, todo, workspace};
+
+/// Set to refuse to start (instead of warning) when the database has columns
+/// this binary doesn't know about.
+const STRICT_SCHEMA_ENV: &str = "MACH_STRICT_SCHEMA";
+
 /// Initialize the local SQLite database file and return a SeaORM connection.
 pub async fn init_database(path: impl AsRef<Path>) -> miette::Result<DatabaseConnection> {
     let path = path.as_ref();
@@ -32,15 +41,102 @@ pub async fn init_database(path: impl AsRef<Path>) -> miette::Result<DatabaseCon
         .into_diagnostic()
         .wrap_err("failed to open SeaORM SQLite connection")?;
 
-    conn.get_schema_registry("machich::entity::*")
-        .sync(&conn)
+    sync_schema(&conn).await?;
+
+    check_unknown_columns(&conn).await?;
+
+    Ok(conn)
+}
+
+/// Open a throwaway in-memory database with the schema in place, for tests.
+pub async fn init_memory_database() -> miette::Result<DatabaseConnection> {
+    let mut options = ConnectOptions::new("sqlite::memory:");
+
+    // Every pooled connection would otherwise get its own empty database.
+    options.max_connections(1).sqlx_logging(false);
+
+    let conn = Database::connect(options)
         .await
         .into_diagnostic()
-        .wrap_err("failed to synchronize schema via SeaORM entity registry")?;
+        .wrap_err("failed to open in-memory SQLite connection")?;
+
+    sync_schema(&conn).await?;
 
     Ok(conn)
 }
 
+async fn sync_schema(conn: &DatabaseConnection) -> miette::Result<()> {
+    conn.get_schema_registry("machich::entity::*")
+        .sync(conn)
+        .await
+        .into_diagnostic()
+        .wrap_err("failed to synchronize schema via SeaORM entity registry")
+}
+
+/// Schema sync only adds columns, so extra ones mean a newer mach has written
+/// to this database. Warn (or refuse in strict mode) so the user upgrades.
+async fn check_unknown_columns(conn: &DatabaseConnection) -> miette::Result<()> {
+    let mut unknown = Vec::new();
+
+    unknown.extend(unknown_columns::<config::Entity>(conn).await?);
+    unknown.extend(unknown_columns::<project::Entity>(conn).await?);
+    unknown.extend(unknown_columns::<todo::Entity>(conn).await?);
+    unknown.extend(unknown_columns::<workspace::Entity>(conn).await?);
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let columns = unknown.join(", ");
+
+    if std::env::var_os(STRICT_SCHEMA_ENV).is_some() {
+        bail!(
+            help =
+                format!("upgrade mach, or unset {STRICT_SCHEMA_ENV} to use the database as it is"),
+            "the database has columns this version of mach doesn't know about ({columns}); \
+             it was probably written by a newer mach"
+        );
+    }
+
+    eprintln!(
+        "warning: the database has columns this version of mach doesn't know about ({columns}); \
+         it was probably written by a newer mach, consider upgrading"
+    );
+
+    Ok(())
+}
+
+async fn unknown_columns<E>(conn: &DatabaseConnection) -> miette::Result<Vec<String>>
+where
+    E: EntityTrait + Default,
+{
+    let table = E::default().table_name().to_string();
+
+    let rows = conn
+        .query_all_raw(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            "SELECT name FROM pragma_table_info(?)",
+            [table.clone().into()],
+        ))
+        .await
+        .into_diagnostic()
+        .wrap_err_with(|| format!("failed to inspect columns of {table}"))?;
+
+    let known: Vec<&str> = E::Column::iter().map(|col| col.as_str()).collect();
+
+    let mut unknown = Vec::new();
+
+    for row in rows {
+        let name: String = row.try_get("", "name").into_diagnostic()?;
+
+        if !known.contains(&name.as_str()) {
+            unknown.push(format!("{table}.{name}"));
+        }
+    }
+
+    Ok(unknown)
+}
+
 fn sqlite_url(path: &str) -> String {
     format!("sqlite://{path}?mode=rwc")
 }
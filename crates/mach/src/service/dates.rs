@@ -0,0 +1,164 @@
+use chrono::{Datelike, Days, Duration as ChronoDuration, NaiveDate, Weekday};
+
+use super::config::WeekStart;
+
+/// Read a day typed relative to `today`: `today`, `tomorrow`, a weekday
+/// (`fri` is the next Friday after today), `next mon`, `next week` (the first
+/// day of the following week), or an offset such as `+3`, `-1` or `+2w`.
+pub fn parse_relative_date(
+    input: &str,
+    today: NaiveDate,
+    week_start: WeekStart,
+) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+
+    match input.as_str() {
+        "today" | "tod" => return Some(today),
+        "tomorrow" | "tom" | "tmr" => return today.succ_opt(),
+        "yesterday" => return today.pred_opt(),
+        "next week" => return week_start.week_of(today).checked_add_days(Days::new(7)),
+        _ => {}
+    }
+
+    if let Some(rest) = input.strip_prefix("next ") {
+        let weekday = rest.trim().parse::<Weekday>().ok()?;
+        let next_week = week_start.week_of(today).checked_add_days(Days::new(7))?;
+
+        return next_week.checked_add_days(Days::new(days_until(next_week.weekday(), weekday)));
+    }
+
+    if let Ok(weekday) = input.parse::<Weekday>() {
+        let ahead = match days_until(today.weekday(), weekday) {
+            0 => 7,
+            days => days,
+        };
+
+        return today.checked_add_days(Days::new(ahead));
+    }
+
+    today.checked_add_signed(parse_offset(&input)?)
+}
+
+/// Relative date first, then a strict `YYYY-MM-DD`.
+pub fn parse_date_input(input: &str, today: NaiveDate, week_start: WeekStart) -> Option<NaiveDate> {
+    parse_relative_date(input, today, week_start)
+        .or_else(|| NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d").ok())
+}
+
+/// `+3`, `-1`, `+4d`, `+2w`. `None` when the count is too large for a
+/// duration.
+fn parse_offset(input: &str) -> Option<ChronoDuration> {
+    let (sign, rest) = if let Some(rest) = input.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = input.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+
+    let (digits, unit) = match rest.strip_suffix('w') {
+        Some(digits) => (digits, 7),
+        None => (rest.strip_suffix('d').unwrap_or(rest), 1),
+    };
+
+    let count: i64 = digits.parse().ok()?;
+
+    ChronoDuration::try_days(count.checked_mul(sign * unit)?)
+}
+
+fn days_until(from: Weekday, to: Weekday) -> u64 {
+    (to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64).rem_euclid(7) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wednesday.
+    fn today() -> NaiveDate {
+        day(3)
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+    }
+
+    fn parse(input: &str) -> Option<NaiveDate> {
+        parse_date_input(input, today(), WeekStart::Monday)
+    }
+
+    #[test]
+    fn named_days() {
+        assert_eq!(parse("today"), Some(today()));
+        assert_eq!(parse("tomorrow"), Some(day(4)));
+        assert_eq!(parse("tmr"), Some(day(4)));
+        assert_eq!(parse("yesterday"), Some(day(2)));
+        assert_eq!(parse("  Tomorrow "), Some(day(4)));
+    }
+
+    #[test]
+    fn weekdays_are_the_next_one_after_today() {
+        assert_eq!(parse("fri"), Some(day(5)));
+        assert_eq!(parse("monday"), Some(day(8)));
+        assert_eq!(parse("Wed"), Some(day(10)));
+    }
+
+    #[test]
+    fn next_counts_from_the_following_week() {
+        assert_eq!(parse("next week"), Some(day(8)));
+        assert_eq!(parse("next mon"), Some(day(8)));
+        assert_eq!(parse("next fri"), Some(day(12)));
+
+        let sunday_first = |input| parse_date_input(input, today(), WeekStart::Sunday);
+
+        assert_eq!(sunday_first("next week"), Some(day(7)));
+        assert_eq!(sunday_first("next fri"), Some(day(12)));
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse("+3"), Some(day(6)));
+        assert_eq!(parse("-1"), Some(day(2)));
+        assert_eq!(parse("+4d"), Some(day(7)));
+        assert_eq!(parse("+2w"), Some(day(17)));
+        assert_eq!(parse("-0"), Some(today()));
+    }
+
+    #[test]
+    fn offsets_past_the_calendar_are_rejected() {
+        for input in [
+            "+100000000",
+            "-100000000",
+            "+9999999999999999",
+            "+9999999999999999w",
+            "+9223372036854775807",
+        ] {
+            assert_eq!(parse(input), None, "'{input}'");
+        }
+    }
+
+    #[test]
+    fn iso_dates_are_the_fallback() {
+        assert_eq!(parse("2026-07-01"), NaiveDate::from_ymd_opt(2026, 7, 1));
+        assert_eq!(
+            parse_relative_date("2026-07-01", today(), WeekStart::Monday),
+            None
+        );
+    }
+
+    #[test]
+    fn anything_else_is_rejected() {
+        for input in [
+            "",
+            "someday",
+            "+",
+            "+x",
+            "+3y",
+            "next",
+            "next blah",
+            "2026-13-01",
+        ] {
+            assert_eq!(parse(input), None, "'{input}'");
+        }
+    }
+}
@@ -1,20 +1,26 @@
 pub mod config;
 pub mod connection;
+pub mod dates;
 pub mod project;
+pub mod recurrence;
+mod retry;
 pub mod todo;
 pub mod workspace;
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use chrono::{Local, NaiveDate};
+use chrono::{Duration as ChronoDuration, Local, NaiveDate};
 use directories::ProjectDirs;
 use miette::{Context, IntoDiagnostic};
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
 
 use self::{
-    config::{ConfigService, WeekStart},
+    config::{ConfigService, LastView, ViewSpan, WeekStart},
     connection::init_database,
     project::ProjectService,
-    todo::TodoService,
+    todo::{MovePlacement, NewTodo, TodoService},
     workspace::WorkspaceService,
 };
 
@@ -26,6 +32,24 @@ pub struct Services {
     pub projects: ProjectService,
     today: NaiveDate,
     week_start_pref: WeekStart,
+    view_span: ViewSpan,
+    restore_session: bool,
+    last_view: Option<LastView>,
+    selection_pulse: bool,
+    collapse_done: bool,
+    show_done: bool,
+    wrap_titles: bool,
+    show_note_preview: bool,
+    keymap: BTreeMap<String, Vec<String>>,
+    theme: Option<String>,
+    theme_colors: BTreeMap<String, String>,
+    backlog_labels: Vec<String>,
+    weekly_capacity: Option<u32>,
+    daily_capacity: Option<u32>,
+    done_backlog_column: Option<usize>,
+    confirm_deletes: bool,
+    first_run: bool,
+    rolled_over: Vec<Uuid>,
 }
 
 impl Services {
@@ -34,6 +58,11 @@ impl Services {
 
         let conn = init_database(&db_path).await?;
 
+        Self::from_connection(conn).await
+    }
+
+    /// Load the services and settings over an already open database.
+    pub async fn from_connection(conn: DatabaseConnection) -> miette::Result<Self> {
         let todos = TodoService::new(conn.clone());
         let config = ConfigService::new(conn.clone());
         let workspaces = WorkspaceService::new(conn.clone());
@@ -41,8 +70,28 @@ impl Services {
 
         let today = Local::now().date_naive();
 
-        todos.rollover_to(today).await?;
+        let rolled_over = todos.rollover_to(today).await?;
         let week_start = config.load_week_start().await?;
+        let view_span = config.load_view_span().await?;
+        let restore_session = config.load_restore_session().await?;
+        let last_view = config.load_last_view().await?;
+        let selection_pulse = config.load_selection_pulse().await?;
+        let collapse_done = config.load_collapse_done().await?;
+        let show_done = config.load_show_done().await?;
+        let wrap_titles = config.load_wrap_titles().await?;
+        let show_note_preview = config.load_show_note_preview().await?;
+        let keymap = config.load_keymap().await?;
+        let theme = config.load_theme().await?;
+        let theme_colors = config.load_theme_colors().await?;
+        let backlog_labels = config.load_backlog_labels().await?;
+        let weekly_capacity = config.load_weekly_capacity().await?;
+        let daily_capacity = config.load_daily_capacity().await?;
+        let done_backlog_column = config.load_done_backlog_column().await?;
+        let confirm_deletes = config.load_confirm_deletes().await?;
+
+        let first_run = !config.load_onboarded().await?
+            && todos.count().await? == 0
+            && workspaces.list().await?.is_empty();
 
         Ok(Self {
             todos,
@@ -51,6 +100,24 @@ impl Services {
             projects,
             today,
             week_start_pref: week_start,
+            view_span,
+            restore_session,
+            last_view,
+            selection_pulse,
+            collapse_done,
+            show_done,
+            wrap_titles,
+            show_note_preview,
+            keymap,
+            theme,
+            theme_colors,
+            backlog_labels,
+            weekly_capacity,
+            daily_capacity,
+            done_backlog_column,
+            confirm_deletes,
+            first_run,
+            rolled_over,
         })
     }
 
@@ -58,9 +125,166 @@ impl Services {
         self.today
     }
 
+    /// Re-read the local date, for a TUI left open past midnight. Returns
+    /// whether it changed.
+    pub fn refresh_today(&mut self) -> bool {
+        let today = Local::now().date_naive();
+        let changed = today != self.today;
+
+        self.today = today;
+
+        changed
+    }
+
+    /// Re-read the backlog column titles after they change under the TUI.
+    pub async fn reload_backlog_labels(&mut self) -> miette::Result<()> {
+        self.backlog_labels = self.config.load_backlog_labels().await?;
+
+        Ok(())
+    }
+
     pub fn week_start(&self) -> WeekStart {
         self.week_start_pref
     }
+
+    pub fn view_span(&self) -> ViewSpan {
+        self.view_span
+    }
+
+    pub fn restore_session(&self) -> bool {
+        self.restore_session
+    }
+
+    /// Where the TUI was left, saved on quit while `restore_session` is on.
+    pub fn last_view(&self) -> Option<LastView> {
+        self.last_view
+    }
+
+    pub fn selection_pulse(&self) -> bool {
+        self.selection_pulse
+    }
+
+    pub fn collapse_done(&self) -> bool {
+        self.collapse_done
+    }
+
+    pub fn show_done(&self) -> bool {
+        self.show_done
+    }
+
+    pub fn wrap_titles(&self) -> bool {
+        self.wrap_titles
+    }
+
+    pub fn show_note_preview(&self) -> bool {
+        self.show_note_preview
+    }
+
+    /// Remapped keys by action name; see `ConfigService::load_keymap`.
+    pub fn keymap(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.keymap
+    }
+
+    pub fn theme(&self) -> Option<&str> {
+        self.theme.as_deref()
+    }
+
+    /// Per-color overrides of the theme; see `ConfigService::load_theme_colors`.
+    pub fn theme_colors(&self) -> &BTreeMap<String, String> {
+        &self.theme_colors
+    }
+
+    /// One title per backlog column; see `ConfigService::load_backlog_labels`.
+    pub fn backlog_labels(&self) -> &[String] {
+        &self.backlog_labels
+    }
+
+    pub fn weekly_capacity(&self) -> Option<u32> {
+        self.weekly_capacity
+    }
+
+    pub fn daily_capacity(&self) -> Option<u32> {
+        self.daily_capacity
+    }
+
+    pub fn done_backlog_column(&self) -> Option<usize> {
+        self.done_backlog_column
+    }
+
+    pub fn confirm_deletes(&self) -> bool {
+        self.confirm_deletes
+    }
+
+    /// A brand-new, empty database that hasn't seen the welcome screen yet.
+    pub fn first_run(&self) -> bool {
+        self.first_run
+    }
+
+    /// Todos moved to today by this launch's rollover, oldest day first.
+    pub fn rolled_over(&self) -> &[Uuid] {
+        &self.rolled_over
+    }
+
+    /// Rename a project and point templates that named it at the new name.
+    /// Todos link to projects by id, so they need no update. Returns the
+    /// renamed project and how many templates changed.
+    pub async fn rename_project(
+        &self,
+        id: Uuid,
+        name: impl Into<String>,
+    ) -> miette::Result<(crate::entity::project::Model, usize)> {
+        let old = self
+            .projects
+            .get(id)
+            .await?
+            .ok_or_else(|| miette::miette!("project not found"))?;
+
+        let renamed = self.projects.update_name(id, name).await?;
+
+        let templates = self
+            .config
+            .rename_template_project(&old, &renamed.name)
+            .await?;
+
+        Ok((renamed, templates))
+    }
+
+    /// Fill an empty database with a sample workspace, project and todos
+    /// that walk through the basic keys.
+    pub async fn seed_sample(&self) -> miette::Result<()> {
+        let workspace = self.workspaces.create("Personal").await?;
+        let project = self
+            .projects
+            .create("Getting Started", workspace.id, "pending")
+            .await?;
+
+        let today = Some(self.today);
+        let tomorrow = Some(self.today + ChronoDuration::days(1));
+
+        let samples = [
+            ("Press x to complete me", today),
+            ("Press Space to open my details", today),
+            ("Press Enter, then h/l to drag me", tomorrow),
+            ("Press b to see me in the backlog", None),
+        ];
+
+        for (title, scheduled_for) in samples {
+            self.todos
+                .add_with_placement(
+                    title,
+                    scheduled_for,
+                    MovePlacement::Bottom,
+                    NewTodo {
+                        workspace_id: Some(workspace.id),
+                        project_id: Some(project.id),
+                        ..NewTodo::default()
+                    },
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
 }
 
 fn default_db_path() -> miette::Result<PathBuf> {
@@ -1,11 +1,13 @@
 use crate::entity::project;
-use miette::{IntoDiagnostic, Result};
+use miette::{IntoDiagnostic, Result, bail};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
-    QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    Set, sea_query::Expr,
 };
 use uuid::Uuid;
 
+use super::retry::retry_busy;
+
 #[derive(Clone)]
 pub struct ProjectService {
     db: DatabaseConnection,
@@ -16,13 +18,24 @@ impl ProjectService {
         Self { db }
     }
 
+    /// Find a project by id or name. Names are matched trimmed and
+    /// case-insensitively, so `MyApp`, `myapp` and ` myapp ` are one project.
     pub async fn find_by_name_or_id(&self, name_or_id: &str) -> Result<Option<project::Model>> {
+        let name_or_id = name_or_id.trim();
+
+        if let Ok(id) = Uuid::parse_str(name_or_id)
+            && let Some(model) = self.get(id).await?
+        {
+            return Ok(Some(model));
+        }
+
+        // Both sides go through SQLite's LOWER, which only folds ASCII, so a
+        // name outside ASCII still matches itself exactly.
         project::Entity::find()
-            .filter(
-                Condition::any()
-                    .add(project::Column::Id.eq(name_or_id))
-                    .add(project::Column::Name.eq(name_or_id)),
-            )
+            .filter(Expr::cust_with_values(
+                "LOWER(name) = LOWER(?)",
+                [name_or_id],
+            ))
             .one(&self.db)
             .await
             .into_diagnostic()
@@ -34,15 +47,19 @@ impl ProjectService {
         workspace_id: Uuid,
         status: impl Into<String>,
     ) -> Result<project::Model> {
+        let name = self.normalize_new_name(name.into(), None).await?;
+
         let model = project::ActiveModel {
             id: Set(Uuid::new_v4()),
-            name: Set(name.into()),
+            name: Set(name),
             workspace_id: Set(workspace_id),
             status: Set(status.into()),
             ..Default::default()
         };
 
-        model.insert(&self.db).await.into_diagnostic()
+        retry_busy(|| model.clone().insert(&self.db))
+            .await
+            .into_diagnostic()
     }
 
     pub async fn list(&self) -> Result<Vec<project::Model>> {
@@ -76,6 +93,8 @@ impl ProjectService {
     }
 
     pub async fn update_name(&self, id: Uuid, name: impl Into<String>) -> Result<project::Model> {
+        let name = self.normalize_new_name(name.into(), Some(id)).await?;
+
         let model = project::Entity::find_by_id(id)
             .one(&self.db)
             .await
@@ -83,8 +102,10 @@ impl ProjectService {
             .ok_or_else(|| miette::miette!("project not found"))?;
 
         let mut active: project::ActiveModel = model.into();
-        active.name = Set(name.into());
-        active.update(&self.db).await.into_diagnostic()
+        active.name = Set(name);
+        retry_busy(|| active.clone().update(&self.db))
+            .await
+            .into_diagnostic()
     }
 
     pub async fn update_status(
@@ -100,6 +121,36 @@ impl ProjectService {
 
         let mut active: project::ActiveModel = model.into();
         active.status = Set(status.into());
-        active.update(&self.db).await.into_diagnostic()
+        retry_busy(|| active.clone().update(&self.db))
+            .await
+            .into_diagnostic()
+    }
+
+    /// Delete a project. Todos still pointing at it must be reassigned or
+    /// cleared first (see `TodoService::reassign_project`).
+    pub async fn delete(&self, id: Uuid) -> Result<bool> {
+        let res = retry_busy(|| project::Entity::delete_by_id(id).exec(&self.db))
+            .await
+            .into_diagnostic()?;
+
+        Ok(res.rows_affected > 0)
+    }
+
+    /// Trim a new project name (case is kept as typed) and reject names that
+    /// only differ from an existing project by case or surrounding spaces.
+    async fn normalize_new_name(&self, name: String, id: Option<Uuid>) -> Result<String> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            bail!("project name cannot be empty");
+        }
+
+        if let Some(existing) = self.find_by_name_or_id(&name).await?
+            && Some(existing.id) != id
+        {
+            bail!("project '{}' already exists", existing.name);
+        }
+
+        Ok(name)
     }
 }
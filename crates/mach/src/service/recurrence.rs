@@ -0,0 +1,177 @@
+use std::fmt;
+
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, Weekday};
+use miette::{Result, bail};
+
+/// Longest `every:` interval, in days: ten years is plenty, and it keeps the
+/// date arithmetic far from chrono's limits.
+const MAX_INTERVAL_DAYS: u32 = 3660;
+
+/// How a todo repeats once it is done: `daily`, `weekly`, `weekdays`, or
+/// `every:<n>d` / `every:<n>w`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    Daily,
+    Weekly,
+    Weekdays,
+    EveryDays(u32),
+}
+
+impl Recurrence {
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim().to_lowercase();
+
+        let rule = match input.as_str() {
+            "daily" => Self::Daily,
+            "weekly" => Self::Weekly,
+            "weekdays" => Self::Weekdays,
+            other => {
+                let Some(spec) = other.strip_prefix("every:") else {
+                    bail!(
+                        "unknown recurrence '{}' (expected daily, weekly, weekdays or every:<n>d)",
+                        input
+                    );
+                };
+
+                let (count, unit) = match spec.strip_suffix('w') {
+                    Some(count) => (count, 7),
+                    None => (spec.strip_suffix('d').unwrap_or(spec), 1),
+                };
+
+                let days = match count.parse::<u32>() {
+                    Ok(count) if count > 0 => count.checked_mul(unit),
+                    _ => bail!("'{}' needs a positive interval, e.g. every:3d", input),
+                };
+
+                match days {
+                    Some(days) if days <= MAX_INTERVAL_DAYS => Self::EveryDays(days),
+                    _ => bail!(
+                        "'{}' repeats too far apart (at most {} days)",
+                        input,
+                        MAX_INTERVAL_DAYS
+                    ),
+                }
+            }
+        };
+
+        Ok(rule)
+    }
+
+    /// First occurrence after both `today` and the day the todo was
+    /// `scheduled` for. Fixed intervals count from `scheduled`, so a weekly
+    /// todo keeps its weekday even when finished late; `weekdays` skips
+    /// Saturday and Sunday. `None` past the last date chrono can represent.
+    pub fn next_after(self, scheduled: NaiveDate, today: NaiveDate) -> Option<NaiveDate> {
+        let base = scheduled.max(today);
+
+        match self {
+            Self::Daily => base.succ_opt(),
+            Self::Weekdays => {
+                let mut next = base.succ_opt()?;
+
+                while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+                    next = next.succ_opt()?;
+                }
+
+                Some(next)
+            }
+            Self::Weekly => step_past(scheduled, base, 7),
+            Self::EveryDays(days) => step_past(scheduled, base, days as i64),
+        }
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily => f.write_str("daily"),
+            Self::Weekly => f.write_str("weekly"),
+            Self::Weekdays => f.write_str("weekdays"),
+            Self::EveryDays(days) => write!(f, "every:{days}d"),
+        }
+    }
+}
+
+/// `from` plus the fewest whole `days` steps that land after `limit`.
+fn step_past(from: NaiveDate, limit: NaiveDate, days: i64) -> Option<NaiveDate> {
+    let steps = (limit - from).num_days().max(0) / days + 1;
+
+    from.checked_add_signed(ChronoDuration::try_days(steps.checked_mul(days)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+    }
+
+    #[test]
+    fn parses_rules_and_intervals() {
+        assert_eq!(Recurrence::parse("Daily").unwrap(), Recurrence::Daily);
+        assert_eq!(
+            Recurrence::parse("every:3d").unwrap(),
+            Recurrence::EveryDays(3)
+        );
+        assert_eq!(
+            Recurrence::parse("every:2w").unwrap(),
+            Recurrence::EveryDays(14)
+        );
+        assert_eq!(
+            Recurrence::parse("every:5").unwrap(),
+            Recurrence::EveryDays(5)
+        );
+    }
+
+    #[test]
+    fn refuses_empty_and_huge_intervals() {
+        for rule in [
+            "every:0d",
+            "every:-1d",
+            "every:4000000000d",
+            "every:700000000w",
+            "every:3661d",
+            "monthly",
+        ] {
+            assert!(Recurrence::parse(rule).is_err(), "'{rule}'");
+        }
+
+        assert_eq!(
+            Recurrence::parse("every:3660d").unwrap(),
+            Recurrence::EveryDays(MAX_INTERVAL_DAYS)
+        );
+    }
+
+    #[test]
+    fn fixed_intervals_count_from_the_scheduled_day() {
+        // Scheduled on the 1st, finished late on the 9th.
+        assert_eq!(Recurrence::Weekly.next_after(day(1), day(9)), Some(day(15)));
+        assert_eq!(
+            Recurrence::EveryDays(3).next_after(day(1), day(1)),
+            Some(day(4))
+        );
+        assert_eq!(Recurrence::Daily.next_after(day(1), day(9)), Some(day(10)));
+    }
+
+    #[test]
+    fn weekdays_skip_the_weekend() {
+        // 2026-06-05 is a Friday.
+        assert_eq!(
+            Recurrence::Weekdays.next_after(day(5), day(5)),
+            Some(day(8))
+        );
+    }
+
+    #[test]
+    fn no_next_date_past_the_end_of_the_calendar() {
+        let last = NaiveDate::MAX;
+
+        assert_eq!(Recurrence::Daily.next_after(last, last), None);
+        assert_eq!(Recurrence::Weekdays.next_after(last, last), None);
+        assert_eq!(
+            Recurrence::EveryDays(MAX_INTERVAL_DAYS).next_after(last, last),
+            None
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::future::Future;
+use std::time::Duration;
+
+use sea_orm::DbErr;
+
+/// Attempts made before a busy error is surfaced to the caller.
+const MAX_ATTEMPTS: u32 = 5;
+
+/// First backoff delay; doubled after every failed attempt.
+const BASE_DELAY: Duration = Duration::from_millis(25);
+
+/// Run a database operation, retrying with exponential backoff while another
+/// connection (a second TUI, a script) holds the SQLite write lock. Any other
+/// error, or a busy error on the last attempt, is returned as is.
+///
+/// `op` must be safe to run again: a single statement, or a whole
+/// transaction.
+pub async fn retry_busy<T, F, Fut>(mut op: F) -> Result<T, DbErr>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        match op().await {
+            Err(err) if attempt < MAX_ATTEMPTS && is_busy(&err) => {
+                tokio::time::sleep(BASE_DELAY * 2u32.pow(attempt - 1)).await;
+
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// SQLITE_BUSY / SQLITE_LOCKED as reported through sqlx.
+fn is_busy(err: &DbErr) -> bool {
+    let message = err.to_string();
+
+    message.contains("database is locked")
+        || message.contains("database table is locked")
+        || message.contains("database is busy")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn locked() -> DbErr {
+        DbErr::Custom("database is locked".to_string())
+    }
+
+    #[tokio::test]
+    async fn retries_until_the_lock_clears() {
+        let attempts = Cell::new(0);
+
+        let result = retry_busy(|| {
+            attempts.set(attempts.get() + 1);
+
+            let outcome = if attempts.get() < 3 {
+                Err(locked())
+            } else {
+                Ok(42)
+            };
+
+            async move { outcome }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_the_last_attempt() {
+        let attempts = Cell::new(0);
+
+        let result: Result<(), DbErr> = retry_busy(|| {
+            attempts.set(attempts.get() + 1);
+
+            async { Err(locked()) }
+        })
+        .await;
+
+        assert!(is_busy(&result.unwrap_err()));
+        assert_eq!(attempts.get(), MAX_ATTEMPTS);
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_not_retried() {
+        let attempts = Cell::new(0);
+
+        let result: Result<(), DbErr> = retry_busy(|| {
+            attempts.set(attempts.get() + 1);
+
+            async { Err(DbErr::Custom("no such table: todos".to_string())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn recognises_busy_and_locked_errors() {
+        assert!(is_busy(&locked()));
+        assert!(is_busy(&DbErr::Custom(
+            "database table is locked".to_string()
+        )));
+        assert!(is_busy(&DbErr::Custom("database is busy".to_string())));
+        assert!(!is_busy(&DbErr::Custom(
+            "UNIQUE constraint failed".to_string()
+        )));
+    }
+}
@@ -1,20 +1,68 @@
-use crate::entity::todo;
-use chrono::NaiveDate;
+use crate::entity::{project, todo, workspace};
+use chrono::{DateTime, Duration as ChronoDuration, Local, NaiveDate, Utc};
 use miette::{IntoDiagnostic, Result, bail};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, QueryFilter,
-    QueryOrder, Set, sea_query::Expr,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait, sea_query::Expr,
 };
 use serde_json::Value as JsonValue;
+use std::collections::HashSet;
 use uuid::Uuid;
 
+use super::recurrence::Recurrence;
+use super::retry::retry_busy;
+
 const STATUS_DONE: &str = "done";
 
+/// Highest priority a todo can carry; 0 means none.
+pub const MAX_PRIORITY: i32 = 3;
+/// Columns the backlog is split into; `backlog_column` counts from 0.
+pub const BACKLOG_COLUMNS: usize = 4;
+const META_PREV_ORDER_INDEX: &str = "prev_order_index";
+const META_ROLLED_OVER_FROM: &str = "rolled_over_from";
+/// On a done repeating todo: the copy spawned for its next occurrence.
+const META_SPAWNED_ID: &str = "spawned_id";
+
+/// Spacing between neighbouring `order_index` values. Leaving room lets a todo
+/// slot in between two others without renumbering the column.
+pub const ORDER_GAP: i64 = 1024;
+
 /// Scope to fetch/move todos.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ListScope {
     Day(NaiveDate),
     Backlog,
+    /// Every day from the first date through the second, both inclusive.
+    /// Only for listing; todos can't be moved to a range.
+    Range(NaiveDate, NaiveDate),
+}
+
+/// Restrict listed todos by their project.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectFilter {
+    #[default]
+    Any,
+    IsNull,
+    Equals(Uuid),
+}
+
+impl ProjectFilter {
+    /// Whether user input asks for todos without a project: an empty string,
+    /// `none` or `null` (any case). Callers map these to `IsNull` instead of
+    /// looking them up as project names.
+    pub fn is_no_project(input: &str) -> bool {
+        let input = input.trim();
+
+        input.is_empty() || input.eq_ignore_ascii_case("none") || input.eq_ignore_ascii_case("null")
+    }
+}
+
+/// Restrict listed todos to those carrying a tag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TagFilter {
+    #[default]
+    Any,
+    Has(String),
 }
 
 /// Pagination and filtering options for listing commands.
@@ -22,6 +70,9 @@ pub enum ListScope {
 pub struct ListOptions {
     pub scope: ListScope,
     pub include_done: bool,
+    pub project: ProjectFilter,
+    pub tag: TagFilter,
+    pub sort: SortKey,
 }
 
 impl ListOptions {
@@ -29,10 +80,46 @@ impl ListOptions {
         Self {
             scope: ListScope::Day(date),
             include_done: false,
+            project: ProjectFilter::Any,
+            tag: TagFilter::Any,
+            sort: SortKey::Manual,
         }
     }
 }
 
+/// Filters for `search`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub include_done: bool,
+    pub project: ProjectFilter,
+    pub workspace: Option<Uuid>,
+    /// Match note bodies as well as titles.
+    pub notes: bool,
+    /// Most matches to return; `None` for all of them.
+    pub limit: Option<u64>,
+}
+
+/// How `list` orders todos within the done/pending split.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortKey {
+    /// Column order, as arranged by `reorder`.
+    #[default]
+    Manual,
+    /// Most urgent first, then column order.
+    Priority,
+}
+
+/// The optional fields of a todo being added.
+#[derive(Debug, Clone, Default)]
+pub struct NewTodo {
+    pub notes: Option<String>,
+    pub workspace_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub recurrence: Option<Recurrence>,
+    /// Checked like `update_url`; blank means no link.
+    pub url: Option<String>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MovePlacement {
     Top,
@@ -67,8 +154,48 @@ impl TodoService {
         notes: Option<String>,
         workspace_id: Option<Uuid>,
         project_id: Option<Uuid>,
+        recurrence: Option<Recurrence>,
+    ) -> Result<todo::Model> {
+        self.add_with_placement(
+            title,
+            scheduled_for,
+            MovePlacement::Top,
+            NewTodo {
+                notes,
+                workspace_id,
+                project_id,
+                recurrence,
+                url: None,
+            },
+        )
+        .await
+    }
+
+    /// Insert a todo at the top or bottom of the pending group of its column.
+    pub async fn add_with_placement(
+        &self,
+        title: impl Into<String>,
+        scheduled_for: Option<NaiveDate>,
+        placement: MovePlacement,
+        new: NewTodo,
     ) -> Result<todo::Model> {
-        let order_index = self.next_top_order_index(scheduled_for).await?;
+        if new.recurrence.is_some() && scheduled_for.is_none() {
+            bail!("backlog todos can't repeat; give the todo a day first");
+        }
+
+        let url = new
+            .url
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty());
+
+        if let Some(url) = &url {
+            validate_url(url)?;
+        }
+
+        let order_index = match placement {
+            MovePlacement::Top => self.next_top_order_index(scheduled_for).await?,
+            MovePlacement::Bottom => self.next_pending_bottom_index(scheduled_for).await?,
+        };
 
         let model = todo::ActiveModel {
             id: Set(Uuid::new_v4()),
@@ -76,14 +203,53 @@ impl TodoService {
             status: Set("pending".to_string()),
             scheduled_for: Set(scheduled_for),
             order_index: Set(order_index),
-            notes: Set(notes),
+            notes: Set(new.notes),
+            url: Set(url),
+            metadata: Set(JsonValue::Null),
+            workspace_id: Set(new.workspace_id),
+            project_id: Set(new.project_id),
+            recurrence: Set(new.recurrence.map(|rule| rule.to_string())),
+            ..Default::default()
+        };
+
+        retry_busy(|| model.clone().insert(&self.db))
+            .await
+            .into_diagnostic()
+    }
+
+    /// Copy a todo into a new pending one on top of the same day or backlog
+    /// column. Title, notes, link, workspace, project, priority and tags come
+    /// along; status, reminder, repeat rule and history do not.
+    pub async fn duplicate(&self, id: Uuid) -> Result<todo::Model> {
+        let source = self.load(id).await?;
+
+        let order_index = self.next_top_order_index(source.scheduled_for).await?;
+
+        let model = todo::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            title: Set(source.title),
+            status: Set("pending".to_string()),
+            scheduled_for: Set(source.scheduled_for),
+            order_index: Set(order_index),
+            backlog_column: Set(source.backlog_column),
+            notes: Set(source.notes),
+            url: Set(source.url),
             metadata: Set(JsonValue::Null),
-            workspace_id: Set(workspace_id),
-            project_id: Set(project_id),
+            workspace_id: Set(source.workspace_id),
+            project_id: Set(source.project_id),
+            priority: Set(source.priority),
+            tags: Set(source.tags),
             ..Default::default()
         };
 
-        model.insert(&self.db).await.into_diagnostic()
+        retry_busy(|| model.clone().insert(&self.db))
+            .await
+            .into_diagnostic()
+    }
+
+    /// Number of todos in the database, done or not.
+    pub async fn count(&self) -> Result<u64> {
+        todo::Entity::find().count(&self.db).await.into_diagnostic()
     }
 
     /// List todos using the provided filters.
@@ -94,26 +260,378 @@ impl TodoService {
             query = query.filter(todo::Column::Status.ne(STATUS_DONE));
         }
 
+        query = match opts.project {
+            ProjectFilter::Any => query,
+            ProjectFilter::IsNull => query.filter(todo::Column::ProjectId.is_null()),
+            ProjectFilter::Equals(id) => query.filter(todo::Column::ProjectId.eq(id)),
+        };
+
+        if let TagFilter::Has(tag) = opts.tag {
+            // Padding both sides with spaces keeps `home` from matching `homework`.
+            query = query.filter(Expr::cust_with_values(
+                "instr(' ' || tags || ' ', ?) > 0",
+                [format!(" {tag} ")],
+            ));
+        }
+
+        if let ListScope::Range(..) = opts.scope {
+            query = query.order_by_asc(todo::Column::ScheduledFor);
+        }
+
         let done_first = Expr::cust("CASE WHEN status = 'done' THEN 1 ELSE 0 END");
 
+        query = query.order_by(done_first, Order::Asc);
+
+        if opts.sort == SortKey::Priority {
+            query = query.order_by_desc(todo::Column::Priority);
+        }
+
         query
-            .order_by(done_first, Order::Asc)
             .order_by_asc(todo::Column::OrderIndex)
             .all(&self.db)
             .await
             .into_diagnostic()
     }
 
+    /// Todos scheduled from `from` through `to`, both inclusive, ordered by
+    /// day and then as they sit in each day's column (done todos last).
+    pub async fn list_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        include_done: bool,
+        project: ProjectFilter,
+    ) -> Result<Vec<todo::Model>> {
+        self.list(ListOptions {
+            scope: ListScope::Range(from, to),
+            include_done,
+            project,
+            tag: TagFilter::Any,
+            sort: SortKey::Manual,
+        })
+        .await
+    }
+
+    /// Todos whose title (and, with `opts.notes`, notes) contains `query`,
+    /// case-insensitively for ASCII. Titles equal to `query` come first, then
+    /// titles starting with it, then other matches; within each group by day
+    /// with the backlog last, then by column order.
+    pub async fn search(&self, query: &str, opts: SearchOptions) -> Result<Vec<todo::Model>> {
+        let query = query.trim();
+
+        let mut matches = Condition::any().add(todo::Column::Title.contains(query));
+
+        if opts.notes {
+            matches = matches.add(todo::Column::Notes.contains(query));
+        }
+
+        let mut select = todo::Entity::find()
+            .filter(matches)
+            .filter(todo::Column::ArchivedAt.is_null());
+
+        if !opts.include_done {
+            select = select.filter(todo::Column::Status.ne(STATUS_DONE));
+        }
+
+        select = match opts.project {
+            ProjectFilter::Any => select,
+            ProjectFilter::IsNull => select.filter(todo::Column::ProjectId.is_null()),
+            ProjectFilter::Equals(id) => select.filter(todo::Column::ProjectId.eq(id)),
+        };
+
+        if let Some(workspace) = opts.workspace {
+            select = select.filter(todo::Column::WorkspaceId.eq(workspace));
+        }
+
+        let relevance = Expr::cust_with_values(
+            "CASE WHEN lower(title) = lower(?) THEN 0 \
+             WHEN substr(lower(title), 1, length(?)) = lower(?) THEN 1 \
+             WHEN instr(lower(title), lower(?)) > 0 THEN 2 \
+             ELSE 3 END",
+            [query; 4],
+        );
+
+        let backlog_last = Expr::cust("CASE WHEN scheduled_for IS NULL THEN 1 ELSE 0 END");
+
+        select
+            .order_by(relevance, Order::Asc)
+            .order_by(backlog_last, Order::Asc)
+            .order_by_asc(todo::Column::ScheduledFor)
+            .order_by_asc(todo::Column::OrderIndex)
+            .limit(opts.limit)
+            .all(&self.db)
+            .await
+            .into_diagnostic()
+    }
+
+    /// Every todo across all days and the backlog, archived ones included,
+    /// optionally limited to one workspace. Ordered like `search`: by day with
+    /// the backlog last, then by column order.
+    pub async fn list_all(
+        &self,
+        include_done: bool,
+        workspace: Option<Uuid>,
+    ) -> Result<Vec<todo::Model>> {
+        let mut select = todo::Entity::find();
+
+        if !include_done {
+            select = select.filter(todo::Column::Status.ne(STATUS_DONE));
+        }
+
+        if let Some(workspace) = workspace {
+            select = select.filter(todo::Column::WorkspaceId.eq(workspace));
+        }
+
+        let backlog_last = Expr::cust("CASE WHEN scheduled_for IS NULL THEN 1 ELSE 0 END");
+
+        select
+            .order_by(backlog_last, Order::Asc)
+            .order_by_asc(todo::Column::ScheduledFor)
+            .order_by_asc(todo::Column::OrderIndex)
+            .all(&self.db)
+            .await
+            .into_diagnostic()
+    }
+
+    /// Ids of the projects that have at least one todo.
+    pub async fn distinct_projects(&self) -> Result<Vec<Uuid>> {
+        todo::Entity::find()
+            .select_only()
+            .column(todo::Column::ProjectId)
+            .distinct()
+            .filter(todo::Column::ProjectId.is_not_null())
+            .filter(todo::Column::ArchivedAt.is_null())
+            .into_tuple::<Uuid>()
+            .all(&self.db)
+            .await
+            .into_diagnostic()
+    }
+
+    /// Todos completed on `date` (local time), in column order.
+    ///
+    /// Todos finished before `completed_at` was recorded count for the day
+    /// they are scheduled on instead.
+    pub async fn completed_on(&self, date: NaiveDate) -> Result<Vec<todo::Model>> {
+        // Widen by a day so completions near midnight in any timezone are
+        // fetched; exact local dates are compared below.
+        let completed_after = (date - ChronoDuration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+
+        let todos = todo::Entity::find()
+            .filter(todo::Column::Status.eq(STATUS_DONE))
+            .filter(todo::Column::ArchivedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(todo::Column::ScheduledFor.eq(date))
+                    .add(todo::Column::CompletedAt.gte(completed_after)),
+            )
+            .order_by_asc(todo::Column::OrderIndex)
+            .all(&self.db)
+            .await
+            .into_diagnostic()?;
+
+        Ok(todos
+            .into_iter()
+            .filter(|todo| completion_day(todo) == Some(date))
+            .collect())
+    }
+
+    /// Archive a todo: it stays in the database but drops out of every list,
+    /// search and rollover until `unarchive` brings it back.
+    pub async fn archive(&self, id: Uuid) -> Result<todo::Model> {
+        let model = self.load(id).await?;
+
+        if model.archived_at.is_some() {
+            return Ok(model);
+        }
+
+        let mut active: todo::ActiveModel = model.into();
+        active.archived_at = Set(Some(Utc::now()));
+
+        self.save(active).await
+    }
+
+    /// Bring an archived todo back, on top of its day or backlog column.
+    pub async fn unarchive(&self, id: Uuid) -> Result<todo::Model> {
+        let model = self.load(id).await?;
+
+        if model.archived_at.is_none() {
+            return Ok(model);
+        }
+
+        let order_index = if model.status == STATUS_DONE {
+            self.next_done_order_index(model.scheduled_for).await?
+        } else {
+            self.next_top_order_index(model.scheduled_for).await?
+        };
+
+        let mut active: todo::ActiveModel = model.into();
+        active.archived_at = Set(None);
+        active.order_index = Set(order_index);
+
+        self.save(active).await
+    }
+
+    /// Archived todos, most recently archived first.
+    pub async fn list_archived(&self) -> Result<Vec<todo::Model>> {
+        todo::Entity::find()
+            .filter(todo::Column::ArchivedAt.is_not_null())
+            .order_by_desc(todo::Column::ArchivedAt)
+            .all(&self.db)
+            .await
+            .into_diagnostic()
+    }
+
+    /// An archived todo by UUID or exact title, for `mach restore`.
+    pub async fn find_archived(&self, title_or_id: &str) -> Result<Option<todo::Model>> {
+        let mut query = todo::Entity::find().filter(todo::Column::ArchivedAt.is_not_null());
+
+        query = match Uuid::parse_str(title_or_id) {
+            Ok(id) => query.filter(todo::Column::Id.eq(id)),
+            Err(_) => query.filter(todo::Column::Title.eq(title_or_id)),
+        };
+
+        let mut matches = query.all(&self.db).await.into_diagnostic()?;
+
+        match matches.len() {
+            0 => Ok(None),
+            1 => Ok(matches.pop()),
+            _ => bail!(
+                "multiple archived todos match '{}', use the id instead (run `mach archive --list -i` to see ids)",
+                title_or_id
+            ),
+        }
+    }
+
     /// Delete a todo by id.
     pub async fn delete(&self, id: Uuid) -> Result<bool> {
-        let res = todo::Entity::delete_by_id(id)
-            .exec(&self.db)
+        let res = retry_busy(|| todo::Entity::delete_by_id(id).exec(&self.db))
             .await
             .into_diagnostic()?;
 
         Ok(res.rows_affected > 0)
     }
 
+    /// Insert exported todos exactly as they were, ids and timestamps
+    /// included, in a single transaction. Every workspace and project a todo
+    /// points at must already exist, so nothing is written when one is
+    /// missing.
+    pub async fn import(
+        &self,
+        models: Vec<todo::Model>,
+        mode: ImportMode,
+    ) -> Result<ImportSummary> {
+        let workspaces: HashSet<Uuid> = workspace::Entity::find()
+            .select_only()
+            .column(workspace::Column::Id)
+            .into_tuple::<Uuid>()
+            .all(&self.db)
+            .await
+            .into_diagnostic()?
+            .into_iter()
+            .collect();
+
+        let projects: HashSet<Uuid> = project::Entity::find()
+            .select_only()
+            .column(project::Column::Id)
+            .into_tuple::<Uuid>()
+            .all(&self.db)
+            .await
+            .into_diagnostic()?
+            .into_iter()
+            .collect();
+
+        for model in &models {
+            if let Some(id) = model.workspace_id
+                && !workspaces.contains(&id)
+            {
+                bail!(
+                    "'{}' belongs to workspace {} which doesn't exist",
+                    model.title,
+                    id
+                );
+            }
+
+            if let Some(id) = model.project_id
+                && !projects.contains(&id)
+            {
+                bail!(
+                    "'{}' belongs to project {} which doesn't exist",
+                    model.title,
+                    id
+                );
+            }
+        }
+
+        let imported = retry_busy(|| self.insert_batch(&models, mode))
+            .await
+            .into_diagnostic()?;
+
+        Ok(ImportSummary {
+            imported,
+            skipped: models.len() - imported,
+        })
+    }
+
+    async fn insert_batch(&self, models: &[todo::Model], mode: ImportMode) -> Result<usize, DbErr> {
+        let txn = self.db.begin().await?;
+
+        if mode == ImportMode::Replace {
+            todo::Entity::delete_many().exec(&txn).await?;
+        }
+
+        let mut seen = HashSet::new();
+        let mut imported = 0;
+
+        for model in models {
+            // Skip ids repeated within the batch as well as ones already stored.
+            if !seen.insert(model.id)
+                || todo::Entity::find_by_id(model.id)
+                    .one(&txn)
+                    .await?
+                    .is_some()
+            {
+                continue;
+            }
+
+            // Entity::insert skips before_save, so timestamps are kept as exported.
+            todo::Entity::insert(todo::ActiveModel::from(model.clone()).reset_all())
+                .exec_without_returning(&txn)
+                .await?;
+
+            imported += 1;
+        }
+
+        txn.commit().await?;
+
+        Ok(imported)
+    }
+
+    /// Put a todo's placement and status back to an earlier snapshot of it.
+    pub async fn restore(&self, snapshot: todo::Model) -> Result<todo::Model> {
+        let model = self.load(snapshot.id).await?;
+
+        // Undoing a `mark_done` also takes back the copy it spawned.
+        if spawned_id(&model).is_some() && spawned_id(&model) != spawned_id(&snapshot) {
+            self.remove_spawned(&model).await?;
+        }
+
+        let mut active: todo::ActiveModel = model.into();
+
+        active.status = Set(snapshot.status);
+        active.completed_at = Set(snapshot.completed_at);
+        active.archived_at = Set(snapshot.archived_at);
+        active.scheduled_for = Set(snapshot.scheduled_for);
+        active.order_index = Set(snapshot.order_index);
+        active.backlog_column = Set(snapshot.backlog_column);
+        active.metadata = Set(snapshot.metadata);
+        active.recurrence = Set(snapshot.recurrence);
+
+        self.save(active).await
+    }
+
     /// Mark a todo as complete, ensuring backlog items move into today's column.
     pub async fn mark_done(&self, id: Uuid, today: NaiveDate) -> Result<todo::Model> {
         let model = self.load(id).await?;
@@ -126,17 +644,93 @@ impl TodoService {
 
         let order_index = self.next_done_order_index(scheduled_for).await?;
 
+        // Only remember the old slot if the todo stays in the same column;
+        // backlog items land in today's column and have no slot there.
+        let metadata = if model.scheduled_for.is_some() {
+            set_metadata_key(
+                model.metadata.clone(),
+                META_PREV_ORDER_INDEX,
+                JsonValue::from(model.order_index),
+            )
+        } else {
+            model.metadata.clone()
+        };
+
+        let next = model
+            .recurrence
+            .as_deref()
+            .and_then(|rule| Recurrence::parse(rule).ok())
+            .zip(scheduled_for)
+            .and_then(|(rule, day)| Some((rule, rule.next_after(day, today)?)));
+
+        let copy = model.clone();
+
         let mut active: todo::ActiveModel = model.into();
 
         active.status = Set(STATUS_DONE.to_string());
+        active.completed_at = Set(Some(Utc::now()));
         active.scheduled_for = Set(scheduled_for);
         active.order_index = Set(order_index);
+        active.metadata = Set(metadata.clone());
+        // Now on a day, so a finished backlog item drops its old lane.
+        active.backlog_column = Set(0);
+
+        // The series moves on to the new copy, so finishing this one again
+        // after a reopen that kept the copy doesn't spawn a second one.
+        if next.is_some() {
+            active.recurrence = Set(None);
+        }
+
+        let mut done = self.save(active).await?;
+
+        if let Some((rule, day)) = next {
+            let copy_tags = tags(&copy);
+
+            let spawned = self
+                .add(
+                    copy.title,
+                    Some(day),
+                    copy.notes,
+                    copy.workspace_id,
+                    copy.project_id,
+                    Some(rule),
+                )
+                .await?;
+
+            if copy.url.is_some() {
+                self.update_url(spawned.id, copy.url).await?;
+            }
+
+            if copy.priority > 0 {
+                self.update_priority(spawned.id, copy.priority).await?;
+            }
+
+            if !copy_tags.is_empty() {
+                self.set_tags(spawned.id, &copy_tags).await?;
+            }
+
+            // Remembered so undo or a reopen can take the copy back.
+            let mut active: todo::ActiveModel = done.into();
+            active.metadata = Set(set_metadata_key(
+                metadata,
+                META_SPAWNED_ID,
+                JsonValue::from(spawned.id.to_string()),
+            ));
 
-        active.update(&self.db).await.into_diagnostic()
+            done = self.save(active).await?;
+        }
+
+        Ok(done)
     }
 
     /// Revert a completed todo back to a pending state.
-    pub async fn mark_pending(&self, id: Uuid) -> Result<todo::Model> {
+    ///
+    /// With `keep_position` the todo returns to the slot it held before it was
+    /// marked done (or the bottom of the pending group when that slot is
+    /// unknown) instead of jumping to the top of the column. A repeating todo
+    /// takes its rule back from the copy finishing it spawned, as long as that
+    /// copy is still pending.
+    pub async fn mark_pending(&self, id: Uuid, keep_position: bool) -> Result<todo::Model> {
         let model = self.load(id).await?;
 
         if model.status != STATUS_DONE {
@@ -144,46 +738,126 @@ impl TodoService {
         }
 
         let scope = model.scheduled_for;
-        let target_index = self.next_top_order_index(scope).await?;
+        let prev_index = model
+            .metadata
+            .get(META_PREV_ORDER_INDEX)
+            .and_then(JsonValue::as_i64);
+
+        let target_index = match (keep_position, prev_index) {
+            (true, Some(index)) => self.pending_slot_at(scope, index).await?,
+            (true, None) => self.next_pending_bottom_index(scope).await?,
+            (false, _) => self.next_top_order_index(scope).await?,
+        };
+
+        let recurrence = self.remove_spawned(&model).await?;
+
+        let metadata = remove_metadata_key(model.metadata.clone(), META_PREV_ORDER_INDEX);
+        let metadata = remove_metadata_key(metadata, META_SPAWNED_ID);
 
         let mut active: todo::ActiveModel = model.into();
         active.status = Set("pending".to_string());
+        active.completed_at = Set(None);
         active.order_index = Set(target_index);
+        active.metadata = Set(metadata);
+
+        if recurrence.is_some() {
+            active.recurrence = Set(recurrence);
+        }
 
-        active.update(&self.db).await.into_diagnostic()
+        self.save(active).await
     }
 
-    /// Move overdue todos (scheduled in the past) to today.
-    pub async fn rollover_to(&self, today: NaiveDate) -> Result<usize> {
-        let overdue = todo::Entity::find()
-            .filter(todo::Column::ScheduledFor.lt(today))
-            .filter(todo::Column::ScheduledFor.is_not_null())
-            .filter(todo::Column::Status.ne(STATUS_DONE))
+    /// Delete the copy `mark_done` spawned from `model`, returning its repeat
+    /// rule. A copy that has since been finished or removed is left alone.
+    async fn remove_spawned(&self, model: &todo::Model) -> Result<Option<String>> {
+        let Some(id) = spawned_id(model) else {
+            return Ok(None);
+        };
+
+        let Some(spawned) = todo::Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .into_diagnostic()?
+        else {
+            return Ok(None);
+        };
+
+        if spawned.status == STATUS_DONE {
+            return Ok(None);
+        }
+
+        self.delete(id).await?;
+
+        Ok(spawned.recurrence)
+    }
+
+    /// Move overdue todos (scheduled in the past) to the bottom of today's
+    /// pending todos and return their ids in their new order. Older days come
+    /// first, and each day keeps its own column order.
+    pub async fn rollover_to(&self, today: NaiveDate) -> Result<Vec<Uuid>> {
+        let overdue = overdue_query(today)
+            .order_by_asc(todo::Column::ScheduledFor)
             .order_by_asc(todo::Column::OrderIndex)
             .all(&self.db)
             .await
             .into_diagnostic()?;
 
         if overdue.is_empty() {
-            return Ok(0);
+            return Ok(Vec::new());
         }
 
-        let mut next_index = self.next_pending_bottom_index(Some(today)).await?;
-        let mut moved = 0usize;
-
-        for model in overdue {
-            next_index += 1;
+        let first_index = self.next_pending_bottom_index(Some(today)).await?;
+
+        let moves: Vec<(Uuid, i64, JsonValue)> = overdue
+            .into_iter()
+            .enumerate()
+            .map(|(offset, model)| {
+                // Keep the first day it was planned for across repeated rollovers.
+                let metadata = match (rolled_over_from(&model), model.scheduled_for) {
+                    (None, Some(from)) => set_metadata_key(
+                        model.metadata,
+                        META_ROLLED_OVER_FROM,
+                        JsonValue::from(from.to_string()),
+                    ),
+                    _ => model.metadata,
+                };
+
+                (model.id, first_index + offset as i64 * ORDER_GAP, metadata)
+            })
+            .collect();
+
+        retry_busy(|| self.apply_rollover(today, &moves))
+            .await
+            .into_diagnostic()?;
 
-            let mut active: todo::ActiveModel = model.into();
+        Ok(moves.into_iter().map(|(id, _, _)| id).collect())
+    }
 
-            active.scheduled_for = Set(Some(today));
-            active.order_index = Set(next_index);
-            active.update(&self.db).await.into_diagnostic()?;
+    /// How many todos `rollover_to(today)` would move.
+    pub async fn count_overdue(&self, today: NaiveDate) -> Result<u64> {
+        overdue_query(today).count(&self.db).await.into_diagnostic()
+    }
 
-            moved += 1;
+    /// Move every rolled-over todo to `today` in one transaction, so an
+    /// interrupted rollover leaves the old days untouched.
+    async fn apply_rollover(
+        &self,
+        today: NaiveDate,
+        moves: &[(Uuid, i64, JsonValue)],
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        for (id, order_index, metadata) in moves {
+            todo::Entity::update_many()
+                .col_expr(todo::Column::ScheduledFor, Expr::value(today))
+                .col_expr(todo::Column::OrderIndex, Expr::value(*order_index))
+                .col_expr(todo::Column::Metadata, Expr::value(metadata.clone()))
+                .filter(todo::Column::Id.eq(*id))
+                .exec(&txn)
+                .await?;
         }
 
-        Ok(moved)
+        txn.commit().await
     }
 
     /// Move a todo to another column (day/backlog) placing it at the top or bottom.
@@ -194,7 +868,7 @@ impl TodoService {
         placement: MovePlacement,
     ) -> Result<todo::Model> {
         let model = self.load(id).await?;
-        let target_date = scope_to_date(scope);
+        let target_date = scope_to_date(scope)?;
 
         let target_index = match placement {
             MovePlacement::Top => self.next_top_order_index(target_date).await?,
@@ -212,38 +886,319 @@ impl TodoService {
         active.scheduled_for = Set(target_date);
         active.order_index = Set(target_index);
 
-        let updated = active.update(&self.db).await.into_diagnostic()?;
+        // A todo leaving the backlog forgets its lane, so sending it back later
+        // files it in the first lane instead of wherever it sat before.
+        if target_date.is_some() {
+            active.backlog_column = Set(0);
+        }
+
+        let updated = self.save(active).await?;
 
         Ok(updated)
     }
 
-    /// Update the backlog_column field for a backlog item.
-    pub async fn set_backlog_column(&self, id: Uuid, column: i64) -> Result<todo::Model> {
-        let model = self.load(id).await?;
-
-        let mut active: todo::ActiveModel = model.into();
-        active.backlog_column = Set(column);
+    /// Move every pending todo in `from` to `to` in one transaction, keeping
+    /// their order and leaving done todos behind. `lane` narrows a backlog
+    /// `from` to one backlog column; todos already in `to` are not touched.
+    /// Returns the ids of the moved todos.
+    pub async fn move_all(
+        &self,
+        from: ListScope,
+        to: ListScope,
+        lane: Option<i64>,
+        placement: MovePlacement,
+    ) -> Result<Vec<Uuid>> {
+        let target_date = scope_to_date(to)?;
 
-        active.update(&self.db).await.into_diagnostic()
-    }
+        let mut query = todo::Entity::find()
+            .filter(scope_condition(from))
+            .filter(todo::Column::Status.ne(STATUS_DONE));
 
-    /// Get a todo by id.
-    pub async fn get(&self, id: Uuid) -> Result<todo::Model> {
-        self.load(id).await
-    }
+        if let Some(lane) = lane {
+            query = query.filter(todo::Column::BacklogColumn.eq(lane));
+        }
 
-    /// Find a todo by title or id.
-    pub async fn find_by_title_or_id(&self, title_or_id: &str) -> Result<Option<todo::Model>> {
-        let matches = todo::Entity::find()
-            .filter(
+        query = match target_date {
+            Some(date) => query.filter(
                 Condition::any()
-                    .add(todo::Column::Id.eq(title_or_id))
-                    .add(todo::Column::Title.eq(title_or_id)),
-            )
+                    .add(todo::Column::ScheduledFor.is_null())
+                    .add(todo::Column::ScheduledFor.ne(date)),
+            ),
+            None => query.filter(todo::Column::ScheduledFor.is_not_null()),
+        };
+
+        let todos = query
+            .order_by_asc(todo::Column::ScheduledFor)
+            .order_by_asc(todo::Column::BacklogColumn)
+            .order_by_asc(todo::Column::OrderIndex)
             .all(&self.db)
             .await
             .into_diagnostic()?;
 
+        if todos.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let first_index = match placement {
+            MovePlacement::Top => {
+                self.next_top_order_index(target_date).await? - (todos.len() as i64 - 1) * ORDER_GAP
+            }
+            MovePlacement::Bottom => self.next_pending_bottom_index(target_date).await?,
+        };
+
+        let moves: Vec<(Uuid, i64)> = todos
+            .into_iter()
+            .enumerate()
+            .map(|(offset, model)| (model.id, first_index + offset as i64 * ORDER_GAP))
+            .collect();
+
+        retry_busy(|| self.apply_moves(target_date, &moves))
+            .await
+            .into_diagnostic()?;
+
+        Ok(moves.into_iter().map(|(id, _)| id).collect())
+    }
+
+    async fn apply_moves(
+        &self,
+        target_date: Option<NaiveDate>,
+        moves: &[(Uuid, i64)],
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        for (id, order_index) in moves {
+            let mut update = todo::Entity::update_many()
+                .col_expr(todo::Column::ScheduledFor, Expr::value(target_date))
+                .col_expr(todo::Column::OrderIndex, Expr::value(*order_index))
+                .filter(todo::Column::Id.eq(*id));
+
+            // Same as `move_to_scope`: a todo leaving the backlog forgets its lane.
+            if target_date.is_some() {
+                update = update.col_expr(todo::Column::BacklogColumn, Expr::value(0));
+            }
+
+            update.exec(&txn).await?;
+        }
+
+        txn.commit().await
+    }
+
+    /// Set or clear how a todo repeats. Backlog todos have no day to repeat
+    /// from, so they are rejected.
+    pub async fn update_recurrence(
+        &self,
+        id: Uuid,
+        recurrence: Option<Recurrence>,
+    ) -> Result<todo::Model> {
+        let model = self.load(id).await?;
+
+        if recurrence.is_some() && model.scheduled_for.is_none() {
+            bail!(
+                "'{}' is in the backlog; schedule it before making it repeat",
+                model.title
+            );
+        }
+
+        let mut active: todo::ActiveModel = model.into();
+        active.recurrence = Set(recurrence.map(|rule| rule.to_string()));
+
+        self.save(active).await
+    }
+
+    /// Update the backlog_column field for a backlog item. `column` counts
+    /// from 0 and must be below `BACKLOG_COLUMNS`.
+    pub async fn set_backlog_column(&self, id: Uuid, column: i64) -> Result<todo::Model> {
+        if !(0..BACKLOG_COLUMNS as i64).contains(&column) {
+            bail!(
+                "backlog column {} is out of range (1-{BACKLOG_COLUMNS})",
+                column + 1
+            );
+        }
+
+        let model = self.load(id).await?;
+
+        let mut active: todo::ActiveModel = model.into();
+        active.backlog_column = Set(column);
+
+        self.save(active).await
+    }
+
+    /// Move a whole backlog lane to another position, shifting the lanes in
+    /// between so every item keeps following its lane.
+    pub async fn move_backlog_column(&self, from: i64, to: i64) -> Result<()> {
+        if from == to {
+            return Ok(());
+        }
+
+        retry_busy(|| self.shift_backlog_column(from, to))
+            .await
+            .into_diagnostic()
+    }
+
+    async fn shift_backlog_column(&self, from: i64, to: i64) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        // Park the moving lane on a sentinel so the shift below can't touch it.
+        todo::Entity::update_many()
+            .col_expr(todo::Column::BacklogColumn, Expr::value(-1))
+            .filter(todo::Column::ScheduledFor.is_null())
+            .filter(todo::Column::BacklogColumn.eq(from))
+            .exec(&txn)
+            .await?;
+
+        let (shift, lo, hi) = if from < to {
+            ("backlog_column - 1", from + 1, to)
+        } else {
+            ("backlog_column + 1", to, from - 1)
+        };
+
+        todo::Entity::update_many()
+            .col_expr(todo::Column::BacklogColumn, Expr::cust(shift))
+            .filter(todo::Column::ScheduledFor.is_null())
+            .filter(todo::Column::BacklogColumn.between(lo, hi))
+            .exec(&txn)
+            .await?;
+
+        todo::Entity::update_many()
+            .col_expr(todo::Column::BacklogColumn, Expr::value(to))
+            .filter(todo::Column::ScheduledFor.is_null())
+            .filter(todo::Column::BacklogColumn.eq(-1))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await
+    }
+
+    /// Exchange the positions of two todos in the same column and status
+    /// group. Every other row keeps its `order_index`.
+    pub async fn swap_order(&self, a: Uuid, b: Uuid) -> Result<()> {
+        if a == b {
+            return Ok(());
+        }
+
+        let first = self.load(a).await?;
+        let second = self.load(b).await?;
+
+        let same_column = first.scheduled_for == second.scheduled_for
+            && (first.scheduled_for.is_some() || first.backlog_column == second.backlog_column);
+
+        if !same_column {
+            bail!(
+                "'{}' and '{}' are in different scopes; only todos in the same column can be swapped",
+                first.title,
+                second.title
+            );
+        }
+
+        if (first.status == STATUS_DONE) != (second.status == STATUS_DONE) {
+            bail!(
+                "'{}' and '{}' are not both pending or both done",
+                first.title,
+                second.title
+            );
+        }
+
+        retry_busy(|| self.exchange_order_index(&first, &second))
+            .await
+            .into_diagnostic()
+    }
+
+    async fn exchange_order_index(
+        &self,
+        first: &todo::Model,
+        second: &todo::Model,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        for (id, order_index) in [
+            (first.id, second.order_index),
+            (second.id, first.order_index),
+        ] {
+            todo::Entity::update_many()
+                .col_expr(todo::Column::OrderIndex, Expr::value(order_index))
+                .filter(todo::Column::Id.eq(id))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await
+    }
+
+    /// 1-based position of `model` among the todos sharing its column and
+    /// status, together with how many there are. Backlog todos are counted
+    /// within their own backlog column.
+    pub async fn column_position(&self, model: &todo::Model) -> Result<(usize, usize)> {
+        let scope = match model.scheduled_for {
+            Some(date) => ListScope::Day(date),
+            None => ListScope::Backlog,
+        };
+
+        let status = if model.status == STATUS_DONE {
+            StatusFilter::Done
+        } else {
+            StatusFilter::Pending
+        };
+
+        let mut query = self.column_query(scope, status);
+
+        if scope == ListScope::Backlog {
+            query = query.filter(todo::Column::BacklogColumn.eq(model.backlog_column));
+        }
+
+        let siblings = query.all(&self.db).await.into_diagnostic()?;
+
+        let position = siblings
+            .iter()
+            .position(|sibling| sibling.id == model.id)
+            .map_or(0, |index| index + 1);
+
+        Ok((position, siblings.len()))
+    }
+
+    /// Get a todo by id.
+    pub async fn get(&self, id: Uuid) -> Result<todo::Model> {
+        self.load(id).await
+    }
+
+    /// Find a todo by title or id.
+    ///
+    /// Ids match anywhere. Titles are first looked up inside `workspace_id`
+    /// (when given) and only searched globally if nothing matches there, so a
+    /// same-titled todo elsewhere doesn't shadow the one in scope. Archived
+    /// todos match neither way; `find_archived` looks those up.
+    pub async fn find_by_title_or_id(
+        &self,
+        title_or_id: &str,
+        workspace_id: Option<Uuid>,
+    ) -> Result<Option<todo::Model>> {
+        if let Ok(id) = Uuid::parse_str(title_or_id)
+            && let Some(model) = todo::Entity::find_by_id(id)
+                .filter(todo::Column::ArchivedAt.is_null())
+                .one(&self.db)
+                .await
+                .into_diagnostic()?
+        {
+            return Ok(Some(model));
+        }
+
+        let by_title = todo::Entity::find()
+            .filter(todo::Column::Title.eq(title_or_id))
+            .filter(todo::Column::ArchivedAt.is_null());
+
+        let mut matches = match workspace_id {
+            Some(workspace_id) => by_title
+                .clone()
+                .filter(todo::Column::WorkspaceId.eq(workspace_id))
+                .all(&self.db)
+                .await
+                .into_diagnostic()?,
+            None => Vec::new(),
+        };
+
+        if matches.is_empty() {
+            matches = by_title.all(&self.db).await.into_diagnostic()?;
+        }
+
         match matches.len() {
             0 => Ok(None),
             1 => Ok(Some(matches.into_iter().next().unwrap())),
@@ -259,7 +1214,7 @@ impl TodoService {
         let model = self.load(id).await?;
         let mut active: todo::ActiveModel = model.into();
         active.title = Set(title);
-        active.update(&self.db).await.into_diagnostic()
+        self.save(active).await
     }
 
     /// Update the scheduled_for date of a todo.
@@ -271,7 +1226,12 @@ impl TodoService {
         let model = self.load(id).await?;
         let mut active: todo::ActiveModel = model.into();
         active.scheduled_for = Set(scheduled_for);
-        active.update(&self.db).await.into_diagnostic()
+
+        if scheduled_for.is_some() {
+            active.backlog_column = Set(0);
+        }
+
+        self.save(active).await
     }
 
     /// Update the notes of a todo.
@@ -279,7 +1239,95 @@ impl TodoService {
         let model = self.load(id).await?;
         let mut active: todo::ActiveModel = model.into();
         active.notes = Set(notes);
-        active.update(&self.db).await.into_diagnostic()
+        self.save(active).await
+    }
+
+    /// Replace a todo's tags. Input is normalized with `normalize_tags`, and
+    /// an empty list clears the column.
+    pub async fn set_tags<S: AsRef<str>>(&self, id: Uuid, tags: &[S]) -> Result<todo::Model> {
+        let tags = normalize_tags(tags);
+
+        let model = self.load(id).await?;
+        let mut active: todo::ActiveModel = model.into();
+        active.tags = Set((!tags.is_empty()).then(|| tags.join(" ")));
+        self.save(active).await
+    }
+
+    /// Add one tag, keeping the ones already there.
+    pub async fn add_tag(&self, id: Uuid, tag: &str) -> Result<todo::Model> {
+        let mut current = tags(&self.load(id).await?);
+        current.push(tag.to_string());
+
+        self.set_tags(id, &current).await
+    }
+
+    /// Remove one tag; a tag the todo doesn't have is ignored.
+    pub async fn remove_tag(&self, id: Uuid, tag: &str) -> Result<todo::Model> {
+        let Some(tag) = normalize_tag(tag) else {
+            return self.get(id).await;
+        };
+
+        let mut current = tags(&self.load(id).await?);
+        current.retain(|t| *t != tag);
+
+        self.set_tags(id, &current).await
+    }
+
+    /// Set a todo's priority, from 0 (none) to `MAX_PRIORITY`.
+    pub async fn update_priority(&self, id: Uuid, priority: i32) -> Result<todo::Model> {
+        if !(0..=MAX_PRIORITY).contains(&priority) {
+            bail!("priority must be between 0 and {}", MAX_PRIORITY);
+        }
+
+        let model = self.load(id).await?;
+        let mut active: todo::ActiveModel = model.into();
+        active.priority = Set(priority);
+        self.save(active).await
+    }
+
+    /// Set or clear the link of a todo, rejecting values that aren't URLs.
+    pub async fn update_url(&self, id: Uuid, url: Option<String>) -> Result<todo::Model> {
+        let url = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+
+        if let Some(url) = &url {
+            validate_url(url)?;
+        }
+
+        let model = self.load(id).await?;
+        let mut active: todo::ActiveModel = model.into();
+        active.url = Set(url);
+        self.save(active).await
+    }
+
+    /// Set or clear the reminder time of a todo.
+    pub async fn update_remind_at(
+        &self,
+        id: Uuid,
+        remind_at: Option<DateTime<Utc>>,
+    ) -> Result<todo::Model> {
+        let model = self.load(id).await?;
+        let mut active: todo::ActiveModel = model.into();
+        active.remind_at = Set(remind_at);
+        self.save(active).await
+    }
+
+    /// Pending todos with a reminder, soonest first. With `due_by` only those
+    /// whose reminder time has been reached are returned.
+    pub async fn list_reminders(&self, due_by: Option<DateTime<Utc>>) -> Result<Vec<todo::Model>> {
+        let mut query = todo::Entity::find()
+            .filter(todo::Column::RemindAt.is_not_null())
+            .filter(todo::Column::Status.ne(STATUS_DONE))
+            .filter(todo::Column::ArchivedAt.is_null());
+
+        if let Some(due_by) = due_by {
+            query = query.filter(todo::Column::RemindAt.lte(due_by));
+        }
+
+        query
+            .order_by_asc(todo::Column::RemindAt)
+            .all(&self.db)
+            .await
+            .into_diagnostic()
     }
 
     /// Update the workspace and project of a todo.
@@ -293,7 +1341,7 @@ impl TodoService {
         let mut active: todo::ActiveModel = model.into();
         active.workspace_id = Set(workspace_id);
         active.project_id = Set(project_id);
-        active.update(&self.db).await.into_diagnostic()
+        self.save(active).await
     }
 
     /// Reorder within a column/group (pending or done).
@@ -311,33 +1359,46 @@ impl TodoService {
             StatusFilter::Pending
         };
 
-        let mut tasks = self
+        // The backlog scope spans every lane; only the todo's own lane counts.
+        let tasks: Vec<todo::Model> = self
             .column_query(scope, status)
             .all(&self.db)
             .await
-            .into_diagnostic()?;
+            .into_diagnostic()?
+            .into_iter()
+            .filter(|t| model.scheduled_for.is_some() || t.backlog_column == model.backlog_column)
+            .collect();
 
         let Some(idx) = tasks.iter().position(|t| t.id == id) else {
             bail!("todo {} no longer exists", id);
         };
 
-        match direction {
-            ReorderDirection::Up if idx > 0 => tasks.swap(idx, idx - 1),
-            ReorderDirection::Down if idx + 1 < tasks.len() => tasks.swap(idx, idx + 1),
+        // The todo's new neighbours, skipping over the one it trades places with.
+        let (prev, next) = match direction {
+            ReorderDirection::Up if idx > 0 => (idx.checked_sub(2), Some(idx - 1)),
+            ReorderDirection::Down if idx + 1 < tasks.len() => (Some(idx + 1), Some(idx + 2)),
             _ => return Ok(()),
-        }
+        };
 
-        for (index, task) in tasks.into_iter().enumerate() {
-            let mut active: todo::ActiveModel = task.into();
+        let prev = prev.map(|i| tasks[i].id);
+        let next = next.and_then(|i| tasks.get(i)).map(|task| task.id);
 
-            active.order_index = Set(index as i64);
+        let order_index = self.order_index_between(scope, prev, next).await?;
 
-            active.update(&self.db).await.into_diagnostic()?;
-        }
+        let mut active: todo::ActiveModel = model.into();
+        active.order_index = Set(order_index);
+        self.save(active).await?;
 
         Ok(())
     }
 
+    /// Persist changes to a single todo, riding out a busy database.
+    async fn save(&self, active: todo::ActiveModel) -> Result<todo::Model> {
+        retry_busy(|| active.clone().update(&self.db))
+            .await
+            .into_diagnostic()
+    }
+
     async fn load(&self, id: Uuid) -> Result<todo::Model> {
         todo::Entity::find_by_id(id)
             .one(&self.db)
@@ -349,6 +1410,7 @@ impl TodoService {
     pub async fn stats_for_workspace(&self, workspace_id: Uuid) -> Result<TodoStats> {
         let todos = todo::Entity::find()
             .filter(todo::Column::WorkspaceId.eq(workspace_id))
+            .filter(todo::Column::ArchivedAt.is_null())
             .all(&self.db)
             .await
             .into_diagnostic()?;
@@ -366,6 +1428,7 @@ impl TodoService {
     pub async fn stats_for_project(&self, project_id: Uuid) -> Result<TodoStats> {
         let todos = todo::Entity::find()
             .filter(todo::Column::ProjectId.eq(project_id))
+            .filter(todo::Column::ArchivedAt.is_null())
             .all(&self.db)
             .await
             .into_diagnostic()?;
@@ -380,6 +1443,109 @@ impl TodoService {
         })
     }
 
+    /// Todos pointing at a project, archived ones included.
+    pub async fn count_by_project(&self, project_id: Uuid) -> Result<u64> {
+        todo::Entity::find()
+            .filter(todo::Column::ProjectId.eq(project_id))
+            .count(&self.db)
+            .await
+            .into_diagnostic()
+    }
+
+    /// Move every todo of project `from` (archived ones too) to `to`, taking
+    /// on its workspace, or detach them from any project when `to` is `None`.
+    /// Returns how many todos changed.
+    pub async fn reassign_project(&self, from: Uuid, to: Option<&project::Model>) -> Result<u64> {
+        let now = Utc::now();
+
+        let res = retry_busy(|| {
+            let mut update = todo::Entity::update_many()
+                .col_expr(todo::Column::ProjectId, Expr::value(to.map(|p| p.id)))
+                .col_expr(todo::Column::UpdatedAt, Expr::value(now))
+                .filter(todo::Column::ProjectId.eq(from));
+
+            // A todo's workspace follows its project.
+            if let Some(project) = to {
+                update = update.col_expr(
+                    todo::Column::WorkspaceId,
+                    Expr::value(Some(project.workspace_id)),
+                );
+            }
+
+            update.exec(&self.db)
+        })
+        .await
+        .into_diagnostic()?;
+
+        Ok(res.rows_affected)
+    }
+
+    /// Per-day activity between `from` and `to` (inclusive).
+    ///
+    /// `completed` counts done todos by the day they were completed (or, for
+    /// todos finished before that was recorded, the day they are scheduled
+    /// on), and `carried_over` counts pending todos on a day that were
+    /// created before it.
+    pub async fn daily_stats(&self, from: NaiveDate, to: NaiveDate) -> Result<Vec<DayStats>> {
+        if from > to {
+            bail!("--from must not be after --to");
+        }
+
+        // Widen by a day so todos created near midnight in any timezone are
+        // fetched; exact local dates are compared below.
+        let created_after = (from - ChronoDuration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time")
+            .and_utc();
+
+        let todos = todo::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(todo::Column::ScheduledFor.between(from, to))
+                    .add(todo::Column::CreatedAt.gte(created_after))
+                    .add(todo::Column::CompletedAt.gte(created_after)),
+            )
+            .all(&self.db)
+            .await
+            .into_diagnostic()?;
+
+        let mut stats: Vec<DayStats> = from
+            .iter_days()
+            .take_while(|day| *day <= to)
+            .map(DayStats::new)
+            .collect();
+
+        for todo in &todos {
+            let created_on = todo.created_at.with_timezone(&Local).date_naive();
+
+            if let Some(day) = stats.iter_mut().find(|s| s.date == created_on) {
+                day.created += 1;
+            }
+
+            if todo.status == STATUS_DONE {
+                if let Some(day) = completion_day(todo)
+                    .and_then(|completed| stats.iter_mut().find(|s| s.date == completed))
+                {
+                    day.completed += 1;
+                }
+
+                continue;
+            }
+
+            let Some(scheduled_for) = todo.scheduled_for else {
+                continue;
+            };
+
+            if let Some(day) = stats.iter_mut().find(|s| s.date == scheduled_for)
+                && created_on < scheduled_for
+            {
+                day.carried_over += 1;
+            }
+        }
+
+        Ok(stats)
+    }
+
     fn column_query(
         &self,
         scope: ListScope,
@@ -396,12 +1562,111 @@ impl TodoService {
         query.order_by_asc(todo::Column::OrderIndex)
     }
 
+    /// Order index for a reopened todo going back to `index`: that value
+    /// while it's free, else the gap just above the pending todo that took it.
+    async fn pending_slot_at(&self, scope_date: Option<NaiveDate>, index: i64) -> Result<i64> {
+        let scope = match scope_date {
+            Some(date) => ListScope::Day(date),
+            None => ListScope::Backlog,
+        };
+
+        let pending = self
+            .column_query(scope, StatusFilter::Pending)
+            .all(&self.db)
+            .await
+            .into_diagnostic()?;
+
+        let split = pending.partition_point(|task| task.order_index < index);
+
+        let Some(taken) = pending.get(split).filter(|task| task.order_index == index) else {
+            return Ok(index);
+        };
+
+        let prev = split.checked_sub(1).map(|i| pending[i].id);
+
+        self.order_index_between(scope, prev, Some(taken.id)).await
+    }
+
+    /// Order index for a todo placed directly after `prev` and before `next`
+    /// in `scope` (`None` means the column edge). Falls back to renumbering
+    /// the column only when the two neighbours have no room left between them.
+    pub async fn order_index_between(
+        &self,
+        scope: ListScope,
+        prev: Option<Uuid>,
+        next: Option<Uuid>,
+    ) -> Result<i64> {
+        if let Some(index) = self.try_order_index_between(prev, next).await? {
+            return Ok(index);
+        }
+
+        self.rebalance(scope).await?;
+
+        self.try_order_index_between(prev, next)
+            .await?
+            .ok_or_else(|| miette::miette!("no room between todos after rebalancing"))
+    }
+
+    async fn try_order_index_between(
+        &self,
+        prev: Option<Uuid>,
+        next: Option<Uuid>,
+    ) -> Result<Option<i64>> {
+        let prev = match prev {
+            Some(id) => Some(self.load(id).await?.order_index),
+            None => None,
+        };
+
+        let next = match next {
+            Some(id) => Some(self.load(id).await?.order_index),
+            None => None,
+        };
+
+        Ok(match (prev, next) {
+            (None, None) => Some(0),
+            (Some(a), None) => Some(a + ORDER_GAP),
+            (None, Some(b)) => Some(b - ORDER_GAP),
+            (Some(a), Some(b)) => midpoint(a, b),
+        })
+    }
+
+    /// Renumber a column with even `ORDER_GAP` spacing, pending before done.
+    pub async fn rebalance(&self, scope: ListScope) -> Result<()> {
+        retry_busy(|| self.renumber_column(scope))
+            .await
+            .into_diagnostic()
+    }
+
+    async fn renumber_column(&self, scope: ListScope) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        let pending = self
+            .column_query(scope, StatusFilter::Pending)
+            .all(&txn)
+            .await?;
+
+        let done = self
+            .column_query(scope, StatusFilter::Done)
+            .all(&txn)
+            .await?;
+
+        for (index, task) in pending.into_iter().chain(done).enumerate() {
+            let mut active: todo::ActiveModel = task.into();
+
+            active.order_index = Set(index as i64 * ORDER_GAP);
+
+            active.update(&txn).await?;
+        }
+
+        txn.commit().await
+    }
+
     async fn next_top_order_index(&self, scope_date: Option<NaiveDate>) -> Result<i64> {
         match self
             .find_order_index(scope_date, StatusFilter::Pending, Extremum::Min)
             .await?
         {
-            Some(min) => Ok(min - 1),
+            Some(min) => Ok(min - ORDER_GAP),
             None => Ok(0),
         }
     }
@@ -410,7 +1675,7 @@ impl TodoService {
         Ok(self
             .find_order_index(scope_date, StatusFilter::Pending, Extremum::Max)
             .await?
-            .map(|max| max + 1)
+            .map(|max| max + ORDER_GAP)
             .unwrap_or(0))
     }
 
@@ -418,7 +1683,7 @@ impl TodoService {
         Ok(self
             .find_order_index(scope_date, StatusFilter::Any, Extremum::Max)
             .await?
-            .map(|max| max + 1)
+            .map(|max| max + ORDER_GAP)
             .unwrap_or(0))
     }
 
@@ -452,20 +1717,168 @@ impl TodoService {
     }
 }
 
+/// Todos in `scope`, leaving out archived ones.
 fn scope_condition(scope: ListScope) -> Condition {
+    let condition = Condition::all().add(todo::Column::ArchivedAt.is_null());
+
     match scope {
-        ListScope::Day(date) => Condition::all().add(todo::Column::ScheduledFor.eq(date)),
-        ListScope::Backlog => Condition::all().add(todo::Column::ScheduledFor.is_null()),
+        ListScope::Day(date) => condition.add(todo::Column::ScheduledFor.eq(date)),
+        ListScope::Backlog => condition.add(todo::Column::ScheduledFor.is_null()),
+        ListScope::Range(from, to) => condition.add(todo::Column::ScheduledFor.between(from, to)),
+    }
+}
+
+/// Accept `scheme:rest` URLs such as `https://…` or `mailto:…`.
+fn validate_url(url: &str) -> Result<()> {
+    let valid = match url.split_once(':') {
+        Some((scheme, rest)) => {
+            !rest.is_empty()
+                && scheme
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !url.chars().any(char::is_whitespace)
+                && (!rest.starts_with("//") || rest.len() > 2)
+        }
+        None => false,
+    };
+
+    if !valid {
+        bail!(
+            "'{}' is not a valid URL (expected e.g. https://example.com)",
+            url
+        );
     }
+
+    Ok(())
+}
+
+/// Integer halfway between two order indices, if they aren't adjacent.
+fn midpoint(a: i64, b: i64) -> Option<i64> {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+
+    (hi - lo > 1).then(|| lo + (hi - lo) / 2)
 }
 
-fn scope_to_date(scope: ListScope) -> Option<NaiveDate> {
+/// Pending, unarchived todos scheduled before `today`.
+fn overdue_query(today: NaiveDate) -> sea_orm::Select<todo::Entity> {
+    todo::Entity::find()
+        .filter(todo::Column::ScheduledFor.lt(today))
+        .filter(todo::Column::ScheduledFor.is_not_null())
+        .filter(todo::Column::Status.ne(STATUS_DONE))
+        .filter(todo::Column::ArchivedAt.is_null())
+}
+
+/// The day an overdue todo was scheduled for before rollover first moved it.
+pub fn rolled_over_from(model: &todo::Model) -> Option<NaiveDate> {
+    model
+        .metadata
+        .get(META_ROLLED_OVER_FROM)
+        .and_then(JsonValue::as_str)
+        .and_then(|value| value.parse().ok())
+}
+
+/// A todo's tags, in the order they were added.
+pub fn tags(model: &todo::Model) -> Vec<String> {
+    model
+        .tags
+        .as_deref()
+        .map(|tags| tags.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Lowercase each tag, drop a leading `#`, split on whitespace and commas,
+/// and remove blanks and duplicates while keeping the first occurrence.
+pub fn normalize_tags<S: AsRef<str>>(input: &[S]) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+
+    for raw in input {
+        for part in raw.as_ref().split(|c: char| c.is_whitespace() || c == ',') {
+            if let Some(tag) = normalize_tag(part)
+                && !tags.contains(&tag)
+            {
+                tags.push(tag);
+            }
+        }
+    }
+
+    tags
+}
+
+fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim().trim_start_matches('#').to_lowercase();
+
+    (!tag.is_empty() && !tag.contains(char::is_whitespace)).then_some(tag)
+}
+
+fn set_metadata_key(metadata: JsonValue, key: &str, value: JsonValue) -> JsonValue {
+    let mut map = match metadata {
+        JsonValue::Object(map) => map,
+        _ => serde_json::Map::new(),
+    };
+
+    map.insert(key.to_string(), value);
+
+    JsonValue::Object(map)
+}
+
+fn spawned_id(model: &todo::Model) -> Option<Uuid> {
+    model
+        .metadata
+        .get(META_SPAWNED_ID)
+        .and_then(JsonValue::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+}
+
+fn remove_metadata_key(metadata: JsonValue, key: &str) -> JsonValue {
+    match metadata {
+        JsonValue::Object(mut map) => {
+            map.remove(key);
+
+            if map.is_empty() {
+                JsonValue::Null
+            } else {
+                JsonValue::Object(map)
+            }
+        }
+        other => other,
+    }
+}
+
+/// The local day a done todo was completed, falling back to the day it is
+/// scheduled on when no completion time was recorded.
+fn completion_day(todo: &todo::Model) -> Option<NaiveDate> {
+    todo.completed_at
+        .map(|at| at.with_timezone(&Local).date_naive())
+        .or(todo.scheduled_for)
+}
+
+fn scope_to_date(scope: ListScope) -> Result<Option<NaiveDate>> {
     match scope {
-        ListScope::Day(date) => Some(date),
-        ListScope::Backlog => None,
+        ListScope::Day(date) => Ok(Some(date)),
+        ListScope::Backlog => Ok(None),
+        ListScope::Range(..) => bail!("todos can only be moved to a single day or the backlog"),
     }
 }
 
+/// What `import` does with the todos already in the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Keep existing todos and skip incoming ones whose id is taken.
+    Merge,
+    /// Delete every existing todo first.
+    Replace,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportSummary {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct TodoStats {
     pub total: u64,
@@ -473,6 +1886,25 @@ pub struct TodoStats {
     pub remaining: u64,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct DayStats {
+    pub date: NaiveDate,
+    pub created: u64,
+    pub completed: u64,
+    pub carried_over: u64,
+}
+
+impl DayStats {
+    fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            created: 0,
+            completed: 0,
+            carried_over: 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum StatusFilter {
     Pending,
@@ -485,3 +1917,384 @@ enum Extremum {
     Min,
     Max,
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::ConnectionTrait;
+
+    use super::*;
+    use crate::service::{
+        connection::init_memory_database, project::ProjectService, workspace::WorkspaceService,
+    };
+
+    fn day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
+    }
+
+    async fn service() -> TodoService {
+        TodoService::new(init_memory_database().await.unwrap())
+    }
+
+    async fn add_bottom(todos: &TodoService, title: &str) -> todo::Model {
+        todos
+            .add_with_placement(
+                title,
+                Some(day()),
+                MovePlacement::Bottom,
+                NewTodo::default(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn set_index(todos: &TodoService, id: Uuid, order_index: i64) {
+        let mut active: todo::ActiveModel = todos.get(id).await.unwrap().into();
+        active.order_index = Set(order_index);
+        todos.save(active).await.unwrap();
+    }
+
+    async fn titles(todos: &TodoService) -> Vec<String> {
+        todos
+            .list(ListOptions {
+                include_done: true,
+                ..ListOptions::today(day())
+            })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|todo| todo.title)
+            .collect()
+    }
+
+    /// Make every update of the todo titled `title` fail, as if the disk
+    /// gave out halfway through a batch.
+    async fn fail_updates_of(todos: &TodoService, title: &str) {
+        todos
+            .db
+            .execute_unprepared(&format!(
+                "CREATE TRIGGER fail_{title} BEFORE UPDATE ON todos \
+                 WHEN NEW.title = '{title}' \
+                 BEGIN SELECT RAISE(ABORT, 'update failed'); END"
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn midpoint_needs_room() {
+        assert_eq!(midpoint(0, ORDER_GAP), Some(ORDER_GAP / 2));
+        assert_eq!(midpoint(ORDER_GAP, 0), Some(ORDER_GAP / 2));
+        assert_eq!(midpoint(4, 6), Some(5));
+        assert_eq!(midpoint(4, 5), None);
+        assert_eq!(midpoint(4, 4), None);
+    }
+
+    #[tokio::test]
+    async fn new_todos_are_spaced_by_the_gap() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+        let top = todos
+            .add("top", Some(day()), None, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(b.order_index - a.order_index, ORDER_GAP);
+        assert_eq!(a.order_index - top.order_index, ORDER_GAP);
+    }
+
+    #[tokio::test]
+    async fn inserting_between_halves_the_gap_until_it_rebalances() {
+        let todos = service().await;
+        let scope = ListScope::Day(day());
+
+        let first = add_bottom(&todos, "first").await;
+        let mut next = add_bottom(&todos, "last").await;
+
+        // 1024 halves ten times before two neighbours sit one apart.
+        for round in 0..10 {
+            let index = todos
+                .order_index_between(scope, Some(first.id), Some(next.id))
+                .await
+                .unwrap();
+
+            assert!(first.order_index < index && index < next.order_index);
+
+            let inserted = add_bottom(&todos, &format!("mid {round}")).await;
+            set_index(&todos, inserted.id, index).await;
+
+            next = todos.get(inserted.id).await.unwrap();
+        }
+
+        assert_eq!(next.order_index - first.order_index, 1);
+
+        // No room left: the column is renumbered before the answer comes back.
+        let index = todos
+            .order_index_between(scope, Some(first.id), Some(next.id))
+            .await
+            .unwrap();
+
+        let first = todos.get(first.id).await.unwrap();
+        let next = todos.get(next.id).await.unwrap();
+
+        assert_eq!(next.order_index - first.order_index, ORDER_GAP);
+        assert!(first.order_index < index && index < next.order_index);
+    }
+
+    #[tokio::test]
+    async fn rebalance_spaces_pending_before_done() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+        let c = add_bottom(&todos, "c").await;
+
+        set_index(&todos, a.id, 7).await;
+        set_index(&todos, b.id, 8).await;
+        set_index(&todos, c.id, 3).await;
+        todos.mark_done(c.id, day()).await.unwrap();
+
+        todos.rebalance(ListScope::Day(day())).await.unwrap();
+
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, 0);
+        assert_eq!(todos.get(b.id).await.unwrap().order_index, ORDER_GAP);
+        assert_eq!(todos.get(c.id).await.unwrap().order_index, 2 * ORDER_GAP);
+    }
+
+    #[tokio::test]
+    async fn reorder_moves_only_the_todo() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+        let c = add_bottom(&todos, "c").await;
+
+        todos.reorder(c.id, ReorderDirection::Up).await.unwrap();
+
+        assert_eq!(titles(&todos).await, ["a", "c", "b"]);
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, a.order_index);
+        assert_eq!(todos.get(b.id).await.unwrap().order_index, b.order_index);
+
+        todos.reorder(a.id, ReorderDirection::Down).await.unwrap();
+
+        assert_eq!(titles(&todos).await, ["c", "a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn reopening_into_a_taken_slot_goes_above_its_occupant() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+
+        todos.mark_done(a.id, day()).await.unwrap();
+        set_index(&todos, b.id, a.order_index).await;
+
+        let reopened = todos.mark_pending(a.id, true).await.unwrap();
+
+        assert!(reopened.order_index < a.order_index);
+        assert_eq!(titles(&todos).await, ["a", "b"]);
+        assert_eq!(todos.get(b.id).await.unwrap().order_index, a.order_index);
+    }
+
+    #[tokio::test]
+    async fn a_bad_url_adds_nothing() {
+        let todos = service().await;
+
+        let link = |url: &str| NewTodo {
+            url: Some(url.to_string()),
+            ..NewTodo::default()
+        };
+
+        assert!(
+            todos
+                .add_with_placement("bad", Some(day()), MovePlacement::Top, link("not a url"))
+                .await
+                .is_err()
+        );
+        assert_eq!(todos.count().await.unwrap(), 0);
+
+        let linked = todos
+            .add_with_placement(
+                "good",
+                Some(day()),
+                MovePlacement::Top,
+                link(" https://example.com "),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(linked.url.as_deref(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn blank_none_and_null_mean_no_project() {
+        for input in ["", "  ", "none", "None", " NULL ", "null"] {
+            assert!(ProjectFilter::is_no_project(input), "'{input}'");
+        }
+
+        for input in ["nonesuch", "n", "myapp", "\"\""] {
+            assert!(!ProjectFilter::is_no_project(input), "'{input}'");
+        }
+    }
+
+    #[tokio::test]
+    async fn is_null_lists_only_todos_without_a_project() {
+        let conn = init_memory_database().await.unwrap();
+        let todos = TodoService::new(conn.clone());
+
+        let workspace = WorkspaceService::new(conn.clone())
+            .create("work")
+            .await
+            .unwrap();
+        let project = ProjectService::new(conn)
+            .create("app", workspace.id, "active")
+            .await
+            .unwrap();
+
+        todos
+            .add("loose", Some(day()), None, Some(workspace.id), None, None)
+            .await
+            .unwrap();
+        todos
+            .add(
+                "filed",
+                Some(day()),
+                None,
+                Some(workspace.id),
+                Some(project.id),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let titles_for = async |project| -> Vec<String> {
+            todos
+                .list(ListOptions {
+                    project,
+                    ..ListOptions::today(day())
+                })
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|todo| todo.title)
+                .collect()
+        };
+
+        let no_project = titles_for(ProjectFilter::IsNull).await;
+        let in_project = titles_for(ProjectFilter::Equals(project.id)).await;
+
+        assert_eq!(no_project, ["loose"]);
+        assert_eq!(in_project, ["filed"]);
+    }
+
+    #[tokio::test]
+    async fn swapping_leaves_the_other_rows_alone() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+        let c = add_bottom(&todos, "c").await;
+        let d = add_bottom(&todos, "d").await;
+
+        todos.swap_order(a.id, d.id).await.unwrap();
+
+        assert_eq!(titles(&todos).await, ["d", "b", "c", "a"]);
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, d.order_index);
+        assert_eq!(todos.get(d.id).await.unwrap().order_index, a.order_index);
+        assert_eq!(todos.get(b.id).await.unwrap().order_index, b.order_index);
+        assert_eq!(todos.get(c.id).await.unwrap().order_index, c.order_index);
+    }
+
+    #[tokio::test]
+    async fn swapping_across_columns_is_refused() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let tomorrow = todos
+            .add("tomorrow", day().succ_opt(), None, None, None, None)
+            .await
+            .unwrap();
+        let backlog = todos
+            .add("someday", None, None, None, None, None)
+            .await
+            .unwrap();
+        let other_lane = todos
+            .add("other lane", None, None, None, None, None)
+            .await
+            .unwrap();
+
+        todos.set_backlog_column(other_lane.id, 1).await.unwrap();
+
+        assert!(todos.swap_order(a.id, tomorrow.id).await.is_err());
+        assert!(todos.swap_order(a.id, backlog.id).await.is_err());
+        assert!(todos.swap_order(backlog.id, other_lane.id).await.is_err());
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, a.order_index);
+    }
+
+    #[tokio::test]
+    async fn swapping_a_done_todo_with_a_pending_one_is_refused() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+
+        todos.mark_done(b.id, day()).await.unwrap();
+
+        assert!(todos.swap_order(a.id, b.id).await.is_err());
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, a.order_index);
+    }
+
+    #[tokio::test]
+    async fn a_failed_rebalance_leaves_the_column_as_it_was() {
+        let todos = service().await;
+
+        let a = add_bottom(&todos, "a").await;
+        let b = add_bottom(&todos, "b").await;
+        let c = add_bottom(&todos, "c").await;
+
+        set_index(&todos, a.id, 5).await;
+        set_index(&todos, b.id, 6).await;
+        set_index(&todos, c.id, 7).await;
+
+        fail_updates_of(&todos, "c").await;
+
+        assert!(todos.rebalance(ListScope::Day(day())).await.is_err());
+        assert_eq!(todos.get(a.id).await.unwrap().order_index, 5);
+        assert_eq!(todos.get(b.id).await.unwrap().order_index, 6);
+        assert_eq!(titles(&todos).await, ["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn a_failed_rollover_moves_nothing() {
+        let todos = service().await;
+        let yesterday = day().pred_opt().unwrap();
+
+        let a = todos
+            .add("a", Some(yesterday), None, None, None, None)
+            .await
+            .unwrap();
+        let b = todos
+            .add_with_placement(
+                "b",
+                Some(yesterday),
+                MovePlacement::Bottom,
+                NewTodo::default(),
+            )
+            .await
+            .unwrap();
+
+        fail_updates_of(&todos, "b").await;
+
+        assert!(todos.rollover_to(day()).await.is_err());
+
+        for original in [a, b] {
+            let todo = todos.get(original.id).await.unwrap();
+
+            assert_eq!(todo.scheduled_for, Some(yesterday));
+            assert_eq!(todo.order_index, original.order_index);
+            assert!(rolled_over_from(&todo).is_none());
+        }
+    }
+}
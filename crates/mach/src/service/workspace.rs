@@ -1,10 +1,21 @@
-use crate::entity::workspace;
-use miette::{IntoDiagnostic, Result};
+use crate::entity::{project, todo, workspace};
+use chrono::Utc;
+use miette::{IntoDiagnostic, Result, bail};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, Set, TransactionTrait, sea_query::Expr,
 };
 use uuid::Uuid;
 
+use super::retry::retry_busy;
+
+/// What `WorkspaceService::delete` removed along with the workspace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorkspaceDeletion {
+    pub projects: u64,
+    pub todos: u64,
+}
+
 #[derive(Clone)]
 pub struct WorkspaceService {
     db: DatabaseConnection,
@@ -34,7 +45,9 @@ impl WorkspaceService {
             ..Default::default()
         };
 
-        model.insert(&self.db).await.into_diagnostic()
+        retry_busy(|| model.clone().insert(&self.db))
+            .await
+            .into_diagnostic()
     }
 
     pub async fn list(&self) -> Result<Vec<workspace::Model>> {
@@ -60,6 +73,70 @@ impl WorkspaceService {
 
         let mut active: workspace::ActiveModel = model.into();
         active.name = Set(name.into());
-        active.update(&self.db).await.into_diagnostic()
+        retry_busy(|| active.clone().update(&self.db))
+            .await
+            .into_diagnostic()
+    }
+
+    /// Delete a workspace. Its todos stay, without a workspace or project.
+    /// A workspace that still has projects is refused unless `cascade` is
+    /// set, in which case its projects go too, all in one transaction.
+    pub async fn delete(&self, id: Uuid, cascade: bool) -> Result<WorkspaceDeletion> {
+        let projects = project::Entity::find()
+            .filter(project::Column::WorkspaceId.eq(id))
+            .count(&self.db)
+            .await
+            .into_diagnostic()?;
+
+        if projects > 0 && !cascade {
+            bail!(
+                "workspace still has {} project{}; delete them first or pass --cascade",
+                projects,
+                if projects == 1 { "" } else { "s" }
+            );
+        }
+
+        let todos = retry_busy(|| self.apply_delete(id))
+            .await
+            .into_diagnostic()?;
+
+        Ok(WorkspaceDeletion { projects, todos })
+    }
+
+    async fn apply_delete(&self, id: Uuid) -> Result<u64, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let project_ids: Vec<Uuid> = project::Entity::find()
+            .filter(project::Column::WorkspaceId.eq(id))
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|project| project.id)
+            .collect();
+
+        // Todos (archived ones too) lose the workspace and its projects
+        // rather than being deleted with them.
+        let todos = todo::Entity::update_many()
+            .col_expr(todo::Column::WorkspaceId, Expr::value(None::<Uuid>))
+            .col_expr(todo::Column::ProjectId, Expr::value(None::<Uuid>))
+            .col_expr(todo::Column::UpdatedAt, Expr::value(Utc::now()))
+            .filter(
+                Condition::any()
+                    .add(todo::Column::WorkspaceId.eq(id))
+                    .add(todo::Column::ProjectId.is_in(project_ids)),
+            )
+            .exec(&txn)
+            .await?;
+
+        project::Entity::delete_many()
+            .filter(project::Column::WorkspaceId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        workspace::Entity::delete_by_id(id).exec(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(todos.rows_affected)
     }
 }
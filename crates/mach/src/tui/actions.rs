@@ -1,33 +1,184 @@
-use chrono::Duration as ChronoDuration;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use chrono::{Duration as ChronoDuration, NaiveDate};
 use uuid::Uuid;
 
-use crate::service::config::WeekStart;
-use crate::service::todo::{ListOptions, ListScope, MovePlacement, ReorderDirection};
+use crate::entity::todo;
+use crate::service::config::{TodoTemplate, ViewSpan, WeekStart};
+use crate::service::todo::{
+    ListOptions, ListScope, MovePlacement, ProjectFilter, ReorderDirection, SortKey, TagFilter,
+};
 
 use super::App;
 use super::cursor::{CursorState, Horizontal, Selection};
-use super::modes::{AddTarget, AddTodoState, DetailField, DetailState, SettingsState, UiMode};
-use super::state::{BACKLOG_COLUMNS, BoardData, TodoView, WeekState};
+use super::modes::{
+    AddTarget, AddTodoState, ConfirmState, DayFullState, DayPlacement, DetailField, DetailState,
+    EditTitleState, PendingAction, PrimaryView, ProjectPickerState, ReviewState, SettingsState,
+    UiMode,
+};
+use super::state::{
+    AGENDA_DAYS, AgendaItem, BACKLOG_COLUMNS, BoardData, ScopeLabels, TodoView, WeekState,
+};
+use super::undo::UndoAction;
+
+/// How long a status bar message stays up.
+const STATUS_MESSAGE_TTL: Duration = Duration::from_secs(4);
 
 impl App {
+    /// Run an action that can fail, showing its error in the status bar
+    /// rather than dropping it.
+    pub fn try_action<T>(
+        &mut self,
+        action: impl FnOnce(&mut Self) -> miette::Result<T>,
+    ) -> Option<T> {
+        match action(self) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.report_error(err.to_string());
+
+                None
+            }
+        }
+    }
+
+    /// Show `message` in the status bar for a few seconds.
+    pub fn report_error(&mut self, message: String) {
+        self.status_message = Some((message, Instant::now()));
+    }
+
+    /// Drop the status bar message once it has been up long enough.
+    pub fn expire_status_message(&mut self) {
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|(_, at)| at.elapsed() >= STATUS_MESSAGE_TTL)
+        {
+            self.status_message = None;
+        }
+    }
+
+    /// Past midnight, move today forward and roll the overdue todos over as
+    /// on launch. A board showing the current week follows today into the
+    /// next one; one paged to another week stays where it is.
+    pub fn check_new_day(&mut self) {
+        let yesterday = self.services.today();
+
+        if !self.services.refresh_today() {
+            return;
+        }
+
+        let today = self.services.today();
+        let yesterday_idx = self.state.column_index(yesterday);
+        // Focus follows today only if it was on yesterday.
+        let follow = yesterday_idx == Some(self.cursor.focus);
+
+        self.try_action(|app| app.runtime.block_on(app.services.todos.rollover_to(today)));
+
+        if yesterday_idx.is_some() && self.state.column_index(today).is_none() {
+            self.state = WeekState::new(today, self.week_pref, self.view_span);
+
+            self.board.reset(self.state.columns.len());
+
+            self.cursor
+                .sync_after_refresh(self.state.columns.len(), &self.board);
+        }
+
+        self.try_action(|app| app.refresh_board());
+
+        if follow && let Some(idx) = self.state.column_index(today) {
+            self.cursor.set_focus_row(idx, 0);
+        }
+    }
+
+    /// Move overdue todos to the bottom of today, like the rollover on
+    /// launch, and show today.
+    pub fn rollover_overdue(&mut self) -> miette::Result<()> {
+        let moved = self
+            .runtime
+            .block_on(self.services.todos.rollover_to(self.services.today()))?;
+
+        self.refresh_board()?;
+
+        if !moved.is_empty() {
+            self.jump_to_today();
+        }
+
+        Ok(())
+    }
+
     pub fn refresh_board(&mut self) -> miette::Result<()> {
+        let project_names = self.project_names()?;
+
         for (idx, column) in self.state.columns.iter().enumerate() {
             let opts = ListOptions {
                 scope: ListScope::Day(column.date),
                 include_done: true,
+                project: self.project_filter,
+                tag: TagFilter::Any,
+                sort: SortKey::Manual,
             };
 
             let todos = self.runtime.block_on(self.services.todos.list(opts))?;
-
-            self.board
-                .set_day(idx, todos.into_iter().map(TodoView::from).collect());
+            let todos = todos
+                .into_iter()
+                .map(|todo| TodoView::new(todo, &project_names))
+                .collect();
+
+            if !self.show_done {
+                self.board.set_day_without_done(idx, todos);
+            } else if self.is_day_collapsed(column.date) {
+                self.board.set_day_collapsed(idx, todos);
+            } else {
+                self.board.set_day(idx, todos);
+            }
         }
 
         self.refresh_backlog()?;
 
+        self.overdue = self
+            .runtime
+            .block_on(self.services.todos.count_overdue(self.services.today()))?;
+
         self.cursor
             .sync_after_refresh(self.state.columns.len(), &self.board);
 
+        if matches!(self.ui_mode, UiMode::Agenda) {
+            self.refresh_agenda()?;
+        }
+
+        Ok(())
+    }
+
+    /// Reload the agenda: every todo from today through the next
+    /// `AGENDA_DAYS` days, in the board's project filter.
+    pub fn refresh_agenda(&mut self) -> miette::Result<()> {
+        let from = self.services.today();
+        let to = from + ChronoDuration::days(AGENDA_DAYS - 1);
+
+        let todos = self.runtime.block_on(self.services.todos.list_range(
+            from,
+            to,
+            self.show_done,
+            self.project_filter,
+        ))?;
+
+        let project_names = self.project_names()?;
+
+        self.agenda.items = todos
+            .into_iter()
+            .filter_map(|todo| {
+                let date = todo.scheduled_for?;
+
+                Some(AgendaItem {
+                    date,
+                    todo: TodoView::new(todo, &project_names),
+                })
+            })
+            .collect();
+
+        self.agenda_cursor.sync_after_refresh(&self.agenda);
+
         Ok(())
     }
 
@@ -36,14 +187,19 @@ impl App {
             .runtime
             .block_on(self.services.todos.list(ListOptions {
                 scope: ListScope::Backlog,
-                include_done: true,
+                include_done: self.show_done,
+                project: self.project_filter,
+                tag: TagFilter::Any,
+                sort: SortKey::Manual,
             }))?;
 
+        let project_names = self.project_names()?;
+
         let mut columns: [Vec<TodoView>; BACKLOG_COLUMNS] = Default::default();
 
         for todo in all_backlog {
             let col = (todo.backlog_column as usize).min(BACKLOG_COLUMNS - 1);
-            columns[col].push(TodoView::from(todo));
+            columns[col].push(TodoView::new(todo, &project_names));
         }
 
         for (col, items) in columns.into_iter().enumerate() {
@@ -55,6 +211,98 @@ impl App {
         Ok(())
     }
 
+    fn project_names(&self) -> miette::Result<HashMap<Uuid, String>> {
+        let projects = self.runtime.block_on(self.services.projects.list())?;
+
+        Ok(projects
+            .into_iter()
+            .map(|project| (project.id, project.name))
+            .collect())
+    }
+
+    fn is_day_collapsed(&self, date: NaiveDate) -> bool {
+        self.collapse_done != self.toggled_days.contains(&date)
+    }
+
+    /// Fold or unfold the done todos of the focused day.
+    pub fn toggle_day_collapse(&mut self) {
+        let date = self.state.columns[self.cursor.focus].date;
+
+        if !self.toggled_days.remove(&date) {
+            self.toggled_days.insert(date);
+        }
+
+        self.cursor.selection = None;
+        self.try_action(|app| app.refresh_board());
+    }
+
+    /// Snapshot a todo before changing or archiving it so `u` can put it
+    /// back.
+    fn remember(&mut self, id: Uuid) -> miette::Result<()> {
+        let model = self.runtime.block_on(self.services.todos.get(id))?;
+
+        self.undo.push(UndoAction::Update(Box::new(model)));
+
+        Ok(())
+    }
+
+    /// Snapshot every todo in `ids` as a single undo step.
+    fn remember_all(&mut self, ids: &[Uuid]) -> miette::Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut models = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            models.push(self.runtime.block_on(self.services.todos.get(*id))?);
+        }
+
+        self.undo.push(UndoAction::Batch(models));
+
+        Ok(())
+    }
+
+    /// `u`: reverse the most recent archive, move or completion toggle (or
+    /// batch of them) and put the cursor back on the first todo.
+    pub fn undo_last(&mut self) -> miette::Result<()> {
+        let snapshots = match self.undo.pop() {
+            Some(UndoAction::Update(model)) => vec![*model],
+            Some(UndoAction::Batch(models)) => models,
+            None => return Ok(()),
+        };
+
+        let mut restored = Vec::with_capacity(snapshots.len());
+
+        for model in snapshots {
+            restored.push(self.runtime.block_on(self.services.todos.restore(model))?);
+        }
+
+        let Some(todo) = restored.into_iter().next() else {
+            return Ok(());
+        };
+
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
+
+        self.refresh_board()?;
+
+        if let Some((col, row)) = self.board.find_day_position(todo.id) {
+            self.cursor.set_focus_row(col, row);
+        }
+
+        if let Some((col, row)) = self.board.find_backlog_position(todo.id) {
+            self.backlog_cursor.column = col;
+            self.backlog_cursor.rows[col] = row;
+        }
+
+        if let Some(row) = self.agenda.position_of(todo.id) {
+            self.agenda_cursor.row = row;
+        }
+
+        Ok(())
+    }
+
     pub fn current_target_id(&self) -> Option<Uuid> {
         self.cursor
             .selection
@@ -69,190 +317,681 @@ impl App {
             .or_else(|| self.backlog_cursor.current_todo_id(&self.board))
     }
 
-    pub fn delete_current(&mut self) -> miette::Result<()> {
+    pub fn archive_current(&mut self) -> miette::Result<()> {
         if let Some(id) = self.current_target_id() {
-            let deleted = self.runtime.block_on(self.services.todos.delete(id))?;
-
-            if deleted {
-                self.cursor.selection = None;
-                self.refresh_board()?;
-            }
+            self.request_archive(id, PrimaryView::Board)?;
         }
         Ok(())
     }
 
-    pub fn delete_backlog_current(&mut self) -> miette::Result<()> {
+    pub fn archive_backlog_current(&mut self) -> miette::Result<()> {
         if let Some(id) = self.backlog_current_target_id() {
-            let deleted = self.runtime.block_on(self.services.todos.delete(id))?;
+            self.request_archive(id, PrimaryView::Backlog)?;
+        }
+        Ok(())
+    }
 
-            if deleted {
-                self.backlog_cursor.selection = None;
-                self.refresh_backlog()?;
-            }
+    pub fn archive_agenda_current(&mut self) -> miette::Result<()> {
+        if let Some(id) = self.agenda_cursor.current_todo_id(&self.agenda) {
+            self.request_archive(id, PrimaryView::Agenda)?;
         }
         Ok(())
     }
 
-    pub fn mark_complete(&mut self) -> miette::Result<()> {
-        if let Some(id) = self.current_target_id() {
-            let current_status = self
-                .board
-                .day_status_of(id)
-                .unwrap_or("pending")
-                .to_string();
+    /// Archive straight away, or ask first when `confirm_deletes` is on.
+    fn request_archive(&mut self, id: Uuid, from: PrimaryView) -> miette::Result<()> {
+        if !self.confirm_deletes {
+            return self.archive_todo(id);
+        }
 
-            let focus = self.cursor.focus;
-            let prev_row = self.cursor.row_for(focus, &self.board);
+        let todo = self.runtime.block_on(self.services.todos.get(id))?;
 
-            self.cursor.selection = None;
+        self.ui_mode = UiMode::Confirm(ConfirmState {
+            prompt: format!("Archive '{}'?", todo.title),
+            action: PendingAction::Archive(id),
+            return_to: from,
+        });
 
-            if current_status == "done" {
-                self.runtime
-                    .block_on(self.services.todos.mark_pending(id))?;
-            } else {
-                let today = self.services.today();
+        Ok(())
+    }
 
-                self.runtime
-                    .block_on(self.services.todos.mark_done(id, today))?;
-            }
+    /// Archive rather than delete, so `u` or `mach restore` can bring the
+    /// todo back.
+    fn archive_todo(&mut self, id: Uuid) -> miette::Result<()> {
+        self.remember(id)?;
 
-            self.refresh_board()?;
+        self.runtime.block_on(self.services.todos.archive(id))?;
 
-            if let Some((new_col, row)) = self.board.find_day_position(id) {
-                self.cursor.set_focus_row(new_col, row);
-            } else if let Some(row) = prev_row {
-                let len = self.board.day_len(focus);
+        self.selected.remove(&id);
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
+        self.refresh_board()
+    }
 
-                if len > 0 {
-                    let new_row = row.min(len.saturating_sub(1));
+    /// Close the confirmation prompt, running its action when `confirmed`.
+    pub fn resolve_confirm(&mut self, confirmed: bool) -> miette::Result<()> {
+        let UiMode::Confirm(state) = std::mem::replace(&mut self.ui_mode, UiMode::Board) else {
+            return Ok(());
+        };
 
-                    self.cursor.set_focus_row(focus, new_row);
-                }
-            }
+        self.ui_mode = state.return_to.mode();
+
+        if !confirmed {
+            return Ok(());
+        }
+
+        match state.action {
+            PendingAction::Archive(id) => self.archive_todo(id),
+            PendingAction::ArchiveSelected => self.archive_selected(),
+            PendingAction::MoveLaneToToday(col) => self.move_lane_to_today(col),
         }
-        Ok(())
     }
 
-    pub fn mark_backlog_complete(&mut self) -> miette::Result<()> {
-        if let Some(id) = self.backlog_current_target_id() {
-            let current_status = self
-                .board
-                .backlog_status_of(id)
-                .unwrap_or("pending")
-                .to_string();
+    /// `V`: add the focused todo to the multi-selection, or take it out.
+    /// Picking todos this way ends drag mode.
+    pub fn toggle_selected(&mut self, id: Option<Uuid>) {
+        let Some(id) = id else {
+            return;
+        };
 
-            let col = self.backlog_cursor.column;
+        if !self.selected.remove(&id) {
+            self.selected.insert(id);
+        }
 
-            let prev_row = self.backlog_cursor.row_for(col, &self.board);
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
+    }
 
-            self.backlog_cursor.selection = None;
+    /// Empty the multi-selection, returning the picked ids in the order they
+    /// appear on the board, then the backlog, then any no longer in view.
+    fn take_selected(&mut self) -> Vec<Uuid> {
+        let mut picked = std::mem::take(&mut self.selected);
 
-            if current_status == "done" {
+        let mut ids: Vec<Uuid> = self
+            .board
+            .days
+            .iter()
+            .chain(self.board.backlog_columns.iter())
+            .flatten()
+            .filter_map(|todo| picked.remove(&todo.id).then_some(todo.id))
+            .collect();
+
+        ids.extend(picked);
+
+        ids
+    }
+
+    /// `x` with todos picked: toggle each one between done and pending.
+    pub fn toggle_complete_selected(&mut self) -> miette::Result<()> {
+        let ids = self.take_selected();
+
+        self.remember_all(&ids)?;
+
+        let today = self.services.today();
+
+        for id in ids {
+            let todo = self.runtime.block_on(self.services.todos.get(id))?;
+
+            if todo.status == "done" {
                 self.runtime
-                    .block_on(self.services.todos.mark_pending(id))?;
+                    .block_on(self.services.todos.mark_pending(id, true))?;
             } else {
-                let today = self.services.today();
-
                 self.runtime
                     .block_on(self.services.todos.mark_done(id, today))?;
             }
+        }
 
-            self.refresh_board()?;
+        self.refresh_board()
+    }
 
-            if let Some((new_col, row)) = self.board.find_backlog_position(id) {
-                self.backlog_cursor.column = new_col;
+    /// `dd` with todos picked: archive them all, asking first when
+    /// `confirm_deletes` is on.
+    pub fn request_archive_selected(&mut self, from: PrimaryView) -> miette::Result<()> {
+        if !self.confirm_deletes {
+            return self.archive_selected();
+        }
 
-                self.backlog_cursor.rows[new_col] = row;
-            } else if let Some(row) = prev_row {
-                let len = self.board.backlog_col_len(col);
+        let count = self.selected.len();
+        let noun = if count == 1 { "todo" } else { "todos" };
+
+        self.ui_mode = UiMode::Confirm(ConfirmState {
+            prompt: format!("Archive {count} selected {noun}?"),
+            action: PendingAction::ArchiveSelected,
+            return_to: from,
+        });
 
-                if len > 0 {
-                    self.backlog_cursor.rows[col] = row.min(len.saturating_sub(1));
-                }
-            }
-        }
         Ok(())
     }
 
-    pub fn move_to_backlog(&mut self) -> miette::Result<()> {
-        if let Some(id) = self.current_target_id() {
-            if matches!(self.board.day_status_of(id), Some("done")) {
-                return Ok(());
+    fn archive_selected(&mut self) -> miette::Result<()> {
+        let ids = self.take_selected();
+
+        self.remember_all(&ids)?;
+
+        for id in ids {
+            self.runtime.block_on(self.services.todos.archive(id))?;
+        }
+
+        self.refresh_board()
+    }
+
+    /// `s` with todos picked: send them to the backlog in order. Done todos
+    /// follow the same rule as a single `s` and stay put unless a backlog
+    /// column is set aside for them.
+    pub fn move_selected_to_backlog(&mut self) -> miette::Result<()> {
+        let mut ids = self.take_selected();
+        let mut done = HashSet::new();
+
+        for id in &ids {
+            let todo = self.runtime.block_on(self.services.todos.get(*id))?;
+
+            if todo.status == "done" {
+                done.insert(*id);
             }
+        }
 
-            self.cursor.selection = None;
+        if self.done_backlog_column.is_none() {
+            ids.retain(|id| !done.contains(id));
+        }
+
+        self.remember_all(&ids)?;
 
+        for id in ids {
             self.runtime.block_on(self.services.todos.move_to_scope(
                 id,
                 ListScope::Backlog,
                 MovePlacement::Bottom,
             ))?;
 
-            self.refresh_board()?;
+            if let Some(col) = self.done_backlog_column
+                && done.contains(&id)
+            {
+                self.runtime
+                    .block_on(self.services.todos.set_backlog_column(id, col as i64))?;
+            }
         }
-        Ok(())
+
+        self.refresh_board()
     }
 
-    pub fn move_to_today(&mut self) -> miette::Result<()> {
-        let Some(id) = self.cursor.current_todo_id(&self.board) else {
+    /// `t` with todos picked: put them all on top of today, in order. The
+    /// daily capacity prompt is skipped for batches.
+    pub fn move_selected_to_today(&mut self) -> miette::Result<()> {
+        let ids = self.take_selected();
+
+        self.remember_all(&ids)?;
+
+        let today = self.services.today();
+
+        // Each lands on top, so go last to first to keep their order.
+        for id in ids.into_iter().rev() {
+            self.runtime.block_on(self.services.todos.move_to_scope(
+                id,
+                ListScope::Day(today),
+                MovePlacement::Top,
+            ))?;
+        }
+
+        self.refresh_board()
+    }
+
+    /// `c`: copy the focused todo into a new pending one on top of its day
+    /// or backlog column and move the cursor onto the copy.
+    pub fn duplicate_todo(&mut self, id: Option<Uuid>) -> miette::Result<()> {
+        let Some(id) = id else {
             return Ok(());
         };
 
-        let today = self.services.today();
+        let copy = self.runtime.block_on(self.services.todos.duplicate(id))?;
 
-        self.runtime.block_on(self.services.todos.move_to_scope(
-            id,
-            ListScope::Day(today),
-            MovePlacement::Top,
-        ))?;
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
 
         self.refresh_board()?;
 
+        if let Some((col, row)) = self.board.find_day_position(copy.id) {
+            self.cursor.set_focus_row(col, row);
+        }
+
+        if let Some((col, row)) = self.board.find_backlog_position(copy.id) {
+            self.backlog_cursor.column = col;
+            self.backlog_cursor.rows[col] = row;
+        }
+
         Ok(())
     }
 
-    pub fn move_to_tomorrow(&mut self) -> miette::Result<()> {
-        let Some(id) = self.cursor.current_todo_id(&self.board) else {
+    /// `yy`: hold on to a todo so `p` can move it later, after navigating
+    /// anywhere, other weeks included.
+    pub fn yank(&mut self, id: Option<Uuid>) -> miette::Result<()> {
+        let Some(id) = id else {
             return Ok(());
         };
 
-        let tomorrow = self.services.today() + ChronoDuration::days(1);
-
-        self.runtime.block_on(self.services.todos.move_to_scope(
-            id,
-            ListScope::Day(tomorrow),
-            MovePlacement::Top,
-        ))?;
+        let todo = self.runtime.block_on(self.services.todos.get(id))?;
 
-        self.refresh_board()?;
+        self.yanked = Some((id, todo.title));
 
         Ok(())
     }
 
-    pub fn move_backlog_to_day(&mut self, days_from_today: i64) -> miette::Result<()> {
-        let Some(id) = self.backlog_current_target_id() else {
+    /// The yanked todo, if it still exists and isn't archived. Consumes the
+    /// yank either way.
+    fn take_yanked(&mut self) -> miette::Result<Option<todo::Model>> {
+        let Some((id, _)) = self.yanked.take() else {
+            return Ok(None);
+        };
+
+        match self.runtime.block_on(self.services.todos.get(id)) {
+            Ok(todo) if todo.archived_at.is_none() => Ok(Some(todo)),
+            _ => Ok(None),
+        }
+    }
+
+    /// `p` on the board: move the yanked todo to the top of the focused day.
+    /// A done todo joins the day's done todos instead.
+    pub fn paste_on_day(&mut self) -> miette::Result<()> {
+        let Some(date) = self
+            .state
+            .columns
+            .get(self.cursor.focus)
+            .map(|col| col.date)
+        else {
             return Ok(());
         };
 
-        if matches!(self.board.backlog_status_of(id), Some("done")) {
+        let Some(todo) = self.take_yanked()? else {
+            return Ok(());
+        };
+
+        let done = todo.status == "done";
+
+        if !done && self.prompt_if_day_full(date, DayPlacement::Move(todo.id))? {
             return Ok(());
         }
 
-        let target_date = self.services.today() + ChronoDuration::days(days_from_today);
+        self.remember(todo.id)?;
+
+        self.cursor.selection = None;
+
+        self.runtime.block_on(self.services.todos.move_to_scope(
+            todo.id,
+            ListScope::Day(date),
+            if done {
+                MovePlacement::Bottom
+            } else {
+                MovePlacement::Top
+            },
+        ))?;
+
+        self.refresh_board()?;
+
+        if let Some((col, row)) = self.board.find_day_position(todo.id) {
+            self.cursor.set_focus_row(col, row);
+        }
+
+        Ok(())
+    }
+
+    /// `p` in the backlog: move the yanked todo into the focused column.
+    pub fn paste_in_backlog(&mut self) -> miette::Result<()> {
+        let Some(todo) = self.take_yanked()? else {
+            return Ok(());
+        };
+
+        let col = self.backlog_cursor.column;
+
+        self.remember(todo.id)?;
 
         self.backlog_cursor.selection = None;
 
         self.runtime.block_on(self.services.todos.move_to_scope(
-            id,
-            ListScope::Day(target_date),
-            MovePlacement::Top,
+            todo.id,
+            ListScope::Backlog,
+            if todo.status == "done" {
+                MovePlacement::Bottom
+            } else {
+                MovePlacement::Top
+            },
         ))?;
 
+        self.runtime
+            .block_on(self.services.todos.set_backlog_column(todo.id, col as i64))?;
+
+        self.refresh_board()?;
+
+        if let Some((col, row)) = self.board.find_backlog_position(todo.id) {
+            self.backlog_cursor.column = col;
+            self.backlog_cursor.rows[col] = row;
+        }
+
+        Ok(())
+    }
+
+    pub fn mark_complete(&mut self) -> miette::Result<()> {
+        if let Some(id) = self.current_target_id() {
+            let current_status = self
+                .board
+                .day_status_of(id)
+                .unwrap_or("pending")
+                .to_string();
+
+            let focus = self.cursor.focus;
+            let prev_row = self.cursor.row_for(focus, &self.board);
+
+            self.remember(id)?;
+
+            self.cursor.selection = None;
+
+            if current_status == "done" {
+                self.runtime
+                    .block_on(self.services.todos.mark_pending(id, true))?;
+            } else {
+                let today = self.services.today();
+
+                self.runtime
+                    .block_on(self.services.todos.mark_done(id, today))?;
+            }
+
+            self.refresh_board()?;
+
+            if let Some((new_col, row)) = self.board.find_day_position(id) {
+                self.cursor.set_focus_row(new_col, row);
+            } else if let Some(row) = prev_row {
+                let len = self.board.day_len(focus);
+
+                if len > 0 {
+                    let new_row = row.min(len.saturating_sub(1));
+
+                    self.cursor.set_focus_row(focus, new_row);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `x` in the agenda: toggle the focused todo between done and pending,
+    /// keeping the cursor on it.
+    pub fn mark_agenda_complete(&mut self) -> miette::Result<()> {
+        let Some(id) = self.agenda_cursor.current_todo_id(&self.agenda) else {
+            return Ok(());
+        };
+
+        let done = self.agenda.status_of(id) == Some("done");
+
+        self.remember(id)?;
+
+        if done {
+            self.runtime
+                .block_on(self.services.todos.mark_pending(id, true))?;
+        } else {
+            let today = self.services.today();
+
+            self.runtime
+                .block_on(self.services.todos.mark_done(id, today))?;
+        }
+
         self.refresh_board()?;
 
+        if let Some(row) = self.agenda.position_of(id) {
+            self.agenda_cursor.row = row;
+        }
+
+        Ok(())
+    }
+
+    pub fn mark_backlog_complete(&mut self) -> miette::Result<()> {
+        if let Some(id) = self.backlog_current_target_id() {
+            let current_status = self
+                .board
+                .backlog_status_of(id)
+                .unwrap_or("pending")
+                .to_string();
+
+            let col = self.backlog_cursor.column;
+
+            let prev_row = self.backlog_cursor.row_for(col, &self.board);
+
+            self.remember(id)?;
+
+            self.backlog_cursor.selection = None;
+
+            if current_status == "done" {
+                self.runtime
+                    .block_on(self.services.todos.mark_pending(id, true))?;
+            } else {
+                let today = self.services.today();
+
+                self.runtime
+                    .block_on(self.services.todos.mark_done(id, today))?;
+            }
+
+            self.refresh_board()?;
+
+            if let Some((new_col, row)) = self.board.find_backlog_position(id) {
+                self.backlog_cursor.column = new_col;
+
+                self.backlog_cursor.rows[new_col] = row;
+            } else if let Some(row) = prev_row {
+                let len = self.board.backlog_col_len(col);
+
+                if len > 0 {
+                    self.backlog_cursor.rows[col] = row.min(len.saturating_sub(1));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn move_to_backlog(&mut self) -> miette::Result<()> {
+        if let Some(id) = self.current_target_id() {
+            // Done todos stay put unless a lane is set aside for them.
+            let done_lane = if matches!(self.board.day_status_of(id), Some("done")) {
+                let Some(col) = self.done_backlog_column else {
+                    return Ok(());
+                };
+
+                Some(col)
+            } else {
+                None
+            };
+
+            self.remember(id)?;
+
+            self.cursor.selection = None;
+
+            self.runtime.block_on(self.services.todos.move_to_scope(
+                id,
+                ListScope::Backlog,
+                MovePlacement::Bottom,
+            ))?;
+
+            if let Some(col) = done_lane {
+                self.runtime
+                    .block_on(self.services.todos.set_backlog_column(id, col as i64))?;
+            }
+
+            self.refresh_board()?;
+        }
         Ok(())
     }
 
+    pub fn move_to_today(&mut self) -> miette::Result<()> {
+        let Some(id) = self.cursor.current_todo_id(&self.board) else {
+            return Ok(());
+        };
+
+        let today = self.services.today();
+
+        if self.prompt_if_day_full(today, DayPlacement::Move(id))? {
+            return Ok(());
+        }
+
+        self.place_on_day(id, today)
+    }
+
+    pub fn move_to_tomorrow(&mut self) -> miette::Result<()> {
+        let Some(id) = self.cursor.current_todo_id(&self.board) else {
+            return Ok(());
+        };
+
+        let tomorrow = self.services.today() + ChronoDuration::days(1);
+
+        if self.prompt_if_day_full(tomorrow, DayPlacement::Move(id))? {
+            return Ok(());
+        }
+
+        self.place_on_day(id, tomorrow)
+    }
+
+    pub fn move_backlog_to_day(&mut self, days_from_today: i64) -> miette::Result<()> {
+        let Some(id) = self.backlog_current_target_id() else {
+            return Ok(());
+        };
+
+        if matches!(self.board.backlog_status_of(id), Some("done")) {
+            return Ok(());
+        }
+
+        let target_date = self.services.today() + ChronoDuration::days(days_from_today);
+
+        if self.prompt_if_day_full(target_date, DayPlacement::Move(id))? {
+            return Ok(());
+        }
+
+        self.place_on_day(id, target_date)
+    }
+
+    /// `A`: ask before scheduling the focused backlog column's pending todos
+    /// for today, since it can move a lot at once.
+    pub fn request_move_lane_to_today(&mut self) {
+        let col = self.backlog_cursor.column;
+
+        let pending = self
+            .board
+            .backlog_columns
+            .get(col)
+            .map(|todos| todos.iter().filter(|todo| todo.status != "done").count())
+            .unwrap_or(0);
+
+        if pending == 0 {
+            return;
+        }
+
+        let noun = if pending == 1 { "todo" } else { "todos" };
+
+        self.ui_mode = UiMode::Confirm(ConfirmState {
+            prompt: format!("Move {pending} {noun} from column {} to today?", col + 1),
+            action: PendingAction::MoveLaneToToday(col),
+            return_to: PrimaryView::Backlog,
+        });
+    }
+
+    fn move_lane_to_today(&mut self, col: usize) -> miette::Result<()> {
+        self.backlog_cursor.selection = None;
+
+        self.runtime.block_on(self.services.todos.move_all(
+            ListScope::Backlog,
+            ListScope::Day(self.services.today()),
+            Some(col as i64),
+            MovePlacement::Top,
+        ))?;
+
+        self.refresh_board()
+    }
+
+    fn place_on_day(&mut self, id: Uuid, date: NaiveDate) -> miette::Result<()> {
+        self.remember(id)?;
+
+        self.backlog_cursor.selection = None;
+
+        self.runtime.block_on(self.services.todos.move_to_scope(
+            id,
+            ListScope::Day(date),
+            MovePlacement::Top,
+        ))?;
+
+        self.refresh_board()
+    }
+
+    /// With a daily capacity set, stop a todo headed for a day that is
+    /// already full and ask where it should go instead. Returns `true` when
+    /// the prompt took over, in which case the caller must not place it.
+    fn prompt_if_day_full(
+        &mut self,
+        date: NaiveDate,
+        placement: DayPlacement,
+    ) -> miette::Result<bool> {
+        let Some(capacity) = self.daily_capacity else {
+            return Ok(false);
+        };
+
+        if let DayPlacement::Move(id) = placement {
+            let todo = self.runtime.block_on(self.services.todos.get(id))?;
+
+            if todo.scheduled_for == Some(date) {
+                return Ok(false);
+            }
+        }
+
+        let pending = self.pending_on(date)?;
+
+        if pending < capacity as usize {
+            return Ok(false);
+        }
+
+        let mut suggestion = None;
+
+        for column in WeekState::new(date, self.week_pref, self.view_span).columns {
+            if column.date > date && self.pending_on(column.date)? < capacity as usize {
+                suggestion = Some(column.date);
+
+                break;
+            }
+        }
+
+        self.ui_mode = UiMode::DayFull(DayFullState {
+            placement,
+            full_day: date,
+            pending,
+            suggestion,
+            return_to: self.current_primary_view().unwrap_or(PrimaryView::Board),
+        });
+
+        Ok(true)
+    }
+
+    fn pending_on(&self, date: NaiveDate) -> miette::Result<usize> {
+        let opts = ListOptions {
+            scope: ListScope::Day(date),
+            include_done: false,
+            project: ProjectFilter::Any,
+            tag: TagFilter::Any,
+            sort: SortKey::Manual,
+        };
+
+        Ok(self.runtime.block_on(self.services.todos.list(opts))?.len())
+    }
+
+    /// Close the capacity prompt, placing its todo on `date` or dropping the
+    /// move (or add) when `date` is `None`.
+    pub fn resolve_day_full(&mut self, date: Option<NaiveDate>) -> miette::Result<()> {
+        let UiMode::DayFull(state) = std::mem::replace(&mut self.ui_mode, UiMode::Board) else {
+            return Ok(());
+        };
+
+        self.ui_mode = state.return_to.mode();
+
+        let Some(date) = date else {
+            return Ok(());
+        };
+
+        match state.placement {
+            DayPlacement::Move(id) => self.place_on_day(id, date),
+            DayPlacement::Add(title) => self.create_todo(title, AddTarget::Day(date)),
+        }
+    }
+
     pub fn move_selected_horizontal(&mut self, dir: Horizontal) -> miette::Result<()> {
         let Some(selection) = self.cursor.selection else {
             return Ok(());
@@ -295,15 +1034,58 @@ impl App {
 
         self.refresh_board()?;
 
+        self.refocus_selection(selection, target_col);
+
+        Ok(())
+    }
+
+    /// `[`/`]` in drag mode: move the selected todo to the same weekday of the
+    /// previous/next week and follow it there.
+    pub fn move_selected_week(&mut self, delta: i32) -> miette::Result<()> {
+        let Some(selection) = self.cursor.selection else {
+            return Ok(());
+        };
+
+        self.state.shift_weeks(delta);
+
+        let day_count = self.state.columns.len();
+        let target_col = selection.column.min(day_count - 1);
+        let target_date = self.state.columns[target_col].date;
+
+        self.runtime.block_on(self.services.todos.move_to_scope(
+            selection.id,
+            ListScope::Day(target_date),
+            MovePlacement::Top,
+        ))?;
+
+        self.board.reset(day_count);
+        self.refresh_board()?;
+
+        self.refocus_selection(selection, target_col);
+
+        Ok(())
+    }
+
+    /// Keep dragging `selection` after it moved to `column`, with the cursor on
+    /// the row it landed in.
+    fn refocus_selection(&mut self, selection: Selection, column: usize) {
+        let row = self
+            .board
+            .find_day_position(selection.id)
+            .filter(|(col, _)| *col == column)
+            .map(|(_, row)| row);
+
         self.cursor.selection = Some(Selection {
-            column: target_col,
-            row: None,
+            column,
+            row,
             ..selection
         });
 
-        self.cursor.focus = target_col;
+        self.cursor.focus = column;
 
-        Ok(())
+        if let Some(row) = row {
+            self.cursor.day_rows[column] = row;
+        }
     }
 
     pub fn reorder_selected(&mut self, dir: ReorderDirection) -> miette::Result<()> {
@@ -334,40 +1116,302 @@ impl App {
         Ok(())
     }
 
+    /// Mark the focused todo for a swap, or clear the mark if it's already
+    /// on that todo.
+    pub fn toggle_swap_mark(&mut self, id: Option<Uuid>) {
+        self.swap_mark = match (self.swap_mark, id) {
+            (Some(marked), Some(id)) if marked == id => None,
+            (_, id) => id,
+        };
+    }
+
+    /// Swap the marked todo with `id`. Todos in different columns are left
+    /// alone and the mark stays so another target can be picked.
+    pub fn swap_with_mark(&mut self, id: Option<Uuid>) -> miette::Result<()> {
+        let (Some(marked), Some(id)) = (self.swap_mark, id) else {
+            return Ok(());
+        };
+
+        self.runtime
+            .block_on(self.services.todos.swap_order(marked, id))?;
+
+        self.swap_mark = None;
+
+        if matches!(self.ui_mode, UiMode::Backlog) {
+            self.refresh_backlog()
+        } else {
+            self.refresh_board()
+        }
+    }
+
     pub fn open_backlog(&mut self) {
         self.ui_mode = UiMode::Backlog;
     }
 
-    pub fn open_settings(&mut self) {
-        let settings = SettingsState {
-            week_start: self.week_pref,
-        };
+    pub fn current_primary_view(&self) -> Option<PrimaryView> {
+        self.ui_mode.primary_view()
+    }
+
+    /// `Tab`/`Shift+Tab`: switch to the next/previous primary view, dropping
+    /// any drag selection on the way out.
+    pub fn cycle_primary_view(&mut self, forward: bool) {
+        let Some(current) = self.current_primary_view() else {
+            return;
+        };
+
+        let target = if forward {
+            current.next()
+        } else {
+            current.prev()
+        };
+
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
+        self.pending_g = false;
+        self.pending_delete = false;
+        self.pending_yank = false;
+
+        self.ui_mode = target.mode();
+
+        if target == PrimaryView::Agenda {
+            self.try_action(|app| app.refresh_agenda());
+        }
+    }
+
+    /// `v`: show upcoming todos as one list instead of the week grid.
+    pub fn open_agenda(&mut self) {
+        self.cursor.selection = None;
+        self.pending_delete = false;
+        self.pending_yank = false;
+
+        self.ui_mode = UiMode::Agenda;
+
+        self.try_action(|app| app.refresh_agenda());
+    }
+
+    pub fn open_done_today(&mut self) -> miette::Result<()> {
+        let todos = self
+            .runtime
+            .block_on(self.services.todos.completed_on(self.services.today()))?;
+
+        self.ui_mode = UiMode::DoneToday(todos.into_iter().map(|todo| todo.title).collect());
+
+        Ok(())
+    }
+
+    pub fn open_settings(&mut self) {
+        let settings = SettingsState {
+            week_start: self.week_pref,
+            view_span: self.view_span,
+            restore_session: self.restore_session,
+            selection_pulse: self.selection_pulse,
+            collapse_done: self.collapse_done,
+            wrap_titles: self.wrap_titles,
+            show_note_preview: self.show_note_preview,
+            weekly_capacity: self.weekly_capacity,
+            daily_capacity: self.daily_capacity,
+            done_backlog_column: self.done_backlog_column,
+            confirm_deletes: self.confirm_deletes,
+        };
+
+        self.ui_mode = UiMode::Settings(settings);
+    }
+
+    pub fn apply_week_start(&mut self, week_start: WeekStart) {
+        if week_start == self.week_pref {
+            return;
+        }
+
+        self.week_pref = week_start;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_week_start(week_start))
+        {
+            self.report_error(format!("failed to save week start preference: {err}"));
+        }
+
+        self.state = WeekState::new(self.services.today(), week_start, self.view_span);
+        self.board = BoardData::new(self.state.columns.len());
+        self.cursor = CursorState::new(self.state.columns.len());
+        if let Some(idx) = self.state.column_index(self.services.today()) {
+            self.cursor.set_focus_row(idx, 0);
+        }
+
+        self.try_action(|app| app.refresh_board());
+    }
+
+    pub fn apply_selection_pulse(&mut self, enabled: bool) {
+        self.selection_pulse = enabled;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_selection_pulse(enabled))
+        {
+            self.report_error(format!("failed to save selection pulse preference: {err}"));
+        }
+    }
+
+    pub fn apply_collapse_done(&mut self, enabled: bool) {
+        self.collapse_done = enabled;
+        self.toggled_days.clear();
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_collapse_done(enabled))
+        {
+            self.report_error(format!("failed to save collapse done preference: {err}"));
+        }
+
+        self.try_action(|app| app.refresh_board());
+    }
+
+    pub fn apply_wrap_titles(&mut self, enabled: bool) {
+        self.wrap_titles = enabled;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_wrap_titles(enabled))
+        {
+            self.report_error(format!("failed to save wrap titles preference: {err}"));
+        }
+    }
+
+    pub fn apply_restore_session(&mut self, enabled: bool) {
+        self.restore_session = enabled;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_restore_session(enabled))
+        {
+            self.report_error(format!("failed to save restore session preference: {err}"));
+        }
+    }
+
+    pub fn apply_show_note_preview(&mut self, enabled: bool) {
+        self.show_note_preview = enabled;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_show_note_preview(enabled))
+        {
+            self.report_error(format!("failed to save note preview preference: {err}"));
+        }
+    }
+
+    /// `H`: hide or show done todos on the board, backlog and agenda.
+    pub fn toggle_show_done(&mut self) {
+        self.show_done = !self.show_done;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_show_done(self.show_done))
+        {
+            self.report_error(format!("failed to save show done preference: {err}"));
+        }
+
+        self.cursor.selection = None;
+        self.backlog_cursor.selection = None;
+
+        self.try_action(|app| app.refresh_board());
+    }
+
+    pub fn apply_weekly_capacity(&mut self, capacity: Option<u32>) {
+        self.weekly_capacity = capacity;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_weekly_capacity(capacity))
+        {
+            self.report_error(format!("failed to save weekly capacity preference: {err}"));
+        }
+    }
+
+    pub fn apply_daily_capacity(&mut self, capacity: Option<u32>) {
+        self.daily_capacity = capacity;
 
-        self.ui_mode = UiMode::Settings(settings);
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_daily_capacity(capacity))
+        {
+            self.report_error(format!("failed to save daily capacity preference: {err}"));
+        }
     }
 
-    pub fn apply_week_start(&mut self, week_start: WeekStart) {
-        if week_start == self.week_pref {
+    /// Switch the board to `span` and rebuild it around today.
+    pub fn apply_view_span(&mut self, span: ViewSpan) {
+        if span == self.view_span {
             return;
         }
 
-        self.week_pref = week_start;
+        self.view_span = span;
 
         if let Err(err) = self
             .runtime
-            .block_on(self.services.config.save_week_start(week_start))
+            .block_on(self.services.config.save_view_span(span))
         {
-            eprintln!("failed to save week start preference: {err}");
+            self.report_error(format!("failed to save view span preference: {err}"));
         }
 
-        self.state = WeekState::new(self.services.today(), week_start);
+        self.state = WeekState::new(self.services.today(), self.week_pref, span);
         self.board = BoardData::new(self.state.columns.len());
         self.cursor = CursorState::new(self.state.columns.len());
         if let Some(idx) = self.state.column_index(self.services.today()) {
             self.cursor.set_focus_row(idx, 0);
         }
 
-        self.refresh_board().ok();
+        self.try_action(|app| app.refresh_board());
+    }
+
+    pub fn apply_confirm_deletes(&mut self, enabled: bool) {
+        self.confirm_deletes = enabled;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_confirm_deletes(enabled))
+        {
+            self.report_error(format!(
+                "failed to save delete confirmation preference: {err}"
+            ));
+        }
+    }
+
+    pub fn apply_done_backlog_column(&mut self, column: Option<usize>) {
+        self.done_backlog_column = column;
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_done_backlog_column(column))
+        {
+            self.report_error(format!(
+                "failed to save done backlog lane preference: {err}"
+            ));
+        }
+    }
+
+    /// Pending todos across the displayed week, from the loaded board data.
+    pub fn week_pending_count(&self) -> usize {
+        self.board
+            .days
+            .iter()
+            .flatten()
+            .filter(|todo| todo.status != "done")
+            .count()
+    }
+
+    /// Leave the welcome screen for good, optionally seeding sample data.
+    pub fn finish_welcome(&mut self, seed: bool) {
+        if seed && let Err(err) = self.runtime.block_on(self.services.seed_sample()) {
+            self.report_error(format!("failed to create sample todos: {err}"));
+        }
+
+        if let Err(err) = self.runtime.block_on(self.services.config.save_onboarded()) {
+            self.report_error(format!("failed to save onboarding state: {err}"));
+        }
+
+        self.ui_mode = UiMode::Board;
+
+        self.try_action(|app| app.refresh_board());
     }
 
     pub fn open_add_todo_board(&mut self) {
@@ -386,21 +1430,52 @@ impl App {
     }
 
     pub fn submit_add_todo(&mut self, title: String, target: AddTarget) -> miette::Result<()> {
+        if let AddTarget::Day(date) = target
+            && self.prompt_if_day_full(date, DayPlacement::Add(title.clone()))?
+        {
+            return Ok(());
+        }
+
+        self.create_todo(title, target)
+    }
+
+    fn create_todo(&mut self, title: String, target: AddTarget) -> miette::Result<()> {
+        let (title, template) = self.expand_add_template(title)?;
+        let notes = template.notes.clone();
+
+        let (mut workspace_id, mut project_id) = self.filter_assignment()?;
+
+        if project_id.is_none()
+            && let Some(reference) = &template.project
+            && let Some(project) = self
+                .runtime
+                .block_on(self.services.projects.find_by_name_or_id(reference))?
+        {
+            workspace_id = Some(project.workspace_id);
+            project_id = Some(project.id);
+        }
+
         match target {
             AddTarget::Day(date) => {
                 self.runtime.block_on(self.services.todos.add(
                     &title,
                     Some(date),
-                    None,
-                    None,
+                    notes,
+                    workspace_id,
+                    project_id,
                     None,
                 ))?;
                 self.refresh_board()?;
             }
             AddTarget::BacklogColumn(col) => {
-                let model = self
-                    .runtime
-                    .block_on(self.services.todos.add(&title, None, None, None, None))?;
+                let model = self.runtime.block_on(self.services.todos.add(
+                    &title,
+                    None,
+                    notes,
+                    workspace_id,
+                    project_id,
+                    None,
+                ))?;
                 self.runtime
                     .block_on(self.services.todos.set_backlog_column(model.id, col as i64))?;
                 self.refresh_backlog()?;
@@ -409,54 +1484,453 @@ impl App {
         Ok(())
     }
 
-    pub fn open_detail_board(&mut self) {
-        let Some(id) = self.cursor.current_todo_id(&self.board) else {
-            return;
+    /// `/name rest` in the add prompt expands template `name`. Unknown names
+    /// are kept as a literal title.
+    fn expand_add_template(&self, input: String) -> miette::Result<(String, TodoTemplate)> {
+        let Some(rest) = input.strip_prefix('/') else {
+            return Ok((input, TodoTemplate::default()));
+        };
+
+        let (name, title) = rest.split_once(' ').unwrap_or((rest, ""));
+
+        let Some(template) = self
+            .runtime
+            .block_on(self.services.config.load_template(name))?
+        else {
+            return Ok((input, TodoTemplate::default()));
+        };
+
+        let title = template.expand_title(title);
+
+        if title.is_empty() {
+            miette::bail!("template '{}' needs a title", name);
+        }
+
+        Ok((title, template))
+    }
+
+    /// Workspace/project for new todos so they stay visible under the active
+    /// project filter.
+    fn filter_assignment(&self) -> miette::Result<(Option<Uuid>, Option<Uuid>)> {
+        let ProjectFilter::Equals(project_id) = self.project_filter else {
+            return Ok((None, None));
         };
-        self.open_detail(id, false);
+
+        let project = self
+            .runtime
+            .block_on(self.services.projects.get(project_id))?;
+
+        Ok((project.map(|p| p.workspace_id), Some(project_id)))
     }
 
-    pub fn open_detail_backlog(&mut self) {
-        let Some(id) = self.backlog_cursor.current_todo_id(&self.board) else {
+    /// Narrow the board to the project of `id` and return to it. Returns
+    /// `false` when the todo has no project.
+    pub fn focus_todo_project(&mut self, id: Uuid) -> bool {
+        let Ok(model) = self.runtime.block_on(self.services.todos.get(id)) else {
+            return false;
+        };
+
+        let Some(project_id) = model.project_id else {
+            return false;
+        };
+
+        self.ui_mode = UiMode::Board;
+        self.cursor.selection = None;
+
+        self.set_project_filter(ProjectFilter::Equals(project_id));
+
+        if let Some((col, row)) = self.board.find_day_position(id) {
+            self.cursor.set_focus_row(col, row);
+        }
+
+        true
+    }
+
+    /// Navigate to the week/day or backlog column holding `id` and open its
+    /// details.
+    pub fn jump_to_todo(&mut self, id: Uuid) -> miette::Result<()> {
+        let model = self.runtime.block_on(self.services.todos.get(id))?;
+
+        let Some(date) = model.scheduled_for else {
+            self.ui_mode = UiMode::Backlog;
+            self.backlog_cursor.selection = None;
+
+            if let Some((col, row)) = self.board.find_backlog_position(id) {
+                self.backlog_cursor.column = col;
+                self.backlog_cursor.rows[col] = row;
+            }
+
+            return self.open_detail(id, PrimaryView::Backlog);
+        };
+
+        self.state = WeekState::new(date, self.week_pref, self.view_span);
+        self.board.reset(self.state.columns.len());
+        self.cursor = CursorState::new(self.state.columns.len());
+
+        // Unfold the day so a done todo has a row to land on.
+        if self.is_day_collapsed(date) && model.status == "done" && !self.toggled_days.remove(&date)
+        {
+            self.toggled_days.insert(date);
+        }
+
+        self.try_action(|app| app.refresh_board());
+
+        if let Some((col, row)) = self.board.find_day_position(id) {
+            self.cursor.set_focus_row(col, row);
+        }
+
+        self.open_detail(id, PrimaryView::Board)
+    }
+
+    /// Apply a project filter to the board and backlog, resolving the names
+    /// shown in the header breadcrumb.
+    pub fn set_project_filter(&mut self, filter: ProjectFilter) {
+        self.project_filter = filter;
+
+        self.scope_labels = match filter {
+            ProjectFilter::Any => ScopeLabels::default(),
+            ProjectFilter::IsNull => ScopeLabels {
+                workspace: None,
+                project: Some("none".to_string()),
+            },
+            ProjectFilter::Equals(id) => {
+                let project = self
+                    .runtime
+                    .block_on(self.services.projects.get(id))
+                    .ok()
+                    .flatten();
+
+                let workspace = project.as_ref().and_then(|p| {
+                    self.runtime
+                        .block_on(self.services.workspaces.get(p.workspace_id))
+                        .ok()
+                        .flatten()
+                });
+
+                ScopeLabels {
+                    workspace: workspace.map(|w| w.name),
+                    project: project.map(|p| p.name),
+                }
+            }
+        };
+
+        self.try_action(|app| app.refresh_board());
+    }
+
+    /// `p`: list the projects that have todos so the board can be narrowed
+    /// to one, starting on the filter in effect.
+    pub fn open_project_picker(&mut self) {
+        let ids = match self
+            .runtime
+            .block_on(self.services.todos.distinct_projects())
+        {
+            Ok(ids) => ids,
+            Err(err) => {
+                self.report_error(format!("failed to load projects: {err}"));
+
+                return;
+            }
+        };
+
+        let Ok(names) = self.project_names() else {
             return;
         };
-        self.open_detail(id, true);
+
+        let mut projects: Vec<(ProjectFilter, String)> = ids
+            .into_iter()
+            .filter_map(|id| {
+                names
+                    .get(&id)
+                    .map(|name| (ProjectFilter::Equals(id), name.clone()))
+            })
+            .collect();
+
+        projects.sort_by_key(|(_, name)| name.to_lowercase());
+
+        let mut options = vec![
+            (ProjectFilter::Any, "All".to_string()),
+            (ProjectFilter::IsNull, "No project".to_string()),
+        ];
+
+        options.extend(projects);
+
+        let index = options
+            .iter()
+            .position(|(filter, _)| *filter == self.project_filter)
+            .unwrap_or(0);
+
+        self.ui_mode = UiMode::ProjectPicker(ProjectPickerState { options, index });
     }
 
-    pub fn open_detail(&mut self, id: Uuid, from_backlog: bool) {
-        let Ok(model) = self.runtime.block_on(self.services.todos.get(id)) else {
+    /// Filter the board to the focused todo's project, or clear an active
+    /// project filter.
+    pub fn toggle_project_filter(&mut self) {
+        if self.project_filter != ProjectFilter::Any {
+            self.set_project_filter(ProjectFilter::Any);
+
             return;
+        }
+
+        if let Some(id) = self.cursor.current_todo_id(&self.board) {
+            self.focus_todo_project(id);
+        }
+    }
+
+    /// `e`: rename the focused todo right on its row.
+    pub fn open_edit_title(&mut self, from_backlog: bool) -> miette::Result<()> {
+        let id = if from_backlog {
+            self.backlog_cursor.current_todo_id(&self.board)
+        } else {
+            self.cursor.current_todo_id(&self.board)
         };
 
+        let Some(id) = id else {
+            return Ok(());
+        };
+
+        let model = self.runtime.block_on(self.services.todos.get(id))?;
+
+        self.ui_mode = UiMode::EditTitle(EditTitleState {
+            id,
+            input: model.title,
+            from_backlog,
+        });
+
+        Ok(())
+    }
+
+    /// Save an inline title edit. Blank titles are ignored and keep the
+    /// editor open.
+    pub fn finish_edit_title(&mut self, save: bool) -> miette::Result<()> {
+        let UiMode::EditTitle(state) = &self.ui_mode else {
+            return Ok(());
+        };
+
+        let title = state.input.trim().to_string();
+        let (id, from_backlog) = (state.id, state.from_backlog);
+
+        if save && title.is_empty() {
+            return Ok(());
+        }
+
+        self.ui_mode = if from_backlog {
+            UiMode::Backlog
+        } else {
+            UiMode::Board
+        };
+
+        if save {
+            self.runtime
+                .block_on(self.services.todos.update_title(id, title))?;
+
+            self.refresh_board()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn open_detail_board(&mut self) -> miette::Result<()> {
+        let Some(id) = self.cursor.current_todo_id(&self.board) else {
+            return Ok(());
+        };
+        self.open_detail(id, PrimaryView::Board)
+    }
+
+    pub fn open_detail_backlog(&mut self) -> miette::Result<()> {
+        let Some(id) = self.backlog_cursor.current_todo_id(&self.board) else {
+            return Ok(());
+        };
+        self.open_detail(id, PrimaryView::Backlog)
+    }
+
+    pub fn open_detail_agenda(&mut self) -> miette::Result<()> {
+        let Some(id) = self.agenda_cursor.current_todo_id(&self.agenda) else {
+            return Ok(());
+        };
+        self.open_detail(id, PrimaryView::Agenda)
+    }
+
+    pub fn open_detail(&mut self, id: Uuid, return_to: PrimaryView) -> miette::Result<()> {
+        let model = self.runtime.block_on(self.services.todos.get(id))?;
+
         self.ui_mode = UiMode::Detail(DetailState {
             todo_id: model.id,
             title: model.title,
             date: model.scheduled_for,
             status: model.status,
+            url: model.url,
             notes: model.notes.unwrap_or_default(),
+            priority: model.priority,
             field: DetailField::Title,
             editing: None,
-            from_backlog,
+            return_to,
+            hint: None,
         });
+
+        Ok(())
     }
 
-    pub fn toggle_detail_status(&mut self) {
+    pub fn open_detail_link(&mut self) {
         let UiMode::Detail(ref mut state) = self.ui_mode else {
             return;
         };
 
-        let id = state.todo_id;
+        state.hint = match &state.url {
+            Some(url) => super::link::open(url)
+                .err()
+                .map(|err| format!("Couldn't open link: {err}")),
+            None => Some("This todo has no link".to_string()),
+        };
+    }
+
+    pub fn toggle_detail_status(&mut self) -> miette::Result<()> {
+        let UiMode::Detail(ref state) = self.ui_mode else {
+            return Ok(());
+        };
+
+        let (id, done) = (state.todo_id, state.status == "done");
         let today = self.services.today();
 
-        if state.status == "done" {
-            if let Ok(model) = self.runtime.block_on(self.services.todos.mark_pending(id)) {
-                state.status = model.status;
-            }
-        } else if let Ok(model) = self
+        self.remember(id)?;
+
+        let model = if done {
+            self.runtime
+                .block_on(self.services.todos.mark_pending(id, true))?
+        } else {
+            self.runtime
+                .block_on(self.services.todos.mark_done(id, today))?
+        };
+
+        if let UiMode::Detail(ref mut state) = self.ui_mode {
+            state.status = model.status;
+        }
+
+        Ok(())
+    }
+
+    /// Set the detail todo's priority; 0 clears it.
+    pub fn set_detail_priority(&mut self, priority: i32) {
+        let UiMode::Detail(ref mut state) = self.ui_mode else {
+            return;
+        };
+
+        match self
             .runtime
-            .block_on(self.services.todos.mark_done(id, today))
+            .block_on(self.services.todos.update_priority(state.todo_id, priority))
         {
-            state.status = model.status;
+            Ok(model) => state.priority = model.priority,
+            Err(err) => state.hint = Some(err.to_string()),
+        }
+    }
+
+    pub fn open_review(&mut self) {
+        let queue = self
+            .board
+            .backlog_columns
+            .iter()
+            .flatten()
+            .filter(|todo| todo.status != "done")
+            .map(|todo| todo.id)
+            .collect();
+
+        self.backlog_cursor.selection = None;
+        self.ui_mode = UiMode::Review(ReviewState::new(queue));
+
+        self.load_review_item();
+    }
+
+    /// Load the current review item, skipping todos that vanished meanwhile.
+    fn load_review_item(&mut self) {
+        let UiMode::Review(ref mut state) = self.ui_mode else {
+            return;
+        };
+
+        while let Some(id) = state.current() {
+            if let Ok(model) = self.runtime.block_on(self.services.todos.get(id)) {
+                state.title = model.title;
+                state.notes = model.notes.unwrap_or_default();
+
+                return;
+            }
+
+            state.index += 1;
+        }
+    }
+
+    pub fn review_keep(&mut self) {
+        if let UiMode::Review(ref mut state) = self.ui_mode {
+            state.kept += 1;
+            state.index += 1;
+        }
+
+        self.load_review_item();
+    }
+
+    pub fn review_schedule(&mut self, days_from_today: i64) -> miette::Result<()> {
+        let date = self.services.today() + ChronoDuration::days(days_from_today);
+
+        self.review_schedule_on(date)
+    }
+
+    pub fn review_schedule_on(&mut self, date: chrono::NaiveDate) -> miette::Result<()> {
+        let UiMode::Review(ref mut state) = self.ui_mode else {
+            return Ok(());
+        };
+
+        let Some(id) = state.current() else {
+            return Ok(());
+        };
+
+        self.runtime.block_on(self.services.todos.move_to_scope(
+            id,
+            ListScope::Day(date),
+            MovePlacement::Top,
+        ))?;
+
+        state.scheduled += 1;
+        state.index += 1;
+        state.date_input = None;
+        state.hint = None;
+
+        self.load_review_item();
+
+        Ok(())
+    }
+
+    /// `D`: archive the current todo like `dd` on the board, asking first
+    /// when `confirm_deletes` is on.
+    pub fn review_archive(&mut self) -> miette::Result<()> {
+        let UiMode::Review(ref mut state) = self.ui_mode else {
+            return Ok(());
+        };
+
+        let Some(id) = state.current() else {
+            return Ok(());
+        };
+
+        if self.confirm_deletes && !state.confirm_archive {
+            state.confirm_archive = true;
+
+            return Ok(());
+        }
+
+        state.confirm_archive = false;
+
+        self.archive_todo(id)?;
+
+        if let UiMode::Review(ref mut state) = self.ui_mode {
+            state.archived += 1;
+            state.index += 1;
         }
+
+        self.load_review_item();
+
+        Ok(())
+    }
+
+    pub fn finish_review(&mut self) {
+        self.ui_mode = UiMode::Backlog;
+
+        self.try_action(|app| app.refresh_board());
     }
 }
@@ -1,8 +1,10 @@
+use std::cell::Cell;
+
 use ratatui::style::{Modifier, Style};
 use uuid::Uuid;
 
-use super::palette;
-use super::state::{BACKLOG_COLUMNS, BoardData};
+use super::palette::Palette;
+use super::state::{AgendaData, BACKLOG_COLUMNS, BoardData};
 
 #[derive(Clone, Copy)]
 pub enum Horizontal {
@@ -33,6 +35,8 @@ pub struct BacklogSelection {
 pub struct CursorState {
     pub focus: usize,
     pub day_rows: Vec<usize>,
+    /// First visible row per day. Updated while drawing, hence the `Cell`.
+    pub scroll_offsets: Vec<Cell<usize>>,
     pub selection: Option<Selection>,
 }
 
@@ -41,10 +45,25 @@ impl CursorState {
         Self {
             focus: 0,
             day_rows: vec![0; num_days],
+            scroll_offsets: vec![Cell::new(0); num_days],
             selection: None,
         }
     }
 
+    /// Scroll day `col`, whose rows are `heights` lines tall, so its cursor
+    /// row is on screen in `lines` lines, and return the resulting offset.
+    pub fn ensure_visible(&self, col: usize, heights: &[usize], lines: usize) -> usize {
+        let Some(offset) = self.scroll_offsets.get(col) else {
+            return 0;
+        };
+
+        let row = self.day_rows.get(col).copied().unwrap_or(0);
+
+        offset.set(clamp_scroll_lines(offset.get(), row, heights, lines));
+
+        offset.get()
+    }
+
     pub fn move_vertical(&mut self, dir: Vertical, board: &BoardData) {
         let len = board.day_len(self.focus);
 
@@ -70,6 +89,24 @@ impl CursorState {
         self.selection = None;
     }
 
+    /// `gg`/`G`: jump to the first (`Up`) or last (`Down`) row.
+    pub fn move_to_edge(&mut self, dir: Vertical, board: &BoardData) {
+        let len = board.day_len(self.focus);
+
+        if len == 0 {
+            return;
+        }
+
+        let row = match dir {
+            Vertical::Up => 0,
+            Vertical::Down => len - 1,
+        };
+
+        self.day_rows[self.focus] = row;
+
+        self.selection = None;
+    }
+
     pub fn row_for(&self, col: usize, board: &BoardData) -> Option<usize> {
         let len = board.day_len(col);
 
@@ -80,13 +117,19 @@ impl CursorState {
         self.day_rows.get(col).copied().filter(|r| *r < len)
     }
 
-    pub fn line_style(&self, col: usize, row: usize, board: &BoardData) -> Style {
+    pub fn line_style(
+        &self,
+        col: usize,
+        row: usize,
+        board: &BoardData,
+        palette: &Palette,
+    ) -> Style {
         if let Some(selection) = self.selection
             && selection.column == col
             && selection.row == Some(row)
         {
             return Style::default()
-                .fg(palette::ACCENT)
+                .fg(palette.accent)
                 .add_modifier(Modifier::BOLD);
         }
 
@@ -94,10 +137,10 @@ impl CursorState {
             && let Some(current_row) = self.row_for(col, board)
             && current_row == row
         {
-            return Style::default().fg(palette::ACTIVE);
+            return Style::default().fg(palette.active);
         }
 
-        Style::default().fg(palette::TEXT)
+        Style::default().fg(palette.text)
     }
 
     pub fn is_selected(&self, id: Uuid) -> bool {
@@ -112,6 +155,7 @@ impl CursorState {
 
     pub fn sync_after_refresh(&mut self, day_count: usize, board: &BoardData) {
         self.day_rows.resize(day_count, 0);
+        self.scroll_offsets.resize(day_count, Cell::new(0));
 
         if self.focus >= day_count {
             self.focus = day_count.saturating_sub(1);
@@ -156,6 +200,8 @@ impl CursorState {
 pub struct BacklogCursor {
     pub column: usize,
     pub rows: [usize; BACKLOG_COLUMNS],
+    /// First visible row per column. Updated while drawing, hence the `Cell`.
+    pub scroll_offsets: [Cell<usize>; BACKLOG_COLUMNS],
     pub selection: Option<BacklogSelection>,
 }
 
@@ -164,10 +210,26 @@ impl BacklogCursor {
         Self {
             column: 0,
             rows: [0; BACKLOG_COLUMNS],
+            scroll_offsets: Default::default(),
             selection: None,
         }
     }
 
+    /// Scroll backlog column `col`, whose rows are `heights` lines tall, so
+    /// its cursor row is on screen in `lines` lines, and return the
+    /// resulting offset.
+    pub fn ensure_visible(&self, col: usize, heights: &[usize], lines: usize) -> usize {
+        let Some(offset) = self.scroll_offsets.get(col) else {
+            return 0;
+        };
+
+        let row = self.rows.get(col).copied().unwrap_or(0);
+
+        offset.set(clamp_scroll_lines(offset.get(), row, heights, lines));
+
+        offset.get()
+    }
+
     pub fn move_horizontal(&mut self, dir: Horizontal) {
         match dir {
             Horizontal::Left => {
@@ -210,6 +272,24 @@ impl BacklogCursor {
         self.selection = None;
     }
 
+    /// `gg`/`G`: jump to the first (`Up`) or last (`Down`) row.
+    pub fn move_to_edge(&mut self, dir: Vertical, board: &BoardData) {
+        let len = board.backlog_col_len(self.column);
+
+        if len == 0 {
+            return;
+        }
+
+        let row = match dir {
+            Vertical::Up => 0,
+            Vertical::Down => len - 1,
+        };
+
+        self.rows[self.column] = row;
+
+        self.selection = None;
+    }
+
     pub fn row_for(&self, col: usize, board: &BoardData) -> Option<usize> {
         let len = board.backlog_col_len(col);
 
@@ -222,13 +302,19 @@ impl BacklogCursor {
         if row < len { Some(row) } else { None }
     }
 
-    pub fn line_style(&self, col: usize, row: usize, board: &BoardData) -> Style {
+    pub fn line_style(
+        &self,
+        col: usize,
+        row: usize,
+        board: &BoardData,
+        palette: &Palette,
+    ) -> Style {
         if let Some(selection) = self.selection
             && selection.column == col
             && selection.row == Some(row)
         {
             return Style::default()
-                .fg(palette::ACCENT)
+                .fg(palette.accent)
                 .add_modifier(Modifier::BOLD);
         }
 
@@ -236,10 +322,10 @@ impl BacklogCursor {
             && let Some(current_row) = self.row_for(col, board)
             && current_row == row
         {
-            return Style::default().fg(palette::ACTIVE);
+            return Style::default().fg(palette.active);
         }
 
-        Style::default().fg(palette::TEXT)
+        Style::default().fg(palette.text)
     }
 
     pub fn is_selected(&self, id: Uuid) -> bool {
@@ -277,3 +363,115 @@ impl BacklogCursor {
         }
     }
 }
+
+/// Position in the agenda's flat list of todos.
+pub struct AgendaCursor {
+    pub row: usize,
+    /// First visible line, counting date headers. Updated while drawing,
+    /// hence the `Cell`.
+    pub scroll_offset: Cell<usize>,
+}
+
+impl AgendaCursor {
+    pub fn new() -> Self {
+        Self {
+            row: 0,
+            scroll_offset: Cell::new(0),
+        }
+    }
+
+    /// Scroll so `line` of `len` lines stays within `visible`, returning the
+    /// first line to draw.
+    pub fn ensure_visible(&self, line: usize, len: usize, visible: usize) -> usize {
+        self.scroll_offset
+            .set(clamp_scroll(self.scroll_offset.get(), line, len, visible));
+
+        self.scroll_offset.get()
+    }
+
+    pub fn move_vertical(&mut self, dir: Vertical, agenda: &AgendaData) {
+        match dir {
+            Vertical::Up => self.row = self.row.saturating_sub(1),
+            Vertical::Down => {
+                if self.row + 1 < agenda.items.len() {
+                    self.row += 1;
+                }
+            }
+        }
+    }
+
+    /// `gg`/`G`: jump to the first (`Up`) or last (`Down`) todo.
+    pub fn move_to_edge(&mut self, dir: Vertical, agenda: &AgendaData) {
+        self.row = match dir {
+            Vertical::Up => 0,
+            Vertical::Down => agenda.items.len().saturating_sub(1),
+        };
+    }
+
+    pub fn current_todo_id(&self, agenda: &AgendaData) -> Option<Uuid> {
+        agenda.items.get(self.row).map(|item| item.todo.id)
+    }
+
+    pub fn sync_after_refresh(&mut self, agenda: &AgendaData) {
+        self.row = self.row.min(agenda.items.len().saturating_sub(1));
+    }
+}
+
+/// Lines rows `from..=to` take up, counting the separator line between
+/// neighbouring rows.
+fn span_lines(heights: &[usize], from: usize, to: usize) -> usize {
+    heights[from..=to].iter().sum::<usize>() + (to - from)
+}
+
+/// How many rows from `offset` on fit in `lines` lines when each row is
+/// `heights` lines tall and rows are separated by one line. Always at least
+/// one, so a title taller than the column still shows its start.
+pub fn rows_fitting(heights: &[usize], offset: usize, lines: usize) -> usize {
+    let mut count = 0;
+
+    while offset + count < heights.len() && span_lines(heights, offset, offset + count) <= lines {
+        count += 1;
+    }
+
+    count.max(1).min(heights.len().saturating_sub(offset))
+}
+
+/// `clamp_scroll` for a column of rows `heights` lines tall (wrapped titles)
+/// shown in `lines` lines: the smallest change to `offset` that keeps `row`
+/// fully on screen, pulled back so no blank space is left below the last row.
+fn clamp_scroll_lines(offset: usize, row: usize, heights: &[usize], lines: usize) -> usize {
+    let Some(last) = heights.len().checked_sub(1) else {
+        return 0;
+    };
+
+    let row = row.min(last);
+    let mut offset = offset.min(row);
+
+    while offset < row && span_lines(heights, offset, row) > lines {
+        offset += 1;
+    }
+
+    while offset > 0 && span_lines(heights, offset - 1, last) <= lines {
+        offset -= 1;
+    }
+
+    offset
+}
+
+/// Smallest change to `offset` that keeps `row` within `visible` rows of a
+/// `len`-row column. Also pulls the offset back when the column or the
+/// terminal has shrunk so no blank space is left below the last row.
+fn clamp_scroll(offset: usize, row: usize, len: usize, visible: usize) -> usize {
+    let visible = visible.max(1);
+    let row = row.min(len.saturating_sub(1));
+
+    let offset = if row < offset {
+        row
+    } else if row >= offset + visible {
+        row + 1 - visible
+    } else {
+        offset
+    };
+
+    offset.min(len.saturating_sub(visible))
+}
@@ -2,23 +2,91 @@ use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
-    text::Line,
-    widgets::{Block, Borders, Clear, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 use uuid::Uuid;
 
-use crate::service::config::WeekStart;
+use crate::service::config::{ViewSpan, WeekStart};
 
 use super::App;
-use super::modes::{AddTodoState, DetailField, DetailState, SettingsState, UiMode};
-use super::palette;
-use super::state::{BACKLOG_COLUMNS, TodoView};
+use super::cursor::rows_fitting;
+use super::keymap::Action;
+use super::modes::{
+    AddTodoState, ConfirmState, DayFullState, DetailField, DetailState, PrimaryView,
+    ProjectPickerState, ReviewState, SettingsState, UiMode,
+};
+use super::mouse::{ColumnHit, HitColumn};
+use super::palette::Palette;
+use super::state::{AGENDA_DAYS, BACKLOG_COLUMNS, TodoView, day_title, priority_marker};
+
+/// Narrower columns keep the plain separator before the done group.
+const DONE_LABEL_MIN_WIDTH: usize = 12;
+
+/// A title being edited in place, scrolled so the cursor stays in view.
+fn edit_line(input: &str, width: u16, palette: &Palette) -> Line<'static> {
+    let room = (width as usize).saturating_sub(1);
+    let skip = input.chars().count().saturating_sub(room);
+
+    let text: String = input.chars().skip(skip).collect();
+
+    Line::from(format!("{text}_")).style(
+        Style::default()
+            .fg(palette.active)
+            .add_modifier(Modifier::UNDERLINED),
+    )
+}
+
+/// `base` followed by as many of `extras` as fit in `width` columns, in order.
+fn fit_title(base: &str, extras: &[String], width: u16) -> String {
+    let mut title = base.to_string();
+
+    for extra in extras {
+        if Span::raw(title.as_str()).width() + Span::raw(extra.as_str()).width() > width as usize {
+            break;
+        }
+
+        title.push_str(extra);
+    }
+
+    title
+}
+
+/// Dim `↑ N` in `top` and `↓ M` in `bottom`, right-aligned, for rows
+/// scrolled out of view above and below. Nothing is drawn for a zero count.
+fn draw_scroll_hints(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    top: Rect,
+    bottom: Rect,
+    above: usize,
+    below: usize,
+) {
+    let style = Style::default().fg(palette.text_dim);
+
+    if above > 0 {
+        frame.render_widget(
+            Paragraph::new(Line::from(format!(" ↑ {above}")).style(style)).right_aligned(),
+            top,
+        );
+    }
+
+    if below > 0 {
+        frame.render_widget(
+            Paragraph::new(Line::from(format!(" ↓ {below}")).style(style)).right_aligned(),
+            bottom,
+        );
+    }
+}
 
 impl App {
     pub fn draw(&mut self, frame: &mut Frame<'_>) {
+        self.hit_areas.get_mut().clear();
+
         match &self.ui_mode {
             UiMode::Board => self.draw_board(frame),
             UiMode::Backlog => self.draw_backlog_view(frame),
+            UiMode::Agenda => self.draw_agenda(frame),
             UiMode::Settings(settings) => {
                 self.draw_board(frame);
 
@@ -35,15 +103,53 @@ impl App {
                 self.draw_add_todo(frame, &state);
             }
             UiMode::Detail(state) => {
+                let state = state.clone();
+
+                self.draw_primary_view(frame, state.return_to);
+
+                self.draw_detail(frame, &state);
+            }
+            UiMode::Review(state) => {
+                let state = state.clone();
+
+                self.draw_review(frame, &state);
+            }
+            UiMode::Welcome => {
+                self.draw_board(frame);
+                self.draw_welcome(frame);
+            }
+            UiMode::DoneToday(titles) => {
+                let titles = titles.clone();
+
+                self.draw_board(frame);
+                self.draw_done_today(frame, &titles);
+            }
+            UiMode::Confirm(state) => {
+                let state = state.clone();
+
+                self.draw_primary_view(frame, state.return_to);
+
+                self.draw_confirm(frame, &state);
+            }
+            UiMode::EditTitle(state) => {
                 if state.from_backlog {
                     self.draw_backlog_view(frame);
                 } else {
                     self.draw_board(frame);
                 }
+            }
+            UiMode::ProjectPicker(state) => {
+                let state = state.clone();
 
+                self.draw_board(frame);
+                self.draw_project_picker(frame, &state);
+            }
+            UiMode::DayFull(state) => {
                 let state = state.clone();
 
-                self.draw_detail(frame, &state);
+                self.draw_primary_view(frame, state.return_to);
+
+                self.draw_day_full(frame, &state);
             }
         }
 
@@ -52,7 +158,37 @@ impl App {
         }
     }
 
+    /// Draw `view` as the backdrop for an overlay opened from it.
+    fn draw_primary_view(&self, frame: &mut Frame<'_>, view: PrimaryView) {
+        match view {
+            PrimaryView::Board => self.draw_board(frame),
+            PrimaryView::Backlog => self.draw_backlog_view(frame),
+            PrimaryView::Agenda => self.draw_agenda(frame),
+        }
+    }
+
     pub fn draw_board(&self, frame: &mut Frame<'_>) {
+        let area = self.draw_status_bar(frame);
+
+        let [header_area, board_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Fill(1)])
+            .areas(area);
+
+        frame.render_widget(Paragraph::new(self.breadcrumb_line()), header_area);
+
+        if let Some(capacity) = self.weekly_capacity {
+            frame.render_widget(
+                Paragraph::new(self.capacity_meter(capacity)).centered(),
+                header_area,
+            );
+        }
+
+        frame.render_widget(
+            Paragraph::new(self.view_indicator(PrimaryView::Board)).right_aligned(),
+            header_area,
+        );
+
         let day_count = self.state.columns.len();
         let mut constraints = Vec::with_capacity(day_count * 2 - 1);
 
@@ -67,7 +203,7 @@ impl App {
         let areas = Layout::default()
             .direction(Direction::Horizontal)
             .constraints(constraints)
-            .split(frame.area());
+            .split(board_area);
 
         let focused = self.cursor.focus;
         let mut col_idx = 0;
@@ -82,9 +218,9 @@ impl App {
                 let adjacent_to_focus = sep_idx == focused || sep_idx + 1 == focused;
 
                 let style = if adjacent_to_focus {
-                    Style::default().fg(palette::FOCUS)
+                    Style::default().fg(self.palette.focus)
                 } else {
-                    Style::default().fg(palette::BORDER)
+                    Style::default().fg(self.palette.border)
                 };
 
                 let lines: Vec<Line<'_>> = (0..area.height).map(|_| Line::from("│")).collect();
@@ -95,14 +231,229 @@ impl App {
         }
     }
 
+    /// Draw the status bar on the bottom row and return the area above it.
+    fn draw_status_bar(&self, frame: &mut Frame<'_>) -> Rect {
+        let [main_area, bar_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(1)])
+            .areas(frame.area());
+
+        frame.render_widget(Paragraph::new(self.status_line()), bar_area);
+
+        // The last failed action, drawn over the right end of the bar.
+        if let Some((message, _)) = &self.status_message {
+            let error = Paragraph::new(format!("{message} "))
+                .style(Style::default().fg(self.palette.error))
+                .right_aligned();
+
+            frame.render_widget(error, bar_area);
+        }
+
+        main_area
+    }
+
+    /// `Fri 2026-10-16 · Oct 12 – Oct 18 · Project: Home · done hidden`:
+    /// today, the days on the board, and any filter narrowing what's shown.
+    fn status_line(&self) -> Line<'static> {
+        let dim = Style::default().fg(self.palette.text_dim);
+        let separator = || Span::styled(" · ", dim);
+
+        let first = self.state.week_start;
+        let last = self
+            .state
+            .columns
+            .last()
+            .map_or(first, |column| column.date);
+
+        let mut spans = vec![
+            Span::styled(
+                format!(" {}", self.services.today().format("%a %Y-%m-%d")),
+                Style::default().fg(self.palette.focus),
+            ),
+            separator(),
+            Span::styled(
+                format!("{} – {}", first.format("%b %-d"), last.format("%b %-d")),
+                Style::default().fg(self.palette.text),
+            ),
+        ];
+
+        if let Some(project) = &self.scope_labels.project {
+            spans.push(separator());
+            spans.push(Span::styled(
+                format!("Project: {project}"),
+                Style::default().fg(self.palette.active),
+            ));
+        }
+
+        spans.push(separator());
+        spans.push(if self.show_done {
+            Span::styled("done shown", dim)
+        } else {
+            Span::styled("done hidden", Style::default().fg(self.palette.active))
+        });
+
+        if self.overdue > 0 {
+            let key = self
+                .keymap
+                .key_for(Action::Rollover)
+                .map(|key| format!(" ({key} moves to today)"))
+                .unwrap_or_default();
+
+            spans.push(separator());
+            spans.push(Span::styled(
+                format!("{} overdue{key}", self.overdue),
+                Style::default().fg(self.palette.overdue),
+            ));
+        }
+
+        Line::from(spans)
+    }
+
+    /// `[Workspace: …] [Project: …]` line describing which todos are shown.
+    fn breadcrumb_line(&self) -> Line<'static> {
+        let labels = &self.scope_labels;
+
+        let style = if labels.is_scoped() {
+            Style::default().fg(self.palette.active)
+        } else {
+            Style::default().fg(self.palette.text_dim)
+        };
+
+        let workspace = labels.workspace.as_deref().unwrap_or("all");
+        let project = labels.project.as_deref().unwrap_or("all");
+
+        let mut spans = vec![
+            Span::styled(format!("[Workspace: {workspace}]"), style),
+            Span::raw(" "),
+            Span::styled(format!("[Project: {project}]"), style),
+        ];
+
+        for label in self.pending_labels() {
+            spans.push(Span::raw(" "));
+            spans.push(label);
+        }
+
+        Line::from(spans)
+    }
+
+    /// `[3 selected]` while todos are picked with `V` and a dim
+    /// `[yanked: …]` while a `yy` waits for `p`.
+    fn pending_labels(&self) -> Vec<Span<'static>> {
+        const YANK_TITLE_WIDTH: usize = 24;
+
+        let mut labels = Vec::new();
+
+        if !self.selected.is_empty() {
+            labels.push(Span::styled(
+                format!("[{} selected]", self.selected.len()),
+                Style::default().fg(self.palette.accent),
+            ));
+        }
+
+        if let Some((_, title)) = &self.yanked {
+            let title = if title.chars().count() > YANK_TITLE_WIDTH {
+                let cut: String = title.chars().take(YANK_TITLE_WIDTH - 1).collect();
+
+                format!("{cut}…")
+            } else {
+                title.clone()
+            };
+
+            labels.push(Span::styled(
+                format!("[yanked: {title}]"),
+                Style::default().fg(self.palette.text_dim),
+            ));
+        }
+
+        labels
+    }
+
+    /// `Week 12/20 ██████░░░░` gauge of pending todos against the weekly
+    /// capacity; turns to the accent colour once the week is over capacity.
+    fn capacity_meter(&self, capacity: u32) -> Line<'static> {
+        const BAR_WIDTH: usize = 10;
+
+        let pending = self.week_pending_count();
+        let capacity = capacity.max(1) as usize;
+        let filled = (pending * BAR_WIDTH).div_ceil(capacity).min(BAR_WIDTH);
+
+        let bar_style = if pending > capacity {
+            Style::default().fg(self.palette.accent)
+        } else {
+            Style::default().fg(self.palette.active)
+        };
+
+        Line::from(vec![
+            Span::styled(
+                format!("Week {pending}/{capacity} "),
+                Style::default().fg(self.palette.text_dim),
+            ),
+            Span::styled("█".repeat(filled), bar_style),
+            Span::styled(
+                "░".repeat(BAR_WIDTH - filled),
+                Style::default().fg(self.palette.border),
+            ),
+        ])
+    }
+
+    /// `Board · Backlog  [Tab]` with the active view highlighted.
+    fn view_indicator(&self, current: PrimaryView) -> Line<'static> {
+        let mut spans = Vec::new();
+
+        for (i, view) in PrimaryView::ALL.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(
+                    " · ",
+                    Style::default().fg(self.palette.text_dim),
+                ));
+            }
+
+            let style = if view == current {
+                Style::default()
+                    .fg(self.palette.active)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.palette.text_dim)
+            };
+
+            spans.push(Span::styled(view.label(), style));
+        }
+
+        spans.push(Span::styled(
+            "  [Tab] ",
+            Style::default().fg(self.palette.text_dim),
+        ));
+
+        Line::from(spans)
+    }
+
     pub fn draw_backlog_view(&self, frame: &mut Frame<'_>) {
-        let outer = Block::default()
+        let mut outer = Block::default()
             .title("Someday / Backlog")
+            .title(self.view_indicator(PrimaryView::Backlog).right_aligned())
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(palette::FOCUS));
+            .border_style(Style::default().fg(self.palette.focus));
 
-        let inner = outer.inner(frame.area());
-        frame.render_widget(outer, frame.area());
+        let labels = self.pending_labels();
+
+        if !labels.is_empty() {
+            let mut spans = Vec::with_capacity(labels.len() * 2);
+
+            for label in labels {
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+
+                spans.push(label);
+            }
+
+            outer = outer.title(Line::from(spans).centered());
+        }
+
+        let area = self.draw_status_bar(frame);
+
+        let inner = outer.inner(area);
+        frame.render_widget(outer, area);
 
         let mut constraints = Vec::with_capacity(BACKLOG_COLUMNS * 2 - 1);
 
@@ -132,9 +483,9 @@ impl App {
                 let adjacent_to_focus = sep_idx == focused || sep_idx + 1 == focused;
 
                 let style = if adjacent_to_focus {
-                    Style::default().fg(palette::FOCUS)
+                    Style::default().fg(self.palette.focus)
                 } else {
-                    Style::default().fg(palette::BORDER)
+                    Style::default().fg(self.palette.border)
                 };
 
                 let lines: Vec<Line<'_>> = (0..area.height).map(|_| Line::from("│")).collect();
@@ -145,6 +496,79 @@ impl App {
         }
     }
 
+    /// The next `AGENDA_DAYS` days as one list, each day's todos under a
+    /// header naming it.
+    pub fn draw_agenda(&self, frame: &mut Frame<'_>) {
+        let outer = Block::default()
+            .title("Agenda")
+            .title(self.view_indicator(PrimaryView::Agenda).right_aligned())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let inner = outer.inner(frame.area());
+        frame.render_widget(outer, frame.area());
+
+        if self.agenda.items.is_empty() {
+            let empty =
+                Paragraph::new(format!("Nothing scheduled in the next {AGENDA_DAYS} days."))
+                    .style(Style::default().fg(self.palette.text_dim))
+                    .centered();
+
+            frame.render_widget(empty, inner);
+
+            return;
+        }
+
+        let today = self.services.today();
+        let header_style = Style::default()
+            .fg(self.palette.focus)
+            .add_modifier(Modifier::BOLD);
+
+        let mut lines = Vec::with_capacity(self.agenda.items.len() * 2);
+        let mut cursor_line = 0;
+        let mut current_date = None;
+
+        for (row, item) in self.agenda.items.iter().enumerate() {
+            if current_date != Some(item.date) {
+                if current_date.is_some() {
+                    lines.push(Line::from(""));
+                }
+
+                let mut title = day_title(item.date);
+
+                if item.date == today {
+                    title.push_str(" · today");
+                }
+
+                lines.push(Line::from(title).style(header_style));
+
+                current_date = Some(item.date);
+            }
+
+            let focused = row == self.agenda_cursor.row;
+
+            if focused {
+                cursor_line = lines.len();
+            }
+
+            let mut line = item
+                .todo
+                .to_line_with_prefix(focused, inner.width, &self.palette);
+
+            if focused {
+                line.style = line.style.patch(Style::default().fg(self.palette.active));
+            }
+
+            lines.push(line);
+        }
+
+        let offset =
+            self.agenda_cursor
+                .ensure_visible(cursor_line, lines.len(), inner.height as usize);
+
+        frame.render_widget(Paragraph::new(lines).scroll((offset as u16, 0)), inner);
+    }
+
     fn draw_backlog_column(&self, frame: &mut Frame<'_>, col_idx: usize, area: Rect) {
         let focused = self.backlog_cursor.column == col_idx;
         let items = &self.board.backlog_columns[col_idx];
@@ -155,17 +579,102 @@ impl App {
             None
         };
 
+        let title_style = if focused {
+            Style::default()
+                .fg(self.palette.focus)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(self.palette.text)
+        };
+
+        let label = self
+            .services
+            .backlog_labels()
+            .get(col_idx)
+            .map_or("", String::as_str);
+
+        let title_line = Line::from(fit_title(label, &[], area.width)).style(title_style);
+        let underline_line = Line::from("─".repeat(area.width as usize)).style(title_style);
+
+        let mut content_area = Rect {
+            y: area.y + 2,
+            height: area.height.saturating_sub(2),
+            ..area
+        };
+
+        let heights =
+            self.todo_heights(items, area.width, |id| self.backlog_cursor.is_selected(id));
+
+        // As on the board: a column that doesn't fit gives up its last line to
+        // the `↓` hint, and the `↑` hint sits on the underline.
+        let overflows = rows_fitting(&heights, 0, content_area.height as usize) < items.len();
+
+        if overflows {
+            content_area.height = content_area.height.saturating_sub(1);
+        }
+
+        let offset =
+            self.backlog_cursor
+                .ensure_visible(col_idx, &heights, content_area.height as usize);
+
+        let visible = rows_fitting(&heights, offset, content_area.height as usize);
+
+        self.hit_areas.borrow_mut().push(ColumnHit {
+            column: HitColumn::Backlog(col_idx),
+            area,
+            content: content_area,
+            offset,
+            heights: heights.clone(),
+        });
+
         let lines = self.build_todo_lines_with_separators(
-            items,
+            &items[offset.min(items.len())..],
             area.width,
-            highlight_row,
-            |row| self.backlog_cursor.line_style(col_idx, row, &self.board),
+            false,
+            highlight_row.and_then(|row| row.checked_sub(offset)),
+            |row| {
+                self.backlog_cursor
+                    .line_style(col_idx, row + offset, &self.board, &self.palette)
+            },
             |id| self.backlog_cursor.is_selected(id),
         );
 
+        frame.render_widget(
+            Paragraph::new(title_line).centered(),
+            Rect { height: 1, ..area },
+        );
+
+        frame.render_widget(
+            Paragraph::new(underline_line),
+            Rect {
+                y: area.y + 1,
+                height: 1,
+                ..area
+            },
+        );
+
         let para = Paragraph::new(lines);
 
-        frame.render_widget(para, area);
+        frame.render_widget(para, content_area);
+
+        if overflows {
+            draw_scroll_hints(
+                frame,
+                &self.palette,
+                Rect {
+                    y: area.y + 1,
+                    height: 1,
+                    ..area
+                },
+                Rect {
+                    y: content_area.bottom(),
+                    height: 1,
+                    ..area
+                },
+                offset,
+                items.len().saturating_sub(offset + visible),
+            );
+        }
     }
 
     fn draw_day_column(&self, frame: &mut Frame<'_>, idx: usize, area: Rect) {
@@ -174,17 +683,16 @@ impl App {
 
         let title_style = if focused {
             Style::default()
-                .fg(palette::FOCUS)
+                .fg(self.palette.focus)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(palette::TEXT)
+            Style::default().fg(self.palette.text)
         };
 
-        let title_line = Line::from(column.title.clone()).style(title_style);
         let underline = "─".repeat(area.width as usize);
         let underline_line = Line::from(underline).style(title_style);
 
-        let content_area = Rect {
+        let mut content_area = Rect {
             x: area.x,
             y: area.y + 2,
             width: area.width,
@@ -198,20 +706,94 @@ impl App {
             .map(|d| d.as_slice())
             .unwrap_or(&[]);
 
+        // The fold summary, when there is one, is a one-line row after the
+        // todos.
+        let mut heights = self.todo_heights(items, area.width, |id| self.cursor.is_selected(id));
+
+        if self.board.summary_row(idx).is_some() {
+            heights.push(1);
+        }
+
+        // A day that doesn't fit gives up its last line to the `↓` hint; the
+        // `↑` hint sits on the underline. One that fits keeps every line.
+        let len = self.board.day_len(idx);
+        let overflows = rows_fitting(&heights, 0, content_area.height as usize) < len;
+
+        if overflows {
+            content_area.height = content_area.height.saturating_sub(1);
+        }
+
+        // `Mon 06/10 · 2/5 (3/12)`: done of total, then the cursor row when
+        // scrolling. Extras that don't fit are dropped from the end.
+        let mut extras = Vec::new();
+
+        if let Some((done, total)) = self.board.day_counts(idx) {
+            extras.push(format!(" · {done}/{total}"));
+        }
+
+        if overflows {
+            let row = self.cursor.row_for(idx, &self.board).unwrap_or(0);
+
+            extras.push(format!(" ({}/{len})", row + 1));
+        }
+
+        let title_line =
+            Line::from(fit_title(&column.title, &extras, area.width)).style(title_style);
+
         let highlight_row = if focused {
             self.cursor.row_for(idx, &self.board)
         } else {
             None
         };
 
-        let lines = self.build_todo_lines_with_separators(
-            items,
+        let offset = self
+            .cursor
+            .ensure_visible(idx, &heights, content_area.height as usize);
+
+        let visible = rows_fitting(&heights, offset, content_area.height as usize);
+
+        self.hit_areas.borrow_mut().push(ColumnHit {
+            column: HitColumn::Day(idx),
+            area,
+            content: content_area,
+            offset,
+            heights: heights.clone(),
+        });
+
+        let mut lines = self.build_todo_lines_with_separators(
+            &items[offset.min(items.len())..],
             area.width,
-            highlight_row,
-            |row| self.cursor.line_style(idx, row, &self.board),
+            column.date < self.services.today(),
+            highlight_row.and_then(|row| row.checked_sub(offset)),
+            |row| {
+                self.cursor
+                    .line_style(idx, row + offset, &self.board, &self.palette)
+            },
             |id| self.cursor.is_selected(id),
         );
 
+        if let Some(row) = self.board.summary_row(idx) {
+            if row > offset {
+                let sep_style = if highlight_row == Some(row - 1) || highlight_row == Some(row) {
+                    Style::default().fg(self.palette.active)
+                } else {
+                    Style::default().fg(self.palette.border)
+                };
+
+                lines.push(Line::from("-".repeat(area.width as usize)).style(sep_style));
+            }
+
+            let label = format!("✓ {} done (expand)", self.board.hidden_done_in(idx));
+
+            let style = if highlight_row == Some(row) {
+                self.cursor.line_style(idx, row, &self.board, &self.palette)
+            } else {
+                Style::default().fg(self.palette.text_dim)
+            };
+
+            lines.push(Line::from(label).style(style));
+        }
+
         frame.render_widget(
             Paragraph::new(title_line).centered(),
             Rect { height: 1, ..area },
@@ -229,12 +811,32 @@ impl App {
         let body = Paragraph::new(lines);
 
         frame.render_widget(body, content_area);
+
+        if overflows {
+            draw_scroll_hints(
+                frame,
+                &self.palette,
+                Rect {
+                    y: area.y + 1,
+                    height: 1,
+                    ..area
+                },
+                Rect {
+                    y: content_area.bottom(),
+                    height: 1,
+                    ..area
+                },
+                offset,
+                len.saturating_sub(offset + visible),
+            );
+        }
     }
 
     fn build_todo_lines_with_separators<'a, F, S>(
         &self,
         items: &'a [TodoView],
         width: u16,
+        overdue: bool,
         highlight_row: Option<usize>,
         style_fn: F,
         is_selected_fn: S,
@@ -245,6 +847,13 @@ impl App {
     {
         let separator = "-".repeat(width as usize);
 
+        // Where the done group starts; its separator doubles as a label so the
+        // boundary takes no extra row and the cursor never lands on it.
+        let done_start = items
+            .iter()
+            .position(|item| item.status == "done")
+            .filter(|&idx| idx > 0 && width as usize >= DONE_LABEL_MIN_WIDTH);
+
         let mut lines = Vec::with_capacity(items.len() * 2);
 
         for (i, item) in items.iter().enumerate() {
@@ -252,50 +861,115 @@ impl App {
                 let adjacent_to_focus = highlight_row == Some(i - 1) || highlight_row == Some(i);
 
                 let sep_style = if adjacent_to_focus {
-                    Style::default().fg(palette::ACTIVE)
+                    Style::default().fg(self.palette.active)
                 } else {
-                    Style::default().fg(palette::BORDER)
+                    Style::default().fg(self.palette.border)
                 };
 
-                lines.push(Line::from(separator.clone()).style(sep_style));
+                if done_start == Some(i) {
+                    lines.push(
+                        Line::from(done_label(width)).style(sep_style.add_modifier(Modifier::DIM)),
+                    );
+                } else {
+                    lines.push(Line::from(separator.clone()).style(sep_style));
+                }
+            }
+
+            if let UiMode::EditTitle(state) = &self.ui_mode
+                && state.id == item.id
+            {
+                lines.push(edit_line(&state.input, width, &self.palette));
+
+                continue;
             }
 
             let is_selected = is_selected_fn(item.id);
 
-            let mut line = item.to_line_with_prefix(is_selected);
+            // Every wrapped line of a todo carries its highlight.
+            for mut line in item.to_lines(is_selected, width, self.wrap_titles, &self.palette) {
+                if is_selected {
+                    line.style = line.style.patch(self.selected_style());
+                } else if highlight_row == Some(i) {
+                    line.style = line.style.patch(style_fn(i));
+                } else if overdue && item.status != "done" {
+                    line.style = line.style.fg(self.palette.overdue);
+                }
+
+                if self.swap_mark == Some(item.id) {
+                    line.style = line.style.add_modifier(Modifier::UNDERLINED);
+                }
 
-            if is_selected {
-                line.style = line.style.patch(
-                    Style::default()
-                        .fg(palette::ACCENT)
-                        .add_modifier(Modifier::BOLD),
-                );
-            } else if highlight_row == Some(i) {
-                line.style = line.style.patch(style_fn(i));
+                if self.selected.contains(&item.id) {
+                    line.style = line.style.add_modifier(Modifier::REVERSED);
+                }
+
+                lines.push(line);
             }
 
-            lines.push(line);
+            // The preview belongs to the todo's row but is never highlighted.
+            if self.show_note_preview
+                && let Some(preview) = item.note_preview(width, &self.palette)
+            {
+                lines.push(preview);
+            }
         }
 
         lines
     }
 
+    /// Lines each todo in `items` takes in a `width`-wide column, matching
+    /// what `build_todo_lines_with_separators` draws.
+    fn todo_heights<S>(&self, items: &[TodoView], width: u16, is_selected_fn: S) -> Vec<usize>
+    where
+        S: Fn(Uuid) -> bool,
+    {
+        items
+            .iter()
+            .map(|item| {
+                let title = match &self.ui_mode {
+                    UiMode::EditTitle(state) if state.id == item.id => return 1,
+                    _ if !self.wrap_titles => 1,
+                    _ => item
+                        .to_lines(is_selected_fn(item.id), width, true, &self.palette)
+                        .len(),
+                };
+
+                let preview =
+                    self.show_note_preview && item.note_preview(width, &self.palette).is_some();
+
+                title + usize::from(preview)
+            })
+            .collect()
+    }
+
+    /// Drag-mode highlight; drops the bold on alternate ticks so the moving
+    /// todo pulses unless the user turned that off in settings.
+    fn selected_style(&self) -> Style {
+        let style = Style::default().fg(self.palette.accent);
+
+        if self.selection_pulse && self.tick % 2 == 1 {
+            style.remove_modifier(Modifier::BOLD)
+        } else {
+            style.add_modifier(Modifier::BOLD)
+        }
+    }
+
     pub fn draw_settings(&self, frame: &mut Frame<'_>, settings: &SettingsState) {
-        let area = centered_rect(30, 18, frame.area());
+        let area = centered_rect(30, 80, frame.area());
 
         let block = Block::default()
             .title("Settings")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(palette::FOCUS));
+            .border_style(Style::default().fg(self.palette.focus));
 
         let (monday_style, sunday_style) = match settings.week_start {
             WeekStart::Monday => (
-                Style::default().fg(palette::ACTIVE),
-                Style::default().fg(palette::TEXT_DIM),
+                Style::default().fg(self.palette.active),
+                Style::default().fg(self.palette.text_dim),
             ),
             WeekStart::Sunday => (
-                Style::default().fg(palette::TEXT_DIM),
-                Style::default().fg(palette::ACTIVE),
+                Style::default().fg(self.palette.text_dim),
+                Style::default().fg(self.palette.active),
             ),
         };
 
@@ -311,7 +985,136 @@ impl App {
                 ratatui::text::Span::styled("Sunday", sunday_style),
             ]),
             Line::from(""),
-            Line::from("[Esc] close").style(Style::default().fg(palette::TEXT_DIM)),
+            Line::from("Days Shown"),
+            Line::from(""),
+            Line::from(vec![
+                "[v] ".into(),
+                ratatui::text::Span::styled(
+                    match settings.view_span {
+                        ViewSpan::Week => "Full week".to_string(),
+                        ViewSpan::Weekdays => "Mon-Fri".to_string(),
+                        ViewSpan::Days(days) => format!("{days} days from today"),
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Restore Last Week"),
+            Line::from(""),
+            Line::from(vec![
+                "[r] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.restore_session {
+                        "On"
+                    } else {
+                        "Off"
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Selection Pulse"),
+            Line::from(""),
+            Line::from(vec![
+                "[p] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.selection_pulse {
+                        "On"
+                    } else {
+                        "Off"
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Collapse Done"),
+            Line::from(""),
+            Line::from(vec![
+                "[c] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.collapse_done { "On" } else { "Off" },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Note Previews"),
+            Line::from(""),
+            Line::from(vec![
+                "[n] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.show_note_preview {
+                        "On"
+                    } else {
+                        "Off"
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Wrap Long Titles"),
+            Line::from(""),
+            Line::from(vec![
+                "[t] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.wrap_titles { "On" } else { "Off" },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Send Done To Backlog"),
+            Line::from(""),
+            Line::from(vec![
+                "[a] ".into(),
+                ratatui::text::Span::styled(
+                    match settings.done_backlog_column {
+                        Some(col) => format!("Column {}", col + 1),
+                        None => "Off (s ignores done)".to_string(),
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Weekly Capacity"),
+            Line::from(""),
+            Line::from(vec![
+                "[w] ".into(),
+                ratatui::text::Span::styled(
+                    match settings.weekly_capacity {
+                        Some(capacity) => format!("{capacity} todos  [+/-]"),
+                        None => "Off".to_string(),
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Confirm Archives"),
+            Line::from(""),
+            Line::from(vec![
+                "[x] ".into(),
+                ratatui::text::Span::styled(
+                    if settings.confirm_deletes {
+                        "On"
+                    } else {
+                        "Off"
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("Daily Capacity"),
+            Line::from(""),
+            Line::from(vec![
+                "[d] ".into(),
+                ratatui::text::Span::styled(
+                    match settings.daily_capacity {
+                        Some(capacity) => format!("{capacity} todos  [</>]"),
+                        None => "Off".to_string(),
+                    },
+                    Style::default().fg(self.palette.active),
+                ),
+            ]),
+            Line::from(""),
+            Line::from("[Esc] close").style(Style::default().fg(self.palette.text_dim)),
         ];
 
         let paragraph = Paragraph::new(lines).block(block);
@@ -321,23 +1124,190 @@ impl App {
         frame.render_widget(paragraph, area);
     }
 
+    pub fn draw_done_today(&self, frame: &mut Frame<'_>, titles: &[String]) {
+        let area = centered_rect(40, 50, frame.area());
+
+        let block = Block::default()
+            .title(format!("Done Today ({})", titles.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let dim = Style::default().fg(self.palette.text_dim);
+
+        let mut lines: Vec<Line<'_>> = if titles.is_empty() {
+            vec![Line::from("Nothing completed yet today").style(dim)]
+        } else {
+            titles
+                .iter()
+                .map(|title| Line::from(format!("✓ {title}")))
+                .collect()
+        };
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("[any key] close").style(dim));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    pub fn draw_confirm(&self, frame: &mut Frame<'_>, state: &ConfirmState) {
+        let area = centered_rect(40, 20, frame.area());
+
+        let block = Block::default()
+            .title("Confirm")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.accent));
+
+        let lines = vec![
+            Line::from(state.prompt.clone()),
+            Line::from(""),
+            Line::from("[y] yes  [n] no").style(Style::default().fg(self.palette.text_dim)),
+        ];
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+            area,
+        );
+    }
+
+    pub fn draw_project_picker(&self, frame: &mut Frame<'_>, state: &ProjectPickerState) {
+        let area = centered_rect(30, 50, frame.area());
+
+        let block = Block::default()
+            .title("Filter by Project")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let mut lines: Vec<Line<'_>> = state
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, (filter, label))| {
+                let marker = if *filter == self.project_filter {
+                    "• "
+                } else {
+                    "  "
+                };
+
+                let style = if i == state.index {
+                    Style::default()
+                        .fg(self.palette.active)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(self.palette.text)
+                };
+
+                Line::from(format!("{marker}{label}")).style(style)
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from("[Enter] apply  [Esc] cancel")
+                .style(Style::default().fg(self.palette.text_dim)),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    pub fn draw_day_full(&self, frame: &mut Frame<'_>, state: &DayFullState) {
+        let area = centered_rect(40, 30, frame.area());
+
+        let block = Block::default()
+            .title("Day Is Full")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let dim = Style::default().fg(self.palette.text_dim);
+
+        let mut lines = vec![
+            Line::from(format!(
+                "{} already has {} pending todos.",
+                state.full_day.format("%a %b %-d"),
+                state.pending
+            )),
+            Line::from(""),
+        ];
+
+        match state.suggestion {
+            Some(day) => {
+                lines.push(Line::from(vec![
+                    "Next day with room: ".into(),
+                    ratatui::text::Span::styled(
+                        day.format("%a %b %-d").to_string(),
+                        Style::default().fg(self.palette.active),
+                    ),
+                ]));
+                lines.push(Line::from(""));
+                lines.push(Line::from("[y] move it there  [n] keep this day").style(dim));
+            }
+            None => {
+                lines.push(Line::from("No later day this week has room."));
+                lines.push(Line::from(""));
+                lines.push(Line::from("[n] keep this day").style(dim));
+            }
+        }
+
+        lines.push(Line::from("[Esc] cancel").style(dim));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    pub fn draw_welcome(&self, frame: &mut Frame<'_>) {
+        let area = centered_rect(50, 50, frame.area());
+
+        let block = Block::default()
+            .title("Welcome to mach")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let heading = Style::default().fg(self.palette.active);
+        let dim = Style::default().fg(self.palette.text_dim);
+
+        let lines = vec![
+            Line::from("The board").style(heading),
+            Line::from("One column per day of the week. Unfinished"),
+            Line::from("todos roll over to today automatically."),
+            Line::from(""),
+            Line::from("The backlog").style(heading),
+            Line::from("Press b for someday items that have no date."),
+            Line::from(""),
+            Line::from("Getting around").style(heading),
+            Line::from("h/j/k/l move, a adds, x completes, Space opens"),
+            Line::from("details, Enter picks a todo up to drag it."),
+            Line::from("Press ? any time for the full list."),
+            Line::from(""),
+            Line::from("[s] add sample todos  [Enter] start empty").style(dim),
+        ];
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
     pub fn draw_add_todo(&self, frame: &mut Frame<'_>, state: &AddTodoState) {
         let area = centered_rect(35, 15, frame.area());
 
         let block = Block::default()
             .title("Add Todo")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(palette::FOCUS));
+            .border_style(Style::default().fg(self.palette.focus));
 
         let inner = block.inner(area);
 
         frame.render_widget(Clear, area);
         frame.render_widget(block, area);
 
+        let footer = Line::from("[Enter] add  [Esc] cancel  @fri sets the day")
+            .style(Style::default().fg(self.palette.text_dim));
+
         let lines = vec![
-            Line::from(format!("› {}_", state.input)).style(Style::default().fg(palette::ACTIVE)),
+            Line::from(format!("› {}_", state.input))
+                .style(Style::default().fg(self.palette.active)),
             Line::from(""),
-            Line::from("[Enter] add  [Esc] cancel").style(Style::default().fg(palette::TEXT_DIM)),
+            footer,
         ];
 
         frame.render_widget(Paragraph::new(lines), inner);
@@ -349,7 +1319,7 @@ impl App {
         let block = Block::default()
             .title("Todo")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(palette::FOCUS));
+            .border_style(Style::default().fg(self.palette.focus));
 
         let inner = block.inner(area);
 
@@ -360,6 +1330,7 @@ impl App {
             DetailField::Title,
             DetailField::Date,
             DetailField::Status,
+            DetailField::Url,
             DetailField::Notes,
         ];
 
@@ -378,9 +1349,9 @@ impl App {
             };
 
             let style = if is_focused {
-                Style::default().fg(palette::ACTIVE)
+                Style::default().fg(self.palette.active)
             } else {
-                Style::default().fg(palette::TEXT)
+                Style::default().fg(self.palette.text)
             };
 
             if field == DetailField::Notes {
@@ -399,7 +1370,7 @@ impl App {
                     }
                 } else if value.is_empty() {
                     lines.push(
-                        Line::from("    (empty)").style(Style::default().fg(palette::TEXT_DIM)),
+                        Line::from("    (empty)").style(Style::default().fg(self.palette.text_dim)),
                     );
                 } else {
                     for line in value.lines() {
@@ -416,13 +1387,29 @@ impl App {
 
         lines.push(Line::from(""));
 
+        let priority = match state.priority {
+            0 => Span::styled("none", Style::default().fg(self.palette.text_dim)),
+            level => priority_marker(level, &self.palette),
+        };
+
+        lines.push(Line::from(vec![Span::raw("  Priority: "), priority]));
+
+        lines.push(Line::from(""));
+
+        if let Some(hint) = &state.hint {
+            lines.push(Line::from(hint.clone()).style(Style::default().fg(self.palette.active)));
+
+            lines.push(Line::from(""));
+        }
+
         lines.push(
-            Line::from("[j/k] navigate  [Enter] edit/confirm  [x] toggle  [Esc] close")
-                .style(Style::default().fg(palette::TEXT_DIM)),
+            Line::from("[j/k] navigate  [Enter] edit/confirm  [x] toggle  [1-3/0] priority")
+                .style(Style::default().fg(self.palette.text_dim)),
         );
 
         lines.push(
-            Line::from("[Ctrl+j] newline in notes").style(Style::default().fg(palette::TEXT_DIM)),
+            Line::from("[Ctrl+j] newline in notes  [o] open link  [P] show project  [Esc] close")
+                .style(Style::default().fg(self.palette.text_dim)),
         );
 
         let paragraph = Paragraph::new(lines);
@@ -430,42 +1417,198 @@ impl App {
         frame.render_widget(paragraph, inner);
     }
 
+    pub fn draw_review(&self, frame: &mut Frame<'_>, state: &ReviewState) {
+        let total = state.queue.len();
+        let position = (state.index + 1).min(total);
+
+        let block = Block::default()
+            .title(format!("Review Backlog ({position}/{total})"))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.focus));
+
+        let inner = block.inner(frame.area());
+
+        frame.render_widget(block, frame.area());
+
+        let dim = Style::default().fg(self.palette.text_dim);
+        let mut lines: Vec<Line<'_>> = Vec::new();
+
+        if state.is_finished() {
+            lines.push(
+                Line::from("Review complete").style(Style::default().fg(self.palette.active)),
+            );
+            lines.push(Line::from(""));
+            lines.push(Line::from(format!(
+                "{} kept  {} scheduled  {} archived",
+                state.kept, state.scheduled, state.archived
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from("[any key] back to backlog").style(dim));
+        } else {
+            lines.push(
+                Line::from(state.title.clone()).style(
+                    Style::default()
+                        .fg(self.palette.active)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            );
+            lines.push(Line::from(""));
+
+            if state.notes.is_empty() {
+                lines.push(Line::from("(no notes)").style(dim));
+            } else {
+                for line in state.notes.lines() {
+                    lines.push(Line::from(line.to_string()));
+                }
+            }
+
+            lines.push(Line::from(""));
+
+            if let Some(input) = &state.date_input {
+                lines.push(
+                    Line::from(format!("Date (YYYY-MM-DD): {input}_"))
+                        .style(Style::default().fg(self.palette.active)),
+                );
+
+                if let Some(hint) = &state.hint {
+                    lines.push(
+                        Line::from(hint.clone()).style(Style::default().fg(self.palette.active)),
+                    );
+                }
+
+                lines.push(Line::from("[Enter] schedule  [Esc] cancel").style(dim));
+            } else if state.confirm_archive {
+                lines.push(
+                    Line::from(format!("Archive '{}'? [y] yes  [n] no", state.title))
+                        .style(Style::default().fg(self.palette.active)),
+                );
+            } else {
+                lines.push(
+                    Line::from(
+                        "[n] keep  [t] today  [T] tomorrow  [p] pick date  [D] archive  [Esc] stop",
+                    )
+                    .style(dim),
+                );
+            }
+        }
+
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
     pub fn draw_help(&self, frame: &mut Frame<'_>) {
         let lines = match &self.ui_mode {
             UiMode::Board => vec![
-                Line::from("Weekly View").style(Style::default().fg(palette::ACTIVE)),
+                Line::from("Weekly View").style(Style::default().fg(self.palette.active)),
                 Line::from(""),
                 Line::from("h/l      Move between days"),
                 Line::from("j/k      Move within column"),
                 Line::from("[/]      Previous/next week"),
+                Line::from("         (drag: move a week)"),
+                Line::from("3j, 2]   Repeat with a count"),
+                Line::from("gg/G     Top/bottom of day"),
                 Line::from("Enter    Select (drag mode)"),
+                Line::from("V        Pick for batch x/dd/s/t"),
+                Line::from("yy/p     Yank, paste on focused day"),
+                Line::from("c        Duplicate todo"),
+                Line::from("m/M      Mark/swap with mark"),
                 Line::from("Space    Open todo details"),
+                Line::from("e        Edit title inline"),
                 Line::from("a        Add new todo"),
                 Line::from("x        Toggle completion"),
-                Line::from("dd       Delete todo"),
+                Line::from("dd       Archive todo"),
+                Line::from("u        Undo last change"),
                 Line::from("s        Send to backlog"),
                 Line::from("t        Move to today"),
                 Line::from("T        Move to tomorrow"),
                 Line::from("b        Open backlog"),
+                Line::from("v        Open agenda"),
+                Line::from("Tab      Next view"),
+                Line::from("z        Fold done todos"),
+                Line::from("H        Hide/show done todos"),
+                Line::from("R        Overdue todos to today"),
+                Line::from("P        Pick project filter"),
+                Line::from("gf       Filter by project"),
+                Line::from("gx       Done today"),
+                Line::from("gt       Jump to today"),
                 Line::from("gs       Settings"),
                 Line::from("?        Toggle help"),
-                Line::from("q/Esc    Quit"),
+                Line::from("Esc      Back out, then quit"),
+                Line::from("q        Quit"),
+            ],
+            UiMode::Agenda => vec![
+                Line::from("Agenda View").style(Style::default().fg(self.palette.active)),
+                Line::from(""),
+                Line::from("j/k      Move through list"),
+                Line::from("gg/G     Top/bottom of list"),
+                Line::from("Space    Open todo details"),
+                Line::from("x        Toggle completion"),
+                Line::from("dd       Archive todo"),
+                Line::from("yy       Yank (paste on board)"),
+                Line::from("u        Undo last change"),
+                Line::from("H        Hide/show done todos"),
+                Line::from("Tab      Next view"),
+                Line::from("?        Toggle help"),
+                Line::from("v/q/Esc  Return to weekly"),
             ],
             UiMode::Backlog => vec![
-                Line::from("Backlog View").style(Style::default().fg(palette::ACTIVE)),
+                Line::from("Backlog View").style(Style::default().fg(self.palette.active)),
                 Line::from(""),
                 Line::from("h/l      Move between columns"),
+                Line::from("</>      Move whole column"),
                 Line::from("j/k      Move within column"),
+                Line::from("gg/G     Top/bottom of column"),
                 Line::from("Enter    Select (drag mode)"),
+                Line::from("V        Pick for batch x/dd/t"),
+                Line::from("yy/p     Yank, paste in column"),
+                Line::from("c        Duplicate todo"),
+                Line::from("m/M      Mark/swap with mark"),
                 Line::from("Space    Open todo details"),
+                Line::from("e        Edit title inline"),
                 Line::from("a        Add new todo"),
                 Line::from("x        Toggle completion"),
-                Line::from("dd       Delete todo"),
+                Line::from("dd       Archive todo"),
+                Line::from("u        Undo last change"),
                 Line::from("t        Move to today"),
                 Line::from("T        Move to tomorrow"),
+                Line::from("A        Move column to today"),
+                Line::from("H        Hide/show done todos"),
+                Line::from("gr       Review one by one"),
+                Line::from("Tab      Next view"),
                 Line::from("?        Toggle help"),
                 Line::from("b/q/Esc  Return to weekly"),
             ],
+            UiMode::AddTodo(_) => vec![
+                Line::from("Add Todo").style(Style::default().fg(self.palette.active)),
+                Line::from(""),
+                Line::from("Enter    Add todo"),
+                Line::from("@fri     Schedule on a day"),
+                Line::from("Esc      Cancel"),
+                Line::from("F1       Toggle help"),
+            ],
+            UiMode::Detail(_) => vec![
+                Line::from("Todo Details").style(Style::default().fg(self.palette.active)),
+                Line::from(""),
+                Line::from("j/k      Move between fields"),
+                Line::from("Enter    Edit field / save"),
+                Line::from("Ctrl+j   New line in notes"),
+                Line::from("x        Toggle completion"),
+                Line::from("0-3      Set priority"),
+                Line::from("o        Open link"),
+                Line::from("P        Filter by its project"),
+                Line::from("?        Toggle help"),
+                Line::from("         (F1 while editing)"),
+                Line::from("q/Esc    Close"),
+            ],
+            UiMode::Settings(_) => vec![
+                Line::from("Settings").style(Style::default().fg(self.palette.active)),
+                Line::from(""),
+                Line::from("m/s      Week starts Mon/Sun"),
+                Line::from("[key]    Toggle that setting"),
+                Line::from("+/-      Weekly capacity"),
+                Line::from("</>      Daily capacity"),
+                Line::from("?        Toggle help"),
+                Line::from("q/Esc    Close"),
+            ],
             _ => vec![],
         };
 
@@ -483,7 +1626,7 @@ impl App {
         let block = Block::default()
             .title("Help (?)")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(palette::FOCUS));
+            .border_style(Style::default().fg(self.palette.focus));
 
         let paragraph = Paragraph::new(lines).block(block);
 
@@ -513,3 +1656,12 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
 
     horizontal[1]
 }
+
+/// `--- done ---` padded with dashes to fill `width`.
+fn done_label(width: u16) -> String {
+    let label = " done ";
+    let dashes = (width as usize).saturating_sub(label.len());
+    let left = dashes / 2;
+
+    format!("{}{label}{}", "-".repeat(left), "-".repeat(dashes - left))
+}
@@ -1,26 +1,37 @@
-use chrono::NaiveDate;
+use chrono::Datelike;
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
-use crate::service::config::WeekStart;
-use crate::service::todo::ReorderDirection;
+use crate::service::config::{
+    DEFAULT_DAILY_CAPACITY, DEFAULT_WEEKLY_CAPACITY, ViewSpan, WeekStart,
+};
+use crate::service::dates::parse_date_input;
+use crate::service::todo::{ProjectFilter, ReorderDirection};
 
 use super::App;
 use super::cursor::{BacklogSelection, Horizontal, Selection, Vertical};
-use super::modes::{AddTarget, DetailField, UiMode};
-use super::state::BACKLOG_COLUMNS;
+use super::keymap::Action;
+use super::modes::{AddTarget, DetailField, PrimaryView, UiMode};
+use super::state::{BACKLOG_COLUMNS, WeekState};
+
+/// Largest count prefix kept; more digits are ignored.
+const MAX_COUNT: usize = 999;
 
 impl App {
     pub fn handle_event(&mut self, evt: Event) {
-        if let Event::Key(key) = evt
-            && key.kind == KeyEventKind::Press
-        {
-            self.handle_key_event(key);
+        match evt {
+            Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key_event(key),
+            Event::Mouse(mouse) => self.handle_mouse_event(mouse),
+            _ => {}
         }
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) {
-        if key.code == KeyCode::Char('?') {
-            if matches!(self.ui_mode, UiMode::Board | UiMode::Backlog) {
+        // `?` is typed while entering text; F1 works there too.
+        let help_key = key.code == KeyCode::F(1)
+            || (key.code == KeyCode::Char('?') && !self.ui_mode.captures_text());
+
+        if help_key {
+            if self.ui_mode.has_help() {
                 self.show_help = !self.show_help;
             }
 
@@ -44,6 +55,11 @@ impl App {
 
                 return;
             }
+            UiMode::Agenda => {
+                self.handle_agenda_key(key);
+
+                return;
+            }
             UiMode::AddTodo(_) => {
                 self.handle_add_todo_key(key);
 
@@ -54,9 +70,47 @@ impl App {
 
                 return;
             }
+            UiMode::Review(_) => {
+                self.handle_review_key(key);
+
+                return;
+            }
+            UiMode::Welcome => {
+                self.handle_welcome_key(key);
+
+                return;
+            }
+            UiMode::DoneToday(_) => {
+                self.ui_mode = UiMode::Board;
+
+                return;
+            }
+            UiMode::DayFull(_) => {
+                self.handle_day_full_key(key);
+
+                return;
+            }
+            UiMode::ProjectPicker(_) => {
+                self.handle_project_picker_key(key);
+
+                return;
+            }
+            UiMode::EditTitle(_) => {
+                self.handle_edit_title_key(key);
+
+                return;
+            }
+            UiMode::Confirm(_) => {
+                self.handle_confirm_key(key);
+
+                return;
+            }
             UiMode::Board => {}
         }
 
+        // Any key drops a half-typed `g`, `dd` or `yy`; Esc does only that.
+        let had_pending = self.pending_g || self.pending_delete || self.pending_yank;
+
         if self.pending_g {
             self.pending_g = false;
             if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('s')) {
@@ -64,100 +118,609 @@ impl App {
 
                 return;
             }
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('f')) {
+                self.toggle_project_filter();
+
+                return;
+            }
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('x')) {
+                self.try_action(|app| app.open_done_today());
+
+                return;
+            }
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('t')) {
+                self.jump_to_today();
+
+                return;
+            }
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('g')) {
+                self.cursor.move_to_edge(Vertical::Up, &self.board);
+
+                return;
+            }
         }
 
-        if !matches!(key.code, KeyCode::Char('d')) {
+        let action = self.keymap.action(&key);
+
+        if action != Some(Action::Archive) {
             self.pending_delete = false;
         }
 
+        if action != Some(Action::Yank) {
+            self.pending_yank = false;
+        }
+
+        // Vim-style count: `5j`, `2]`. A leading `0` isn't a count.
+        if key.modifiers.is_empty()
+            && let KeyCode::Char(c @ '0'..='9') = key.code
+            && (c != '0' || self.pending_count.is_some())
+        {
+            let digit = c as usize - '0' as usize;
+            let count = self.pending_count.unwrap_or(0) * 10 + digit;
+
+            self.pending_count = Some(count.min(MAX_COUNT));
+
+            return;
+        }
+
+        let count = self.pending_count.take();
+
+        if count.is_some() && key.code == KeyCode::Esc {
+            return;
+        }
+
+        let count = count.unwrap_or(1);
+
         match key.code {
-            KeyCode::Char('q') if key.modifiers.is_empty() => self.should_quit = true,
-            KeyCode::Esc => self.should_quit = true,
+            KeyCode::Esc if had_pending => {}
+            KeyCode::Esc => self.escape_board(),
             KeyCode::Char('g') if key.modifiers.is_empty() => {
                 self.pending_g = true;
             }
-            KeyCode::Char('a') if key.modifiers.is_empty() => {
-                self.open_add_todo_board();
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true
             }
-            KeyCode::Char('b') if key.modifiers.is_empty() => {
-                self.open_backlog();
+            _ => {
+                if let Some(action) = action {
+                    self.handle_board_action(action, count);
+                }
             }
-            KeyCode::Char('h') => self.handle_horizontal(Horizontal::Left),
-            KeyCode::Char('l') => self.handle_horizontal(Horizontal::Right),
-            KeyCode::Char('j') => self.handle_vertical(Vertical::Down),
-            KeyCode::Char('k') => self.handle_vertical(Vertical::Up),
-            KeyCode::Char('[') => self.change_week(-1),
-            KeyCode::Char(']') => self.change_week(1),
-            KeyCode::Char('x') if key.modifiers.is_empty() => {
-                self.mark_complete().ok();
+        }
+    }
+
+    /// Esc on the board undoes one layer of transient state at a time: a
+    /// drag, then picks, the yank, the swap mark and the project filter.
+    /// With none left it quits; `q` always does.
+    fn escape_board(&mut self) {
+        if self.cursor.selection.is_some() {
+            self.cursor.selection = None;
+        } else if !self.selected.is_empty() {
+            self.selected.clear();
+        } else if self.yanked.is_some() {
+            self.yanked = None;
+        } else if self.swap_mark.is_some() {
+            self.swap_mark = None;
+        } else if self.project_filter != ProjectFilter::Any {
+            self.set_project_filter(ProjectFilter::Any);
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    fn handle_board_action(&mut self, action: Action, count: usize) {
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::AddTodo => self.open_add_todo_board(),
+            Action::Backlog => self.open_backlog(),
+            Action::Agenda => self.open_agenda(),
+            Action::Paste => {
+                self.try_action(|app| app.paste_on_day());
             }
-            KeyCode::Char('s') if key.modifiers.is_empty() => {
-                self.move_to_backlog().ok();
+            Action::ProjectPicker => self.open_project_picker(),
+            Action::EditTitle => {
+                self.try_action(|app| app.open_edit_title(false));
             }
-            KeyCode::Char('t') if key.modifiers.is_empty() => {
-                self.move_to_today().ok();
+            Action::NextView => self.cycle_primary_view(true),
+            Action::PrevView => self.cycle_primary_view(false),
+            Action::MoveLeft => {
+                for _ in 0..count {
+                    self.handle_horizontal(Horizontal::Left);
+                }
+            }
+            Action::MoveRight => {
+                for _ in 0..count {
+                    self.handle_horizontal(Horizontal::Right);
+                }
             }
-            KeyCode::Char('T') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-                self.move_to_tomorrow().ok();
+            Action::MoveDown => {
+                // Rows past the end are no-ops, so don't repeat beyond them.
+                for _ in 0..count.min(self.board.day_len(self.cursor.focus)) {
+                    self.handle_vertical(Vertical::Down);
+                }
+            }
+            Action::MoveUp => {
+                for _ in 0..count.min(self.board.day_len(self.cursor.focus)) {
+                    self.handle_vertical(Vertical::Up);
+                }
             }
-            KeyCode::Char(' ') if key.modifiers.is_empty() => {
-                self.open_detail_board();
+            Action::LastRow => self.cursor.move_to_edge(Vertical::Down, &self.board),
+            Action::PrevWeek if self.cursor.selection.is_some() => {
+                self.try_action(|app| app.move_selected_week(-(count as i32)));
             }
-            KeyCode::Enter => self.toggle_selection(),
-            KeyCode::Char('d') if key.modifiers.is_empty() => {
-                if self.pending_delete {
-                    self.delete_current().ok();
+            Action::NextWeek if self.cursor.selection.is_some() => {
+                self.try_action(|app| app.move_selected_week(count as i32));
+            }
+            Action::PrevWeek => self.change_week(-(count as i32)),
+            Action::NextWeek => self.change_week(count as i32),
+            Action::Pick => self.toggle_selected(self.cursor.current_todo_id(&self.board)),
+            Action::Duplicate => {
+                self.try_action(|app| app.duplicate_todo(app.cursor.current_todo_id(&app.board)));
+            }
+            Action::Yank => {
+                if self.pending_yank {
+                    self.try_action(|app| app.yank(app.cursor.current_todo_id(&app.board)));
+                }
+
+                self.pending_yank = !self.pending_yank;
+            }
+            Action::ToggleDone if !self.selected.is_empty() => {
+                self.try_action(|app| app.toggle_complete_selected());
+            }
+            Action::ToggleDone => {
+                self.try_action(|app| app.mark_complete());
+            }
+            Action::Undo => {
+                self.try_action(|app| app.undo_last());
+            }
+            Action::SendToBacklog if !self.selected.is_empty() => {
+                self.try_action(|app| app.move_selected_to_backlog());
+            }
+            Action::SendToBacklog => {
+                self.try_action(|app| app.move_to_backlog());
+            }
+            Action::MoveToToday if !self.selected.is_empty() => {
+                self.try_action(|app| app.move_selected_to_today());
+            }
+            Action::MoveToToday => {
+                self.try_action(|app| app.move_to_today());
+            }
+            Action::MoveToTomorrow => {
+                self.try_action(|app| app.move_to_tomorrow());
+            }
+            Action::Details => {
+                self.try_action(|app| app.open_detail_board());
+            }
+            Action::Drag => self.toggle_selection(),
+            Action::FoldDay => self.toggle_day_collapse(),
+            Action::ToggleShowDone => self.toggle_show_done(),
+            Action::Mark => self.toggle_swap_mark(self.current_target_id()),
+            Action::SwapWithMark => {
+                self.try_action(|app| app.swap_with_mark(app.current_target_id()));
+            }
+            Action::Archive => {
+                if self.pending_delete && !self.selected.is_empty() {
+                    self.try_action(|app| app.request_archive_selected(PrimaryView::Board));
+                    self.pending_delete = false;
+                } else if self.pending_delete {
+                    self.try_action(|app| app.archive_current());
                     self.pending_delete = false;
                 } else {
                     self.pending_delete = true;
                 }
             }
-            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.should_quit = true
+            Action::Rollover => {
+                self.try_action(|app| app.rollover_overdue());
             }
-            _ => {}
+            Action::LaneToToday | Action::LaneLeft | Action::LaneRight => {}
         }
     }
 
     pub fn handle_backlog_key(&mut self, key: KeyEvent) {
-        if !matches!(key.code, KeyCode::Char('d')) {
+        if self.pending_g {
+            self.pending_g = false;
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('r')) {
+                self.open_review();
+
+                return;
+            }
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('g')) {
+                self.backlog_cursor.move_to_edge(Vertical::Up, &self.board);
+
+                return;
+            }
+        }
+
+        let action = self.keymap.action(&key);
+
+        if action != Some(Action::Archive) {
             self.pending_delete = false;
         }
 
+        if action != Some(Action::Yank) {
+            self.pending_yank = false;
+        }
+
         match key.code {
-            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('b') => {
+            KeyCode::Char('g') if key.modifiers.is_empty() => {
+                self.pending_g = true;
+            }
+            KeyCode::Esc if !self.selected.is_empty() => self.selected.clear(),
+            KeyCode::Esc if self.yanked.is_some() => self.yanked = None,
+            KeyCode::Esc => self.ui_mode = UiMode::Board,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {
+                if let Some(action) = action {
+                    self.handle_backlog_action(action);
+                }
+            }
+        }
+    }
+
+    fn handle_backlog_action(&mut self, action: Action) {
+        match action {
+            Action::Quit | Action::Backlog => {
                 self.ui_mode = UiMode::Board;
             }
-            KeyCode::Char('h') => self.handle_backlog_horizontal(Horizontal::Left),
-            KeyCode::Char('l') => self.handle_backlog_horizontal(Horizontal::Right),
-            KeyCode::Char('j') => self.handle_backlog_vertical(Vertical::Down),
-            KeyCode::Char('k') => self.handle_backlog_vertical(Vertical::Up),
-            KeyCode::Enter => self.toggle_backlog_selection(),
-            KeyCode::Char('x') if key.modifiers.is_empty() => {
-                self.mark_backlog_complete().ok();
+            Action::NextView => self.cycle_primary_view(true),
+            Action::PrevView => self.cycle_primary_view(false),
+            Action::MoveLeft => self.handle_backlog_horizontal(Horizontal::Left),
+            Action::MoveRight => self.handle_backlog_horizontal(Horizontal::Right),
+            Action::LaneLeft => {
+                self.try_action(|app| app.move_backlog_lane(Horizontal::Left));
             }
-            KeyCode::Char('a') if key.modifiers.is_empty() => {
-                self.open_add_todo_backlog();
+            Action::LaneRight => {
+                self.try_action(|app| app.move_backlog_lane(Horizontal::Right));
             }
-            KeyCode::Char('t') if key.modifiers.is_empty() => {
-                self.move_backlog_to_day(0).ok();
+            Action::MoveDown => self.handle_backlog_vertical(Vertical::Down),
+            Action::MoveUp => self.handle_backlog_vertical(Vertical::Up),
+            Action::LastRow => {
+                self.backlog_cursor
+                    .move_to_edge(Vertical::Down, &self.board);
+            }
+            Action::Drag => self.toggle_backlog_selection(),
+            Action::Mark => {
+                self.toggle_swap_mark(self.backlog_current_target_id());
             }
-            KeyCode::Char('T') | KeyCode::Char('t')
-                if key.modifiers.contains(KeyModifiers::SHIFT) =>
-            {
-                self.move_backlog_to_day(1).ok();
+            Action::SwapWithMark => {
+                self.try_action(|app| app.swap_with_mark(app.backlog_current_target_id()));
             }
-            KeyCode::Char('d') if key.modifiers.is_empty() => {
+            Action::Pick => {
+                self.toggle_selected(self.backlog_cursor.current_todo_id(&self.board));
+            }
+            Action::Yank => {
+                if self.pending_yank {
+                    self.try_action(|app| app.yank(app.backlog_cursor.current_todo_id(&app.board)));
+                }
+
+                self.pending_yank = !self.pending_yank;
+            }
+            Action::Paste => {
+                self.try_action(|app| app.paste_in_backlog());
+            }
+            Action::Duplicate => {
+                self.try_action(|app| {
+                    app.duplicate_todo(app.backlog_cursor.current_todo_id(&app.board))
+                });
+            }
+            Action::ToggleDone if !self.selected.is_empty() => {
+                self.try_action(|app| app.toggle_complete_selected());
+            }
+            Action::ToggleDone => {
+                self.try_action(|app| app.mark_backlog_complete());
+            }
+            Action::Undo => {
+                self.try_action(|app| app.undo_last());
+            }
+            Action::AddTodo => self.open_add_todo_backlog(),
+            Action::EditTitle => {
+                self.try_action(|app| app.open_edit_title(true));
+            }
+            Action::ToggleShowDone => self.toggle_show_done(),
+            Action::LaneToToday => self.request_move_lane_to_today(),
+            Action::MoveToToday if !self.selected.is_empty() => {
+                self.try_action(|app| app.move_selected_to_today());
+            }
+            Action::MoveToToday => {
+                self.try_action(|app| app.move_backlog_to_day(0));
+            }
+            Action::MoveToTomorrow => {
+                self.try_action(|app| app.move_backlog_to_day(1));
+            }
+            Action::Archive => {
+                if self.pending_delete && !self.selected.is_empty() {
+                    self.try_action(|app| app.request_archive_selected(PrimaryView::Backlog));
+
+                    self.pending_delete = false;
+                } else if self.pending_delete {
+                    self.try_action(|app| app.archive_backlog_current());
+
+                    self.pending_delete = false;
+                } else {
+                    self.pending_delete = true;
+                }
+            }
+            Action::Details => {
+                self.try_action(|app| app.open_detail_backlog());
+            }
+            Action::PrevWeek
+            | Action::NextWeek
+            | Action::SendToBacklog
+            | Action::FoldDay
+            | Action::Rollover
+            | Action::ProjectPicker
+            | Action::Agenda => {}
+        }
+    }
+
+    pub fn handle_agenda_key(&mut self, key: KeyEvent) {
+        if self.pending_g {
+            self.pending_g = false;
+
+            if key.modifiers.is_empty() && matches!(key.code, KeyCode::Char('g')) {
+                self.agenda_cursor.move_to_edge(Vertical::Up, &self.agenda);
+
+                return;
+            }
+        }
+
+        let action = self.keymap.action(&key);
+
+        if action != Some(Action::Archive) {
+            self.pending_delete = false;
+        }
+
+        if action != Some(Action::Yank) {
+            self.pending_yank = false;
+        }
+
+        match key.code {
+            KeyCode::Char('g') if key.modifiers.is_empty() => {
+                self.pending_g = true;
+            }
+            KeyCode::Esc => self.ui_mode = UiMode::Board,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {
+                if let Some(action) = action {
+                    self.handle_agenda_action(action);
+                }
+            }
+        }
+    }
+
+    fn handle_agenda_action(&mut self, action: Action) {
+        match action {
+            Action::Quit | Action::Agenda => {
+                self.ui_mode = UiMode::Board;
+            }
+            Action::NextView => self.cycle_primary_view(true),
+            Action::PrevView => self.cycle_primary_view(false),
+            Action::MoveDown => {
+                self.agenda_cursor
+                    .move_vertical(Vertical::Down, &self.agenda);
+            }
+            Action::MoveUp => {
+                self.agenda_cursor.move_vertical(Vertical::Up, &self.agenda);
+            }
+            Action::LastRow => {
+                self.agenda_cursor
+                    .move_to_edge(Vertical::Down, &self.agenda);
+            }
+            Action::ToggleDone => {
+                self.try_action(|app| app.mark_agenda_complete());
+            }
+            Action::ToggleShowDone => self.toggle_show_done(),
+            Action::Undo => {
+                self.try_action(|app| app.undo_last());
+            }
+            Action::Yank => {
+                if self.pending_yank {
+                    self.try_action(|app| app.yank(app.agenda_cursor.current_todo_id(&app.agenda)));
+                }
+
+                self.pending_yank = !self.pending_yank;
+            }
+            Action::Archive => {
                 if self.pending_delete {
-                    self.delete_backlog_current().ok();
+                    self.try_action(|app| app.archive_agenda_current());
 
                     self.pending_delete = false;
                 } else {
                     self.pending_delete = true;
                 }
             }
-            KeyCode::Char(' ') if key.modifiers.is_empty() => {
-                self.open_detail_backlog();
+            Action::Details => {
+                self.try_action(|app| app.open_detail_agenda());
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_review_key(&mut self, key: KeyEvent) {
+        let UiMode::Review(ref mut state) = self.ui_mode else {
+            return;
+        };
+
+        if state.is_finished() {
+            self.finish_review();
+
+            return;
+        }
+
+        if let Some(ref mut input) = state.date_input {
+            match key.code {
+                KeyCode::Esc => {
+                    state.date_input = None;
+                    state.hint = None;
+                }
+                KeyCode::Enter => {
+                    match parse_date_input(input, self.services.today(), self.week_pref) {
+                        Some(date) => {
+                            self.try_action(|app| app.review_schedule_on(date));
+                        }
+                        None => {
+                            state.hint = Some(format!(
+                                "Can't read '{}' as a date (try tomorrow, fri, +3 or 2026-06-13)",
+                                input.trim()
+                            ));
+                        }
+                    }
+                }
+                KeyCode::Char(c) => {
+                    input.push(c);
+                    state.hint = None;
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                    state.hint = None;
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        if state.confirm_archive {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    self.try_action(|app| app.review_archive());
+                }
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.should_quit = true;
+                }
+                _ => state.confirm_archive = false,
+            }
+
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('n') | KeyCode::Char('j') | KeyCode::Enter => self.review_keep(),
+            KeyCode::Char('t') if key.modifiers.is_empty() => {
+                self.try_action(|app| app.review_schedule(0));
+            }
+            KeyCode::Char('T') => {
+                self.try_action(|app| app.review_schedule(1));
+            }
+            KeyCode::Char('p') => {
+                state.date_input = Some(String::new());
+            }
+            KeyCode::Char('D') => {
+                self.try_action(|app| app.review_archive());
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.finish_review(),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_welcome_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('s') if key.modifiers.is_empty() => self.finish_welcome(true),
+            KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => self.finish_welcome(false),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_confirm_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                self.try_action(|app| app.resolve_confirm(true));
+            }
+            KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('q') => {
+                self.try_action(|app| app.resolve_confirm(false));
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_edit_title_key(&mut self, key: KeyEvent) {
+        let UiMode::EditTitle(ref mut state) = self.ui_mode else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.try_action(|app| app.finish_edit_title(false));
+            }
+            KeyCode::Enter => {
+                self.try_action(|app| app.finish_edit_title(true));
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            KeyCode::Char(c) => {
+                state.input.push(c);
+            }
+            KeyCode::Backspace => {
+                state.input.pop();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_project_picker_key(&mut self, key: KeyEvent) {
+        let UiMode::ProjectPicker(ref mut state) = self.ui_mode else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down if state.index + 1 < state.options.len() => {
+                state.index += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                state.index = state.index.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                let filter = state.options[state.index].0;
+
+                self.ui_mode = UiMode::Board;
+
+                self.set_project_filter(filter);
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.ui_mode = UiMode::Board;
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_quit = true;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn handle_day_full_key(&mut self, key: KeyEvent) {
+        let UiMode::DayFull(ref state) = self.ui_mode else {
+            return;
+        };
+
+        let (full_day, suggestion) = (state.full_day, state.suggestion);
+
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter if suggestion.is_some() => {
+                self.try_action(|app| app.resolve_day_full(suggestion));
+            }
+            KeyCode::Char('n') => {
+                self.try_action(|app| app.resolve_day_full(Some(full_day)));
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.try_action(|app| app.resolve_day_full(None));
             }
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.should_quit = true;
@@ -169,6 +732,16 @@ impl App {
     pub fn handle_settings_key(&mut self, key: KeyEvent) {
         if let UiMode::Settings(settings) = &mut self.ui_mode {
             let mut apply: Option<WeekStart> = None;
+            let mut pulse: Option<bool> = None;
+            let mut collapse: Option<bool> = None;
+            let mut wrap: Option<bool> = None;
+            let mut preview: Option<bool> = None;
+            let mut restore: Option<bool> = None;
+            let mut capacity: Option<Option<u32>> = None;
+            let mut daily: Option<Option<u32>> = None;
+            let mut done_lane: Option<Option<usize>> = None;
+            let mut confirm: Option<bool> = None;
+            let mut span: Option<ViewSpan> = None;
             let mut close = false;
 
             match key.code {
@@ -191,6 +764,98 @@ impl App {
                         apply = Some(target);
                     }
                 }
+                KeyCode::Char('v') => {
+                    settings.view_span = settings.view_span.cycle();
+
+                    span = Some(settings.view_span);
+                }
+                KeyCode::Char('p') => {
+                    settings.selection_pulse = !settings.selection_pulse;
+
+                    pulse = Some(settings.selection_pulse);
+                }
+                KeyCode::Char('c') => {
+                    settings.collapse_done = !settings.collapse_done;
+
+                    collapse = Some(settings.collapse_done);
+                }
+                KeyCode::Char('n') => {
+                    settings.show_note_preview = !settings.show_note_preview;
+
+                    preview = Some(settings.show_note_preview);
+                }
+                KeyCode::Char('r') => {
+                    settings.restore_session = !settings.restore_session;
+
+                    restore = Some(settings.restore_session);
+                }
+                KeyCode::Char('t') => {
+                    settings.wrap_titles = !settings.wrap_titles;
+
+                    wrap = Some(settings.wrap_titles);
+                }
+                KeyCode::Char('x') => {
+                    settings.confirm_deletes = !settings.confirm_deletes;
+
+                    confirm = Some(settings.confirm_deletes);
+                }
+                KeyCode::Char('a') => {
+                    settings.done_backlog_column = match settings.done_backlog_column {
+                        None => Some(0),
+                        Some(col) if col + 1 < BACKLOG_COLUMNS => Some(col + 1),
+                        Some(_) => None,
+                    };
+
+                    done_lane = Some(settings.done_backlog_column);
+                }
+                KeyCode::Char('w') => {
+                    settings.weekly_capacity = match settings.weekly_capacity {
+                        Some(_) => None,
+                        None => Some(DEFAULT_WEEKLY_CAPACITY),
+                    };
+
+                    capacity = Some(settings.weekly_capacity);
+                }
+                KeyCode::Char('d') => {
+                    settings.daily_capacity = match settings.daily_capacity {
+                        Some(_) => None,
+                        None => Some(DEFAULT_DAILY_CAPACITY),
+                    };
+
+                    daily = Some(settings.daily_capacity);
+                }
+                KeyCode::Char('>') => {
+                    if let Some(value) = settings.daily_capacity.as_mut() {
+                        *value = value.saturating_add(1);
+
+                        daily = Some(settings.daily_capacity);
+                    }
+                }
+                KeyCode::Char('<') => {
+                    if let Some(value) = settings.daily_capacity.as_mut()
+                        && *value > 1
+                    {
+                        *value -= 1;
+
+                        daily = Some(settings.daily_capacity);
+                    }
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    if let Some(value) = settings.weekly_capacity.as_mut() {
+                        *value = value.saturating_add(1);
+
+                        capacity = Some(settings.weekly_capacity);
+                    }
+                }
+                KeyCode::Char('-') => {
+                    if let Some(value) = settings.weekly_capacity.as_mut()
+                        && *value > 1
+                    {
+                        *value -= 1;
+
+                        capacity = Some(settings.weekly_capacity);
+                    }
+                }
                 _ => {}
             }
 
@@ -203,6 +868,46 @@ impl App {
             if let Some(new_pref) = apply {
                 self.apply_week_start(new_pref);
             }
+
+            if let Some(span) = span {
+                self.apply_view_span(span);
+            }
+
+            if let Some(enabled) = pulse {
+                self.apply_selection_pulse(enabled);
+            }
+
+            if let Some(enabled) = collapse {
+                self.apply_collapse_done(enabled);
+            }
+
+            if let Some(enabled) = wrap {
+                self.apply_wrap_titles(enabled);
+            }
+
+            if let Some(enabled) = preview {
+                self.apply_show_note_preview(enabled);
+            }
+
+            if let Some(enabled) = restore {
+                self.apply_restore_session(enabled);
+            }
+
+            if let Some(capacity) = capacity {
+                self.apply_weekly_capacity(capacity);
+            }
+
+            if let Some(capacity) = daily {
+                self.apply_daily_capacity(capacity);
+            }
+
+            if let Some(column) = done_lane {
+                self.apply_done_backlog_column(column);
+            }
+
+            if let Some(enabled) = confirm {
+                self.apply_confirm_deletes(enabled);
+            }
         }
     }
 
@@ -219,18 +924,31 @@ impl App {
                 };
             }
             KeyCode::Enter => {
-                let input = std::mem::take(&mut state.input);
-                let target = state.target.clone();
-
-                if !input.trim().is_empty() {
-                    self.submit_add_todo(input.trim().to_string(), target.clone())
-                        .ok();
+                // `Buy milk @fri` schedules onto that day instead.
+                let mut target = state.target.clone();
+
+                // A tail that isn't a date ("Meet @ 5pm") stays part of the title.
+                if let Some((title, date)) =
+                    state.input.rsplit_once(" @").and_then(|(title, when)| {
+                        parse_date_input(when, self.services.today(), self.week_pref)
+                            .map(|date| (title, date))
+                    })
+                {
+                    state.input = title.to_string();
+                    target = AddTarget::Day(date);
                 }
 
-                self.ui_mode = match target {
+                let input = std::mem::take(&mut state.input);
+
+                // Leave the prompt first: a full day swaps in its own overlay.
+                self.ui_mode = match state.target {
                     AddTarget::Day(_) => UiMode::Board,
                     AddTarget::BacklogColumn(_) => UiMode::Backlog,
                 };
+
+                if !input.trim().is_empty() {
+                    self.try_action(|app| app.submit_add_todo(input.trim().to_string(), target));
+                }
             }
             KeyCode::Char(c) => {
                 state.input.push(c);
@@ -255,17 +973,11 @@ impl App {
 
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') => {
-                let from_backlog = state.from_backlog;
+                self.ui_mode = state.return_to.mode();
 
-                self.ui_mode = if from_backlog {
-                    UiMode::Backlog
-                } else {
-                    UiMode::Board
-                };
+                self.try_action(|app| app.refresh_board());
 
-                self.refresh_board().ok();
-
-                self.refresh_backlog().ok();
+                self.try_action(|app| app.refresh_backlog());
             }
             KeyCode::Char('j') => {
                 let UiMode::Detail(ref mut state) = self.ui_mode else {
@@ -291,7 +1003,22 @@ impl App {
                 }
             }
             KeyCode::Char('x') => {
-                self.toggle_detail_status();
+                self.try_action(|app| app.toggle_detail_status());
+            }
+            KeyCode::Char('o') => {
+                self.open_detail_link();
+            }
+            KeyCode::Char(c @ '0'..='3') => {
+                self.set_detail_priority(c as i32 - '0' as i32);
+            }
+            KeyCode::Char('P') => {
+                let id = state.todo_id;
+
+                if !self.focus_todo_project(id)
+                    && let UiMode::Detail(ref mut state) = self.ui_mode
+                {
+                    state.hint = Some("This todo has no project".to_string());
+                }
             }
             _ => {}
         }
@@ -310,13 +1037,13 @@ impl App {
 
         match key.code {
             KeyCode::Esc => {
-                self.finish_detail_edit(false);
+                self.try_action(|app| app.finish_detail_edit(false));
             }
             KeyCode::Char('j') if is_notes && key.modifiers.contains(KeyModifiers::CONTROL) => {
                 input.push('\n');
             }
             KeyCode::Enter => {
-                self.finish_detail_edit(true);
+                self.try_action(|app| app.finish_detail_edit(true));
             }
             KeyCode::Char(c) => {
                 input.push(c);
@@ -328,17 +1055,17 @@ impl App {
         }
     }
 
-    pub fn finish_detail_edit(&mut self, save: bool) {
+    pub fn finish_detail_edit(&mut self, save: bool) -> miette::Result<()> {
         let UiMode::Detail(ref mut state) = self.ui_mode else {
-            return;
+            return Ok(());
         };
 
         let Some(input) = state.editing.take() else {
-            return;
+            return Ok(());
         };
 
         if !save {
-            return;
+            return Ok(());
         }
 
         let id = state.todo_id;
@@ -346,20 +1073,17 @@ impl App {
 
         match field {
             DetailField::Title => {
-                if !input.trim().is_empty()
-                    && self
-                        .runtime
-                        .block_on(
-                            self.services
-                                .todos
-                                .update_title(id, input.trim().to_string()),
-                        )
-                        .is_ok()
-                {
-                    let UiMode::Detail(ref mut state) = self.ui_mode else {
-                        return;
-                    };
+                if input.trim().is_empty() {
+                    return Ok(());
+                }
 
+                self.runtime.block_on(
+                    self.services
+                        .todos
+                        .update_title(id, input.trim().to_string()),
+                )?;
+
+                if let UiMode::Detail(ref mut state) = self.ui_mode {
                     state.title = input.trim().to_string();
                 }
             }
@@ -370,22 +1094,24 @@ impl App {
                 {
                     Some(None)
                 } else {
-                    NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d")
-                        .ok()
-                        .map(Some)
+                    parse_date_input(&input, self.services.today(), self.week_pref).map(Some)
                 };
 
-                if let Some(date) = new_date
-                    && self
-                        .runtime
-                        .block_on(self.services.todos.update_scheduled_for(id, date))
-                        .is_ok()
-                {
-                    let UiMode::Detail(ref mut state) = self.ui_mode else {
-                        return;
-                    };
+                let Some(date) = new_date else {
+                    state.hint = Some(format!(
+                        "Can't read '{}' as a date (try tomorrow, fri, +3 or 2026-06-13)",
+                        input.trim()
+                    ));
+
+                    return Ok(());
+                };
 
+                self.runtime
+                    .block_on(self.services.todos.update_scheduled_for(id, date))?;
+
+                if let UiMode::Detail(ref mut state) = self.ui_mode {
                     state.date = date;
+                    state.hint = None;
                 }
             }
             DetailField::Notes => {
@@ -395,27 +1121,48 @@ impl App {
                     Some(input.clone())
                 };
 
-                if self
-                    .runtime
-                    .block_on(self.services.todos.update_notes(id, notes))
-                    .is_ok()
-                {
-                    let UiMode::Detail(ref mut state) = self.ui_mode else {
-                        return;
-                    };
+                self.runtime
+                    .block_on(self.services.todos.update_notes(id, notes))?;
 
+                if let UiMode::Detail(ref mut state) = self.ui_mode {
                     state.notes = input;
                 }
             }
+            DetailField::Url => {
+                let url = if input.trim().is_empty() || input.trim().eq_ignore_ascii_case("none") {
+                    None
+                } else {
+                    Some(input.trim().to_string())
+                };
+
+                let result = self
+                    .runtime
+                    .block_on(self.services.todos.update_url(id, url));
+
+                let UiMode::Detail(ref mut state) = self.ui_mode else {
+                    return Ok(());
+                };
+
+                // A malformed link stays next to the field it came from.
+                match result {
+                    Ok(model) => {
+                        state.url = model.url;
+                        state.hint = None;
+                    }
+                    Err(err) => state.hint = Some(err.to_string()),
+                }
+            }
             DetailField::Status => {}
         }
+
+        Ok(())
     }
 
     pub fn handle_horizontal(&mut self, dir: Horizontal) {
         let day_count = self.state.columns.len();
 
         if self.cursor.selection.is_some() {
-            self.move_selected_horizontal(dir).ok();
+            self.try_action(|app| app.move_selected_horizontal(dir));
         } else {
             match dir {
                 Horizontal::Left => {
@@ -426,7 +1173,7 @@ impl App {
 
                         self.board.reset(day_count);
 
-                        self.refresh_board().ok();
+                        self.try_action(|app| app.refresh_board());
                     } else {
                         self.cursor.focus -= 1;
                     }
@@ -439,7 +1186,7 @@ impl App {
 
                         self.board.reset(day_count);
 
-                        self.refresh_board().ok();
+                        self.try_action(|app| app.refresh_board());
                     } else {
                         self.cursor.focus += 1;
                     }
@@ -456,7 +1203,7 @@ impl App {
                 Vertical::Down => ReorderDirection::Down,
             };
 
-            self.reorder_selected(reorder_dir).ok();
+            self.try_action(|app| app.reorder_selected(reorder_dir));
         } else {
             self.cursor.move_vertical(dir, &self.board);
         }
@@ -468,8 +1215,18 @@ impl App {
             return;
         }
 
+        let row = self.cursor.row_for(self.cursor.focus, &self.board);
+
+        if row.is_some() && row == self.board.summary_row(self.cursor.focus) {
+            self.toggle_day_collapse();
+
+            return;
+        }
+
         if let Some(id) = self.current_target_id() {
-            let row = self.cursor.row_for(self.cursor.focus, &self.board);
+            // Drag mode moves one todo, so it drops the multi-selection.
+            self.selected.clear();
+
             self.cursor.selection = Some(Selection {
                 id,
                 column: self.cursor.focus,
@@ -480,7 +1237,7 @@ impl App {
 
     pub fn handle_backlog_horizontal(&mut self, dir: Horizontal) {
         if self.backlog_cursor.selection.is_some() {
-            self.move_backlog_selected_horizontal(dir).ok();
+            self.try_action(|app| app.move_backlog_selected_horizontal(dir));
         } else {
             self.backlog_cursor.move_horizontal(dir);
         }
@@ -493,7 +1250,7 @@ impl App {
                 Vertical::Down => ReorderDirection::Down,
             };
 
-            self.reorder_backlog_selected(reorder_dir).ok();
+            self.try_action(|app| app.reorder_backlog_selected(reorder_dir));
         } else {
             self.backlog_cursor.move_vertical(dir, &self.board);
         }
@@ -507,6 +1264,8 @@ impl App {
         }
 
         if let Some(id) = self.backlog_cursor.current_todo_id(&self.board) {
+            self.selected.clear();
+
             let row = self
                 .backlog_cursor
                 .row_for(self.backlog_cursor.column, &self.board);
@@ -519,19 +1278,57 @@ impl App {
         }
     }
 
-    pub fn change_week(&mut self, delta: i32) {
-        if delta < 0 {
-            self.state.prev_week();
-        } else {
-            self.state.next_week();
+    /// Return to the week holding today with the cursor on today. Already on
+    /// that week, only the focus moves; nothing is reloaded.
+    pub fn jump_to_today(&mut self) {
+        let today = self.services.today();
+
+        if self.state.column_index(today).is_none() {
+            self.state = WeekState::new(today, self.week_pref, self.view_span);
+
+            self.board.reset(self.state.columns.len());
+
+            self.cursor
+                .sync_after_refresh(self.state.columns.len(), &self.board);
+
+            self.try_action(|app| app.refresh_board());
+        }
+
+        if let Some(idx) = self.state.column_index(today) {
+            self.cursor.set_focus_row(idx, 0);
         }
+    }
+
+    /// `[`/`]`: page the board, keeping the cursor on the same weekday and,
+    /// where the new day has enough todos, the same row.
+    pub fn change_week(&mut self, delta: i32) {
+        let focus = self.cursor.focus;
+        let weekday = self.state.columns.get(focus).map(|col| col.date.weekday());
+        let row = self.cursor.row_for(focus, &self.board).unwrap_or(0);
+
+        self.state.shift_weeks(delta);
 
         self.board.reset(self.state.columns.len());
 
         self.cursor
             .sync_after_refresh(self.state.columns.len(), &self.board);
 
-        self.refresh_board().ok();
+        self.try_action(|app| app.refresh_board());
+
+        // A `Days` view pages by its own length, so the weekday may have moved
+        // to another column.
+        let column = weekday.and_then(|weekday| {
+            self.state
+                .columns
+                .iter()
+                .position(|col| col.date.weekday() == weekday)
+        });
+
+        if let Some(column) = column {
+            let last = self.board.day_len(column).saturating_sub(1);
+
+            self.cursor.set_focus_row(column, row.min(last));
+        }
     }
 
     pub fn move_backlog_selected_horizontal(&mut self, dir: Horizontal) -> miette::Result<()> {
@@ -574,4 +1371,238 @@ impl App {
 
         Ok(())
     }
+
+    pub fn move_backlog_lane(&mut self, dir: Horizontal) -> miette::Result<()> {
+        let from = self.backlog_cursor.column;
+
+        let to = match dir {
+            Horizontal::Left if from > 0 => from - 1,
+            Horizontal::Right if from + 1 < BACKLOG_COLUMNS => from + 1,
+            _ => return Ok(()),
+        };
+
+        self.runtime.block_on(
+            self.services
+                .todos
+                .move_backlog_column(from as i64, to as i64),
+        )?;
+
+        // The lane's title goes with it.
+        self.runtime
+            .block_on(self.services.config.swap_backlog_labels(from, to))?;
+        self.runtime
+            .block_on(self.services.reload_backlog_labels())?;
+
+        self.backlog_cursor.rows.swap(from, to);
+        self.backlog_cursor.column = to;
+        self.backlog_cursor.selection = None;
+
+        self.refresh_backlog()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::test_support;
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            press(app, KeyCode::Char(c));
+        }
+    }
+
+    /// Put a todo on today's column and focus it.
+    fn add_today(app: &mut App, title: &str) {
+        let today = app.services.today();
+
+        app.runtime
+            .block_on(
+                app.services
+                    .todos
+                    .add(title, Some(today), None, None, None, None),
+            )
+            .unwrap();
+
+        app.refresh_board().unwrap();
+
+        let col = app.state.column_index(today).unwrap();
+        app.cursor.set_focus_row(col, 0);
+    }
+
+    /// A Monday-first week board, so the columns don't depend on settings.
+    fn week_board(app: &mut App) {
+        app.state = WeekState::new(app.services.today(), WeekStart::Monday, ViewSpan::Week);
+        app.board.reset(app.state.columns.len());
+        app.refresh_board().unwrap();
+    }
+
+    fn scheduled_for(app: &App, id: uuid::Uuid) -> Option<chrono::NaiveDate> {
+        app.runtime
+            .block_on(app.services.todos.get(id))
+            .unwrap()
+            .scheduled_for
+    }
+
+    #[test]
+    fn week_keys_still_page_on_the_board() {
+        let (_rt, mut app) = test_support::app();
+        let week = app.state.week_start;
+
+        press(&mut app, KeyCode::Char(']'));
+
+        assert!(app.state.week_start > week);
+    }
+
+    #[test]
+    fn paste_and_the_project_picker_have_their_own_keys() {
+        let (_rt, mut app) = test_support::app();
+
+        // Nothing yanked: `p` has nothing to paste and opens nothing.
+        press(&mut app, KeyCode::Char('p'));
+
+        assert!(matches!(app.ui_mode, UiMode::Board));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT));
+
+        assert!(matches!(app.ui_mode, UiMode::ProjectPicker(_)));
+    }
+
+    #[test]
+    fn brackets_are_typed_into_the_add_prompt() {
+        let (_rt, mut app) = test_support::app();
+        let week = app.state.week_start;
+
+        app.open_add_todo_board();
+        type_text(&mut app, "[x] ]done[");
+
+        assert_eq!(app.state.week_start, week);
+
+        let UiMode::AddTodo(state) = &app.ui_mode else {
+            panic!("the add prompt closed");
+        };
+
+        assert_eq!(state.input, "[x] ]done[");
+    }
+
+    #[test]
+    fn brackets_are_typed_into_an_inline_rename() {
+        let (_rt, mut app) = test_support::app();
+        add_today(&mut app, "Title");
+        let week = app.state.week_start;
+
+        app.open_edit_title(false).unwrap();
+        type_text(&mut app, " [wip]");
+
+        assert_eq!(app.state.week_start, week);
+
+        let UiMode::EditTitle(state) = &app.ui_mode else {
+            panic!("the rename closed");
+        };
+
+        assert_eq!(state.input, "Title [wip]");
+    }
+
+    #[test]
+    fn brackets_are_typed_into_a_detail_field() {
+        let (_rt, mut app) = test_support::app();
+        add_today(&mut app, "Title");
+        let week = app.state.week_start;
+
+        app.open_detail_board().unwrap();
+        press(&mut app, KeyCode::Enter);
+        type_text(&mut app, "]");
+
+        assert_eq!(app.state.week_start, week);
+
+        let UiMode::Detail(state) = &app.ui_mode else {
+            panic!("the detail view closed");
+        };
+
+        assert_eq!(state.editing.as_deref(), Some("Title]"));
+    }
+
+    #[test]
+    fn brackets_move_a_held_todo_a_week_and_keep_it_selected() {
+        let (_rt, mut app) = test_support::app();
+        week_board(&mut app);
+
+        let today = app.services.today();
+        let next_week = today + chrono::Duration::days(7);
+
+        // Something already waits there, so the row has to be looked up.
+        app.runtime
+            .block_on(
+                app.services
+                    .todos
+                    .add("waiting", Some(next_week), None, None, None, None),
+            )
+            .unwrap();
+
+        add_today(&mut app, "held");
+
+        let col = app.cursor.focus;
+        let id = app.current_target_id().unwrap();
+        let week = app.state.week_start;
+
+        app.toggle_selection();
+        press(&mut app, KeyCode::Char(']'));
+
+        assert_eq!(scheduled_for(&app, id), Some(next_week));
+        assert_eq!(app.state.week_start, week + chrono::Duration::days(7));
+
+        let selection = app.cursor.selection.unwrap();
+
+        assert_eq!(selection.id, id);
+        assert_eq!(selection.column, col);
+        assert_eq!(selection.row, Some(0));
+        assert_eq!(app.cursor.focus, col);
+        assert_eq!(app.cursor.day_rows[col], 0);
+
+        press(&mut app, KeyCode::Char('['));
+
+        assert_eq!(scheduled_for(&app, id), Some(today));
+        assert_eq!(app.state.week_start, week);
+        assert_eq!(app.cursor.selection.unwrap().row, Some(0));
+        assert_eq!(app.current_target_id(), Some(id));
+    }
+
+    #[test]
+    fn moving_right_off_the_week_lands_on_the_next_monday() {
+        let (_rt, mut app) = test_support::app();
+        week_board(&mut app);
+
+        let last = app.state.columns.len() - 1;
+        let sunday = app.state.columns[last].date;
+        let todo = app
+            .runtime
+            .block_on(
+                app.services
+                    .todos
+                    .add("held", Some(sunday), None, None, None, None),
+            )
+            .unwrap();
+
+        app.refresh_board().unwrap();
+        app.cursor.set_focus_row(last, 0);
+        app.toggle_selection();
+
+        press(&mut app, KeyCode::Char('l'));
+
+        assert_eq!(
+            scheduled_for(&app, todo.id),
+            Some(sunday + chrono::Duration::days(1))
+        );
+        assert_eq!(app.state.week_start, sunday + chrono::Duration::days(1));
+
+        let selection = app.cursor.selection.unwrap();
+
+        assert_eq!(selection.column, 0);
+        assert_eq!(selection.row, Some(0));
+        assert_eq!(app.cursor.focus, 0);
+    }
 }
@@ -0,0 +1,551 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Something a key does on the board, in the backlog or in the agenda.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Quit from the board; back to the board from the other views.
+    Quit,
+    MoveLeft,
+    MoveRight,
+    MoveDown,
+    MoveUp,
+    LastRow,
+    PrevWeek,
+    NextWeek,
+    /// Start or end drag mode.
+    Drag,
+    Pick,
+    /// Takes the key twice, like `yy`.
+    Yank,
+    Paste,
+    Duplicate,
+    Mark,
+    SwapWithMark,
+    Details,
+    EditTitle,
+    AddTodo,
+    ToggleDone,
+    /// Takes the key twice, like `dd`.
+    Archive,
+    Undo,
+    SendToBacklog,
+    MoveToToday,
+    MoveToTomorrow,
+    LaneToToday,
+    LaneLeft,
+    LaneRight,
+    FoldDay,
+    ToggleShowDone,
+    /// Move overdue todos to today, as on launch.
+    Rollover,
+    /// Pick a project to filter the board by.
+    ProjectPicker,
+    /// Open the backlog, or leave it for the board.
+    Backlog,
+    /// Open the agenda, or leave it for the board.
+    Agenda,
+    NextView,
+    PrevView,
+}
+
+/// Config names and built-in keys, in the order they are listed to users.
+const DEFAULTS: &[(Action, &str, &[&str])] = &[
+    (Action::Quit, "quit", &["q"]),
+    (Action::MoveLeft, "move_left", &["h"]),
+    (Action::MoveRight, "move_right", &["l"]),
+    (Action::MoveDown, "move_down", &["j"]),
+    (Action::MoveUp, "move_up", &["k"]),
+    (Action::LastRow, "last_row", &["G"]),
+    (Action::PrevWeek, "prev_week", &["["]),
+    (Action::NextWeek, "next_week", &["]"]),
+    (Action::Drag, "drag", &["enter"]),
+    (Action::Pick, "pick", &["V"]),
+    (Action::Yank, "yank", &["y"]),
+    (Action::Paste, "paste", &["p"]),
+    (Action::Duplicate, "duplicate", &["c"]),
+    (Action::Mark, "mark", &["m"]),
+    (Action::SwapWithMark, "swap_with_mark", &["M"]),
+    (Action::Details, "details", &["space"]),
+    (Action::EditTitle, "edit_title", &["e"]),
+    (Action::AddTodo, "add_todo", &["a"]),
+    (Action::ToggleDone, "toggle_done", &["x"]),
+    (Action::Archive, "archive", &["d"]),
+    (Action::Undo, "undo", &["u"]),
+    (Action::SendToBacklog, "send_to_backlog", &["s"]),
+    (Action::MoveToToday, "move_to_today", &["t"]),
+    (Action::MoveToTomorrow, "move_to_tomorrow", &["T"]),
+    (Action::LaneToToday, "lane_to_today", &["A"]),
+    (Action::LaneLeft, "lane_left", &["<"]),
+    (Action::LaneRight, "lane_right", &[">"]),
+    (Action::FoldDay, "fold_day", &["z"]),
+    (Action::ToggleShowDone, "toggle_show_done", &["H"]),
+    (Action::Rollover, "rollover", &["R"]),
+    (Action::ProjectPicker, "project_picker", &["P"]),
+    (Action::Backlog, "backlog", &["b"]),
+    (Action::Agenda, "agenda", &["v"]),
+    (Action::NextView, "next_view", &["tab"]),
+    (Action::PrevView, "prev_view", &["backtab"]),
+];
+
+/// A key with its modifiers. Shift is folded into the character, so `T`
+/// and `shift+t` are the same binding, and into `BackTab`, which terminals
+/// report with Shift held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => Self {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+
+    /// Parse `x`, `T`, `ctrl+d`, `alt+j`, `shift+tab`, `left`, `space`, ...
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = input.trim();
+
+        // A lone `+` is a key, not a separator.
+        while let Some((prefix, key)) = rest.split_once('+')
+            && !key.is_empty()
+        {
+            modifiers |= match prefix.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+
+            rest = key;
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "space" => KeyCode::Char(' '),
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            _ => {
+                let mut chars = rest.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return None,
+                }
+            }
+        };
+
+        Some(Self::new(code, modifiers))
+    }
+
+    /// Keys the views handle themselves: the `g` prefix, count digits, `?`,
+    /// `Esc` and `Ctrl+C`.
+    fn is_reserved(&self) -> bool {
+        match self.code {
+            KeyCode::Esc => true,
+            KeyCode::Char('c') => self.modifiers.contains(KeyModifiers::CONTROL),
+            KeyCode::Char(c) => {
+                self.modifiers.is_empty() && (c == 'g' || c == '?' || c.is_ascii_digit())
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("ctrl+")?;
+        }
+
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("alt+")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// Which action each key triggers in the board, backlog and agenda views.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyBinding, Action>,
+}
+
+impl Keymap {
+    /// Build the keymap from the `keymap` setting, e.g.
+    /// `{"archive": ["D"], "move_left": ["h", "left"]}`. Each entry replaces
+    /// that action's default keys. Entries that can't be used are skipped
+    /// and described in the returned warnings.
+    pub fn from_overrides(overrides: &BTreeMap<String, Vec<String>>) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut custom: Vec<(Action, Vec<KeyBinding>)> = Vec::new();
+
+        for (name, keys) in overrides {
+            let Some(action) = action_by_name(name) else {
+                warnings.push(format!("keymap: unknown action '{name}'"));
+                continue;
+            };
+
+            let mut bindings = Vec::with_capacity(keys.len());
+
+            for key in keys {
+                match KeyBinding::parse(key) {
+                    Some(binding) if binding.is_reserved() => {
+                        warnings.push(format!("keymap: '{key}' is reserved, not bound to {name}"));
+                    }
+                    Some(binding) => bindings.push(binding),
+                    None => warnings.push(format!("keymap: unknown key '{key}' for {name}")),
+                }
+            }
+
+            custom.push((action, bindings));
+        }
+
+        let mut map = HashMap::new();
+
+        // Defaults first so that a remapped key takes over from them.
+        for (action, _, keys) in DEFAULTS {
+            if custom.iter().any(|(a, _)| a == action) {
+                continue;
+            }
+
+            for key in *keys {
+                if let Some(binding) = KeyBinding::parse(key) {
+                    map.insert(binding, *action);
+                }
+            }
+        }
+
+        let defaults = map.clone();
+
+        for (action, bindings) in custom {
+            for binding in bindings {
+                match map.get(&binding) {
+                    Some(other) if *other != action && !defaults.contains_key(&binding) => {
+                        warnings.push(format!(
+                            "keymap: '{binding}' is already bound to {}, not {}",
+                            action_name(*other),
+                            action_name(action)
+                        ));
+                    }
+                    _ => {
+                        map.insert(binding, action);
+                    }
+                }
+            }
+        }
+
+        (Self { bindings: map }, warnings)
+    }
+
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings.get(&KeyBinding::from_event(key)).copied()
+    }
+
+    /// A key bound to `action`, for hints; the same one every time when
+    /// there are several.
+    pub fn key_for(&self, action: Action) -> Option<String> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(binding, _)| binding.to_string())
+            .min()
+    }
+}
+
+fn action_by_name(name: &str) -> Option<Action> {
+    let name = name.trim().to_lowercase().replace('-', "_");
+
+    DEFAULTS
+        .iter()
+        .find(|(_, n, _)| *n == name)
+        .map(|(action, _, _)| *action)
+}
+
+fn action_name(action: Action) -> &'static str {
+    DEFAULTS
+        .iter()
+        .find(|(a, _, _)| *a == action)
+        .map_or("?", |(_, name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn binding(input: &str) -> KeyBinding {
+        KeyBinding::parse(input).unwrap()
+    }
+
+    fn overrides(entries: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(name, keys)| {
+                (
+                    name.to_string(),
+                    keys.iter().map(|key| key.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_plain_and_named_keys() {
+        assert_eq!(binding("x").code, KeyCode::Char('x'));
+        assert_eq!(binding(" x ").code, KeyCode::Char('x'));
+        assert_eq!(binding("space").code, KeyCode::Char(' '));
+        assert_eq!(binding("Enter").code, KeyCode::Enter);
+        assert_eq!(binding("return").code, KeyCode::Enter);
+        assert_eq!(binding("del").code, KeyCode::Delete);
+        assert_eq!(binding("pagedown").code, KeyCode::PageDown);
+        assert_eq!(binding("+").code, KeyCode::Char('+'));
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        let ctrl_d = binding("ctrl+d");
+
+        assert_eq!(ctrl_d.code, KeyCode::Char('d'));
+        assert_eq!(ctrl_d.modifiers, KeyModifiers::CONTROL);
+        assert_eq!(binding("Control+d"), ctrl_d);
+        assert_eq!(binding("alt+j").modifiers, KeyModifiers::ALT);
+        assert_eq!(
+            binding("ctrl+alt+left").modifiers,
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        );
+        assert_eq!(binding("ctrl++").code, KeyCode::Char('+'));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert_eq!(KeyBinding::parse(""), None);
+        assert_eq!(KeyBinding::parse("xy"), None);
+        assert_eq!(KeyBinding::parse("hyper+x"), None);
+        assert_eq!(KeyBinding::parse("ctrl+"), None);
+    }
+
+    #[test]
+    fn shift_is_folded_into_the_char() {
+        assert_eq!(binding("shift+t"), binding("T"));
+        assert_eq!(binding("T").modifiers, KeyModifiers::NONE);
+        assert_eq!(binding("ctrl+shift+x"), binding("ctrl+X"));
+        assert_ne!(binding("T"), binding("t"));
+
+        // Terminals report `T` with Shift held; it has to match the binding.
+        let event = key(KeyCode::Char('T'), KeyModifiers::SHIFT);
+
+        assert_eq!(KeyBinding::from_event(&event), binding("T"));
+    }
+
+    #[test]
+    fn shift_tab_is_backtab() {
+        assert_eq!(binding("shift+tab"), binding("backtab"));
+        assert_eq!(binding("shift+tab").modifiers, KeyModifiers::NONE);
+        assert_ne!(binding("tab"), binding("backtab"));
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for input in [
+            "x",
+            "T",
+            "[",
+            "<",
+            "space",
+            "enter",
+            "tab",
+            "backtab",
+            "backspace",
+            "delete",
+            "left",
+            "pageup",
+            "ctrl+d",
+            "alt+j",
+            "ctrl+alt+x",
+            "ctrl+T",
+        ] {
+            let parsed = binding(input);
+
+            assert_eq!(parsed.to_string(), input);
+            assert_eq!(binding(&parsed.to_string()), parsed);
+        }
+
+        assert_eq!(binding("shift+t").to_string(), "T");
+    }
+
+    #[test]
+    fn every_default_parses_and_is_not_reserved() {
+        for (_, name, keys) in DEFAULTS {
+            for key in *keys {
+                let parsed = KeyBinding::parse(key);
+
+                assert!(parsed.is_some(), "{name}: '{key}' doesn't parse");
+                assert!(
+                    !parsed.unwrap().is_reserved(),
+                    "{name}: '{key}' is reserved"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn defaults_map_keys_to_actions() {
+        let (keymap, warnings) = Keymap::from_overrides(&BTreeMap::new());
+
+        assert!(warnings.is_empty());
+
+        let plain = |c| key(KeyCode::Char(c), KeyModifiers::NONE);
+
+        assert_eq!(keymap.action(&plain('h')), Some(Action::MoveLeft));
+        assert_eq!(keymap.action(&plain('d')), Some(Action::Archive));
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('T'), KeyModifiers::SHIFT)),
+            Some(Action::MoveToTomorrow)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Enter, KeyModifiers::NONE)),
+            Some(Action::Drag)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(Action::PrevView)
+        );
+        assert_eq!(keymap.action(&plain('g')), None);
+        assert_eq!(keymap.key_for(Action::Details).as_deref(), Some("space"));
+    }
+
+    #[test]
+    fn default_names_and_keys_are_unique() {
+        let mut names = std::collections::HashSet::new();
+        let mut keys = std::collections::HashSet::new();
+
+        for (action, name, bound) in DEFAULTS {
+            assert!(names.insert(*name), "'{name}' is listed twice");
+            assert_eq!(action_by_name(name), Some(*action));
+            assert_eq!(action_name(*action), *name);
+
+            for key in *bound {
+                assert!(keys.insert(binding(key)), "'{key}' is bound twice");
+            }
+        }
+    }
+
+    #[test]
+    fn an_override_replaces_the_default_keys() {
+        let (keymap, warnings) = Keymap::from_overrides(&overrides(&[("archive", &["D"])]));
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('D'), KeyModifiers::SHIFT)),
+            Some(Action::Archive)
+        );
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('d'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(keymap.key_for(Action::Archive).as_deref(), Some("D"));
+    }
+
+    #[test]
+    fn an_override_can_take_a_default_key() {
+        let (keymap, warnings) = Keymap::from_overrides(&overrides(&[("archive", &["x"])]));
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Some(Action::Archive)
+        );
+    }
+
+    #[test]
+    fn action_names_accept_dashes_and_case() {
+        let (keymap, warnings) = Keymap::from_overrides(&overrides(&[("Move-Left", &["left"])]));
+
+        assert!(warnings.is_empty());
+        assert_eq!(
+            keymap.action(&key(KeyCode::Left, KeyModifiers::NONE)),
+            Some(Action::MoveLeft)
+        );
+    }
+
+    #[test]
+    fn unusable_entries_are_skipped_with_a_warning() {
+        let (keymap, warnings) = Keymap::from_overrides(&overrides(&[
+            ("fly", &["f"]),
+            ("undo", &["g", "ctrl+c", "hyper+u", "U"]),
+        ]));
+
+        assert_eq!(warnings.len(), 4);
+        assert!(warnings[0].contains("unknown action 'fly'"));
+        assert!(warnings[1].contains("'g' is reserved"));
+        assert!(warnings[2].contains("'ctrl+c' is reserved"));
+        assert!(warnings[3].contains("unknown key 'hyper+u'"));
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('U'), KeyModifiers::SHIFT)),
+            Some(Action::Undo)
+        );
+    }
+
+    #[test]
+    fn two_overrides_cannot_share_a_key() {
+        let (keymap, warnings) =
+            Keymap::from_overrides(&overrides(&[("archive", &["D"]), ("undo", &["D"])]));
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("already bound to archive, not undo"));
+        assert_eq!(
+            keymap.action(&key(KeyCode::Char('D'), KeyModifiers::SHIFT)),
+            Some(Action::Archive)
+        );
+    }
+}
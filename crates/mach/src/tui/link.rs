@@ -0,0 +1,42 @@
+use std::io;
+use std::process::{Command, Stdio};
+
+/// Open `url` with the platform's default handler without touching the TUI.
+#[cfg(feature = "open-url")]
+pub fn open(url: &str) -> io::Result<()> {
+    let mut cmd = opener();
+
+    cmd.arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(not(feature = "open-url"))]
+pub fn open(_url: &str) -> io::Result<()> {
+    Err(io::Error::other("opening links is disabled in this build"))
+}
+
+#[cfg(all(feature = "open-url", target_os = "macos"))]
+fn opener() -> Command {
+    Command::new("open")
+}
+
+#[cfg(all(feature = "open-url", target_os = "windows"))]
+fn opener() -> Command {
+    let mut cmd = Command::new("cmd");
+
+    cmd.args(["/C", "start", ""]);
+
+    cmd
+}
+
+#[cfg(all(
+    feature = "open-url",
+    not(any(target_os = "macos", target_os = "windows"))
+))]
+fn opener() -> Command {
+    Command::new("xdg-open")
+}
@@ -1,33 +1,50 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::time::{Duration, Instant};
 
+use chrono::NaiveDate;
 use crossterm::event;
 use miette::{Context, IntoDiagnostic};
 use tokio::runtime::Handle;
+use uuid::Uuid;
 
-use crate::service::{Services, config::WeekStart};
+use crate::service::{
+    Services,
+    config::{LastView, ViewSpan, WeekStart},
+    todo::ProjectFilter,
+};
 
 mod actions;
 mod cursor;
 mod draw;
 mod input;
+mod keymap;
+mod link;
 mod modes;
+mod mouse;
 pub mod palette;
 mod state;
 mod terminal;
+mod undo;
 
-use cursor::{BacklogCursor, CursorState};
+use cursor::{AgendaCursor, BacklogCursor, CursorState};
+use keymap::Keymap;
 use modes::UiMode;
-use state::{BoardData, WeekState};
+use mouse::{ColumnHit, HitColumn};
+use palette::Palette;
+use state::{AgendaData, BACKLOG_COLUMNS, BoardData, ScopeLabels, WeekState};
 use terminal::{TerminalGuard, setup_terminal};
+use undo::UndoStack;
 
-/// Launch the Ratatui application, blocking on the UI event loop.
-pub async fn run(services: Services) -> miette::Result<()> {
+/// Launch the Ratatui application, blocking on the UI event loop. With
+/// `focus` the board starts on that todo with its details open.
+pub async fn run(services: Services, focus: Option<Uuid>) -> miette::Result<()> {
     let handle = Handle::current();
 
     let task = tokio::task::spawn_blocking(move || {
         let mut app = App::new(services, handle);
 
-        app.run()
+        app.run(focus)
     });
 
     task.await.into_diagnostic()??;
@@ -42,10 +59,56 @@ pub struct App {
     board: BoardData,
     cursor: CursorState,
     backlog_cursor: BacklogCursor,
+    agenda: AgendaData,
+    agenda_cursor: AgendaCursor,
     week_pref: WeekStart,
+    view_span: ViewSpan,
+    /// Reopen on the week and day left at quit.
+    restore_session: bool,
+    selection_pulse: bool,
+    collapse_done: bool,
+    /// Whether done todos are listed at all; `H` toggles it.
+    show_done: bool,
+    /// Wrap long titles over several lines instead of cutting them off.
+    wrap_titles: bool,
+    /// Show the first line of a todo's notes under its title.
+    show_note_preview: bool,
+    /// Days whose done todos are shown opposite to `collapse_done`.
+    toggled_days: HashSet<NaiveDate>,
+    weekly_capacity: Option<u32>,
+    daily_capacity: Option<u32>,
+    done_backlog_column: Option<usize>,
+    confirm_deletes: bool,
+    keymap: Keymap,
+    palette: Palette,
+    tick: u64,
     ui_mode: UiMode,
+    project_filter: ProjectFilter,
+    scope_labels: ScopeLabels,
     pending_g: bool,
     pending_delete: bool,
+    /// First `y` of `yy` seen.
+    pending_yank: bool,
+    /// Digits typed before a motion, e.g. the `5` in `5j`.
+    pending_count: Option<usize>,
+    /// Todo marked with `m`, waiting for `M` to swap it with another.
+    swap_mark: Option<Uuid>,
+    /// Todos picked with `V`; while any are picked, `x`, `dd`, `s` and `t`
+    /// act on all of them instead of the focused todo.
+    selected: HashSet<Uuid>,
+    /// Todo taken with `yy` (with its title for the header), waiting for `p`
+    /// to move it to whichever day or backlog column has focus.
+    yanked: Option<(Uuid, String)>,
+    undo: UndoStack,
+    /// Columns as last drawn, for mapping mouse clicks to rows.
+    hit_areas: RefCell<Vec<ColumnHit>>,
+    /// Last failed action's error and when it happened, shown in the
+    /// status bar until it expires.
+    status_message: Option<(String, Instant)>,
+    /// Pending todos on days before today, counted on every refresh.
+    overdue: u64,
+    /// Time and row of the last left click, to spot a double click.
+    last_click: Option<(Instant, HitColumn, usize)>,
     should_quit: bool,
     show_help: bool,
 }
@@ -54,12 +117,55 @@ impl App {
     fn new(services: Services, runtime: Handle) -> Self {
         let today = services.today();
         let week_pref = services.week_start();
+        let selection_pulse = services.selection_pulse();
+        let collapse_done = services.collapse_done();
+        let show_done = services.show_done();
+        let wrap_titles = services.wrap_titles();
+        let show_note_preview = services.show_note_preview();
+        let weekly_capacity = services.weekly_capacity();
+        let daily_capacity = services.daily_capacity();
+        let confirm_deletes = services.confirm_deletes();
+        let done_backlog_column = services
+            .done_backlog_column()
+            .filter(|col| *col < BACKLOG_COLUMNS);
+
+        // Printed before the terminal switches screens, so they stay visible
+        // once the TUI exits.
+        let (keymap, mut warnings) = Keymap::from_overrides(services.keymap());
+        let (palette, theme_warnings) =
+            Palette::from_config(services.theme(), services.theme_colors());
+
+        warnings.extend(theme_warnings);
+
+        for warning in warnings {
+            eprintln!("{warning}");
+        }
+
+        let ui_mode = if services.first_run() {
+            UiMode::Welcome
+        } else {
+            UiMode::Board
+        };
+
+        let view_span = services.view_span();
+        let restore_session = services.restore_session();
+        let last_view = services.last_view().filter(|_| restore_session);
+
+        // The saved page is re-aligned to the current week start and span,
+        // in case either changed since it was saved.
+        let state = match last_view {
+            Some(view) => WeekState::new(view.week_start, week_pref, view_span),
+            None => WeekState::new(today, week_pref, view_span),
+        };
 
-        let state = WeekState::new(today, week_pref);
         let board = BoardData::new(state.columns.len());
         let mut cursor = CursorState::new(state.columns.len());
 
-        if let Some(idx) = state.column_index(today) {
+        let focus = last_view
+            .and_then(|view| state.column_index(view.focus))
+            .or_else(|| state.column_index(today));
+
+        if let Some(idx) = focus {
             cursor.set_focus_row(idx, 0);
         }
 
@@ -70,17 +176,50 @@ impl App {
             board,
             cursor,
             backlog_cursor: BacklogCursor::new(),
+            agenda: AgendaData::default(),
+            agenda_cursor: AgendaCursor::new(),
             week_pref,
-            ui_mode: UiMode::Board,
+            view_span,
+            restore_session,
+            selection_pulse,
+            collapse_done,
+            show_done,
+            wrap_titles,
+            show_note_preview,
+            toggled_days: HashSet::new(),
+            weekly_capacity,
+            daily_capacity,
+            done_backlog_column,
+            confirm_deletes,
+            keymap,
+            palette,
+            tick: 0,
+            ui_mode,
+            project_filter: ProjectFilter::Any,
+            scope_labels: ScopeLabels::default(),
             pending_g: false,
             pending_delete: false,
+            pending_yank: false,
+            pending_count: None,
+            swap_mark: None,
+            selected: HashSet::new(),
+            yanked: None,
+            undo: UndoStack::default(),
+            hit_areas: RefCell::new(Vec::new()),
+            last_click: None,
+            overdue: 0,
+            status_message: None,
             should_quit: false,
             show_help: false,
         }
     }
 
-    fn run(&mut self) -> miette::Result<()> {
-        self.refresh_board().ok();
+    fn run(&mut self, focus: Option<Uuid>) -> miette::Result<()> {
+        self.try_action(|app| app.refresh_board());
+
+        if let Some(id) = focus {
+            self.try_action(|app| app.jump_to_todo(id));
+        }
 
         let mut terminal = setup_terminal()?;
         let _guard = TerminalGuard;
@@ -110,9 +249,64 @@ impl App {
 
             if last_tick.elapsed() >= tick_rate {
                 last_tick = Instant::now();
+
+                self.tick = self.tick.wrapping_add(1);
+
+                self.expire_status_message();
+
+                self.check_new_day();
             }
         }
 
+        if self.restore_session {
+            self.save_last_view();
+        }
+
         Ok(())
     }
+
+    fn save_last_view(&self) {
+        let Some(focus) = self.state.columns.get(self.cursor.focus) else {
+            return;
+        };
+
+        let view = LastView {
+            week_start: self.state.week_start,
+            focus: focus.date,
+        };
+
+        if let Err(err) = self
+            .runtime
+            .block_on(self.services.config.save_last_view(view))
+        {
+            eprintln!("failed to save the last viewed week: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_support {
+    use tokio::runtime::Runtime;
+
+    use super::*;
+    use crate::service::connection::init_memory_database;
+
+    /// An app on the board over a fresh in-memory database. The runtime has
+    /// to outlive the app, which blocks on it from the test thread.
+    pub fn app() -> (Runtime, App) {
+        let runtime = Runtime::new().unwrap();
+
+        let services = runtime.block_on(async {
+            let conn = init_memory_database().await.unwrap();
+
+            Services::from_connection(conn).await.unwrap()
+        });
+
+        let mut app = App::new(services, runtime.handle().clone());
+
+        app.ui_mode = UiMode::Board;
+        app.refresh_board().unwrap();
+
+        (runtime, app)
+    }
 }
@@ -1,19 +1,188 @@
 use chrono::NaiveDate;
 use uuid::Uuid;
 
-use crate::service::config::WeekStart;
+use crate::service::config::{ViewSpan, WeekStart};
+use crate::service::todo::ProjectFilter;
 
 pub enum UiMode {
     Board,
     Backlog,
+    /// Upcoming todos as one list, grouped under date headers.
+    Agenda,
     Settings(SettingsState),
     AddTodo(AddTodoState),
     Detail(DetailState),
+    Review(ReviewState),
+    /// One-time introduction shown on a fresh, empty database.
+    Welcome,
+    /// Titles of the todos completed today.
+    DoneToday(Vec<String>),
+    /// A todo headed for a day that is already at its daily capacity.
+    DayFull(DayFullState),
+    /// Choosing which project the board is narrowed to.
+    ProjectPicker(ProjectPickerState),
+    /// Renaming a todo in place on its board or backlog row.
+    EditTitle(EditTitleState),
+    /// Yes/no question guarding a destructive action.
+    Confirm(ConfirmState),
+}
+
+impl UiMode {
+    /// The full-screen view this mode belongs to, if it is one of the views
+    /// `Tab` cycles through.
+    pub fn primary_view(&self) -> Option<PrimaryView> {
+        match self {
+            UiMode::Board => Some(PrimaryView::Board),
+            UiMode::Backlog => Some(PrimaryView::Backlog),
+            UiMode::Agenda => Some(PrimaryView::Agenda),
+            _ => None,
+        }
+    }
+
+    /// Whether the mode is collecting text. Such modes must see every key,
+    /// including global shortcuts like `?`, so typed characters never leak
+    /// into board navigation.
+    /// Modes with a help overlay.
+    pub fn has_help(&self) -> bool {
+        matches!(
+            self,
+            UiMode::Board
+                | UiMode::Backlog
+                | UiMode::Agenda
+                | UiMode::AddTodo(_)
+                | UiMode::Detail(_)
+                | UiMode::Settings(_)
+        )
+    }
+
+    pub fn captures_text(&self) -> bool {
+        match self {
+            UiMode::AddTodo(_) | UiMode::EditTitle(_) => true,
+            UiMode::Detail(state) => state.editing.is_some(),
+            UiMode::Review(state) => state.date_input.is_some(),
+            UiMode::Board
+            | UiMode::Backlog
+            | UiMode::Agenda
+            | UiMode::Settings(_)
+            | UiMode::Welcome
+            | UiMode::DoneToday(_)
+            | UiMode::DayFull(_)
+            | UiMode::ProjectPicker(_)
+            | UiMode::Confirm(_) => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PrimaryView {
+    Board,
+    Backlog,
+    Agenda,
+}
+
+impl PrimaryView {
+    pub const ALL: [PrimaryView; 3] = [
+        PrimaryView::Board,
+        PrimaryView::Backlog,
+        PrimaryView::Agenda,
+    ];
+
+    pub fn next(self) -> Self {
+        match self {
+            PrimaryView::Board => PrimaryView::Backlog,
+            PrimaryView::Backlog => PrimaryView::Agenda,
+            PrimaryView::Agenda => PrimaryView::Board,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            PrimaryView::Board => PrimaryView::Agenda,
+            PrimaryView::Backlog => PrimaryView::Board,
+            PrimaryView::Agenda => PrimaryView::Backlog,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PrimaryView::Board => "Board",
+            PrimaryView::Backlog => "Backlog",
+            PrimaryView::Agenda => "Agenda",
+        }
+    }
+
+    /// The mode that shows this view.
+    pub fn mode(self) -> UiMode {
+        match self {
+            PrimaryView::Board => UiMode::Board,
+            PrimaryView::Backlog => UiMode::Backlog,
+            PrimaryView::Agenda => UiMode::Agenda,
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct SettingsState {
     pub week_start: WeekStart,
+    pub view_span: ViewSpan,
+    pub restore_session: bool,
+    pub selection_pulse: bool,
+    pub collapse_done: bool,
+    pub wrap_titles: bool,
+    pub show_note_preview: bool,
+    pub weekly_capacity: Option<u32>,
+    pub daily_capacity: Option<u32>,
+    pub done_backlog_column: Option<usize>,
+    pub confirm_deletes: bool,
+}
+
+#[derive(Clone)]
+pub struct ProjectPickerState {
+    /// Filters on offer with their labels: all, no project, then each
+    /// project that has todos.
+    pub options: Vec<(ProjectFilter, String)>,
+    pub index: usize,
+}
+
+/// What to do once the user picks a day in the capacity prompt.
+#[derive(Clone)]
+pub enum DayPlacement {
+    Move(Uuid),
+    Add(String),
+}
+
+#[derive(Clone)]
+pub struct DayFullState {
+    pub placement: DayPlacement,
+    pub full_day: NaiveDate,
+    pub pending: usize,
+    /// Next day in the same week with room, if any.
+    pub suggestion: Option<NaiveDate>,
+    pub return_to: PrimaryView,
+}
+
+/// What a confirmed `Confirm` prompt goes on to do.
+#[derive(Clone, Copy)]
+pub enum PendingAction {
+    Archive(Uuid),
+    /// Archive every todo in the multi-selection.
+    ArchiveSelected,
+    /// Schedule every pending todo in this backlog column for today.
+    MoveLaneToToday(usize),
+}
+
+#[derive(Clone)]
+pub struct ConfirmState {
+    pub prompt: String,
+    pub action: PendingAction,
+    pub return_to: PrimaryView,
+}
+
+#[derive(Clone)]
+pub struct EditTitleState {
+    pub id: Uuid,
+    pub input: String,
+    pub from_backlog: bool,
 }
 
 #[derive(Clone)]
@@ -33,6 +202,7 @@ pub enum DetailField {
     Title,
     Date,
     Status,
+    Url,
     Notes,
 }
 
@@ -41,7 +211,8 @@ impl DetailField {
         match self {
             Self::Title => Self::Date,
             Self::Date => Self::Status,
-            Self::Status => Self::Notes,
+            Self::Status => Self::Url,
+            Self::Url => Self::Notes,
             Self::Notes => Self::Notes,
         }
     }
@@ -51,7 +222,8 @@ impl DetailField {
             Self::Title => Self::Title,
             Self::Date => Self::Title,
             Self::Status => Self::Date,
-            Self::Notes => Self::Status,
+            Self::Url => Self::Status,
+            Self::Notes => Self::Url,
         }
     }
 
@@ -60,6 +232,7 @@ impl DetailField {
             Self::Title => "Title",
             Self::Date => "Date",
             Self::Status => "Status",
+            Self::Url => "Link",
             Self::Notes => "Notes",
         }
     }
@@ -75,10 +248,13 @@ pub struct DetailState {
     pub title: String,
     pub date: Option<NaiveDate>,
     pub status: String,
+    pub url: Option<String>,
     pub notes: String,
+    pub priority: i32,
     pub field: DetailField,
     pub editing: Option<String>,
-    pub from_backlog: bool,
+    pub return_to: PrimaryView,
+    pub hint: Option<String>,
 }
 
 impl DetailState {
@@ -90,7 +266,49 @@ impl DetailState {
                 .map(|d| d.format("%Y-%m-%d").to_string())
                 .unwrap_or_else(|| "none".to_string()),
             DetailField::Status => self.status.clone(),
+            DetailField::Url => self.url.clone().unwrap_or_else(|| "none".to_string()),
             DetailField::Notes => self.notes.clone(),
         }
     }
 }
+
+/// Guided backlog grooming: one pending backlog todo at a time.
+#[derive(Clone)]
+pub struct ReviewState {
+    pub queue: Vec<Uuid>,
+    pub index: usize,
+    pub title: String,
+    pub notes: String,
+    pub date_input: Option<String>,
+    pub hint: Option<String>,
+    /// `D` was pressed with `confirm_deletes` on; waiting for `y`.
+    pub confirm_archive: bool,
+    pub kept: usize,
+    pub scheduled: usize,
+    pub archived: usize,
+}
+
+impl ReviewState {
+    pub fn new(queue: Vec<Uuid>) -> Self {
+        Self {
+            queue,
+            index: 0,
+            title: String::new(),
+            notes: String::new(),
+            date_input: None,
+            hint: None,
+            confirm_archive: false,
+            kept: 0,
+            scheduled: 0,
+            archived: 0,
+        }
+    }
+
+    pub fn current(&self) -> Option<Uuid> {
+        self.queue.get(self.index).copied()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.queue.len()
+    }
+}
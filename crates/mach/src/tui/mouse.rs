@@ -0,0 +1,142 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use super::App;
+use super::cursor::Vertical;
+use super::modes::UiMode;
+
+/// Two clicks on the same row this close together open its details.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitColumn {
+    Day(usize),
+    Backlog(usize),
+}
+
+/// Where a board or backlog column was last drawn, so a click can be
+/// mapped back to the row under it.
+#[derive(Debug, Clone)]
+pub struct ColumnHit {
+    pub column: HitColumn,
+    /// The whole column, header included.
+    pub area: Rect,
+    /// Where the rows start, below any header or scroll hint.
+    pub content: Rect,
+    /// First row drawn.
+    pub offset: usize,
+    /// Lines each row takes up, one per row of the column.
+    pub heights: Vec<usize>,
+}
+
+impl ColumnHit {
+    /// The row drawn on line `y`, if any. The separator between two rows
+    /// belongs to neither.
+    fn row_at(&self, y: u16) -> Option<usize> {
+        if y < self.content.y || y >= self.content.bottom() {
+            return None;
+        }
+
+        let mut line = (y - self.content.y) as usize;
+
+        for (row, height) in self.heights.iter().enumerate().skip(self.offset) {
+            if line < *height {
+                return Some(row);
+            }
+
+            // Past this row and the separator below it.
+            line = line.checked_sub(height + 1)?;
+        }
+
+        None
+    }
+}
+
+impl App {
+    /// Left click focuses the column and row under the pointer (a second
+    /// click opens the todo); the wheel moves the cursor through the column
+    /// under it. Only the board and the backlog take the mouse, and not
+    /// while dragging a todo.
+    pub fn handle_mouse_event(&mut self, event: MouseEvent) {
+        if self.show_help || !matches!(self.ui_mode, UiMode::Board | UiMode::Backlog) {
+            return;
+        }
+
+        if self.cursor.selection.is_some() || self.backlog_cursor.selection.is_some() {
+            return;
+        }
+
+        let position = Position::new(event.column, event.row);
+
+        let Some(hit) = self
+            .hit_areas
+            .borrow()
+            .iter()
+            .find(|hit| hit.area.contains(position))
+            .cloned()
+        else {
+            return;
+        };
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let row = hit.row_at(event.row);
+
+                self.focus_hit(hit.column, row);
+
+                let now = Instant::now();
+                let double = row.is_some()
+                    && self.last_click.is_some_and(|(at, column, last_row)| {
+                        column == hit.column
+                            && Some(last_row) == row
+                            && now.duration_since(at) <= DOUBLE_CLICK
+                    });
+
+                if double {
+                    self.last_click = None;
+
+                    self.try_action(|app| match hit.column {
+                        HitColumn::Day(_) => app.open_detail_board(),
+                        HitColumn::Backlog(_) => app.open_detail_backlog(),
+                    });
+                } else {
+                    self.last_click = row.map(|row| (now, hit.column, row));
+                }
+            }
+            MouseEventKind::ScrollDown => self.scroll_hit(hit.column, Vertical::Down),
+            MouseEventKind::ScrollUp => self.scroll_hit(hit.column, Vertical::Up),
+            _ => {}
+        }
+    }
+
+    fn focus_hit(&mut self, column: HitColumn, row: Option<usize>) {
+        match column {
+            HitColumn::Day(idx) => {
+                let row =
+                    row.unwrap_or_else(|| self.cursor.day_rows.get(idx).copied().unwrap_or(0));
+
+                self.cursor.set_focus_row(idx, row);
+            }
+            HitColumn::Backlog(idx) => {
+                self.backlog_cursor.column = idx;
+
+                if let Some(row) = row {
+                    self.backlog_cursor.rows[idx] = row;
+                }
+            }
+        }
+    }
+
+    /// The wheel scrolls by moving the cursor, since drawing keeps the
+    /// cursor row on screen.
+    fn scroll_hit(&mut self, column: HitColumn, dir: Vertical) {
+        self.focus_hit(column, None);
+
+        match column {
+            HitColumn::Day(_) => self.cursor.move_vertical(dir, &self.board),
+            HitColumn::Backlog(_) => self.backlog_cursor.move_vertical(dir, &self.board),
+        }
+    }
+}
@@ -1,14 +1,162 @@
-#![allow(dead_code)]
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use ratatui::style::Color;
 
-// Text
-pub const TEXT: Color = Color::Reset;
-pub const TEXT_DIM: Color = Color::DarkGray;
+/// Names accepted by the `theme` setting.
+pub const THEMES: &[&str] = &["default", "high-contrast", "mono"];
+
+/// Colors the TUI draws with, picked by the `theme` setting and adjusted by
+/// `theme_colors`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    // Text
+    pub text: Color,
+    pub text_dim: Color,
+
+    // States (hierarchy: accent > active > focus)
+    pub focus: Color,
+    pub active: Color,
+    pub accent: Color,
+
+    // Priority markers (!, !!, !!!)
+    pub priority_low: Color,
+    pub priority_medium: Color,
+    pub priority_high: Color,
+
+    // Chrome
+    pub border: Color,
+    /// Failed actions in the status bar.
+    pub error: Color,
+    /// Pending todos left on days that have passed.
+    pub overdue: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            text: Color::Reset,
+            text_dim: Color::DarkGray,
+            focus: Color::LightBlue,
+            active: Color::Yellow,
+            accent: Color::Magenta,
+            priority_low: Color::LightBlue,
+            priority_medium: Color::Yellow,
+            priority_high: Color::LightRed,
+            border: Color::DarkGray,
+            error: Color::LightRed,
+            overdue: Color::Red,
+        }
+    }
+}
+
+impl Palette {
+    /// Brighter colors and a lighter dim gray for low-contrast terminals.
+    pub fn high_contrast() -> Self {
+        Self {
+            text: Color::White,
+            text_dim: Color::Gray,
+            focus: Color::LightCyan,
+            active: Color::LightYellow,
+            accent: Color::LightMagenta,
+            priority_low: Color::LightGreen,
+            priority_medium: Color::LightYellow,
+            priority_high: Color::LightRed,
+            border: Color::Gray,
+            error: Color::LightRed,
+            overdue: Color::LightRed,
+        }
+    }
+
+    /// Shades of gray only, safe on 16-color and monochrome terminals; the
+    /// bold and reversed styles still tell states apart.
+    pub fn mono() -> Self {
+        Self {
+            text: Color::Reset,
+            text_dim: Color::DarkGray,
+            focus: Color::White,
+            active: Color::White,
+            accent: Color::White,
+            priority_low: Color::Reset,
+            priority_medium: Color::Reset,
+            priority_high: Color::White,
+            border: Color::DarkGray,
+            error: Color::White,
+            overdue: Color::White,
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "default" => Some(Self::default()),
+            "high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+            "mono" => Some(Self::mono()),
+            _ => None,
+        }
+    }
+
+    /// The `theme` palette with `colors` (role name to color, e.g.
+    /// `{"focus": "cyan", "border": "#444444"}`) applied on top. An unknown
+    /// theme falls back to the default and unusable colors are skipped;
+    /// both are described in the returned warnings.
+    pub fn from_config(
+        theme: Option<&str>,
+        colors: &BTreeMap<String, String>,
+    ) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+
+        let mut palette = match theme {
+            Some(name) => Self::named(name).unwrap_or_else(|| {
+                warnings.push(format!(
+                    "theme: unknown theme '{name}', using default (one of: {})",
+                    THEMES.join(", ")
+                ));
+
+                Self::default()
+            }),
+            None => Self::default(),
+        };
+
+        for (role, value) in colors {
+            let Ok(color) = Color::from_str(value.trim()) else {
+                warnings.push(format!("theme_colors: unknown color '{value}' for {role}"));
+                continue;
+            };
+
+            let Some(slot) = palette.role_mut(role) else {
+                warnings.push(format!("theme_colors: unknown color role '{role}'"));
+                continue;
+            };
+
+            *slot = color;
+        }
+
+        (palette, warnings)
+    }
 
-// States (hierarchy: ACCENT > ACTIVE > FOCUS)
-pub const FOCUS: Color = Color::LightBlue;
-pub const ACTIVE: Color = Color::Yellow;
-pub const ACCENT: Color = Color::Magenta;
+    /// Color for a priority marker of `level` (1 to 3).
+    pub fn priority(&self, level: i32) -> Color {
+        match level {
+            1 => self.priority_low,
+            2 => self.priority_medium,
+            _ => self.priority_high,
+        }
+    }
 
-// Chrome
-pub const BORDER: Color = Color::DarkGray;
+    fn role_mut(&mut self, role: &str) -> Option<&mut Color> {
+        match role.trim().to_lowercase().replace('-', "_").as_str() {
+            "text" => Some(&mut self.text),
+            "text_dim" => Some(&mut self.text_dim),
+            "focus" => Some(&mut self.focus),
+            "active" => Some(&mut self.active),
+            "accent" => Some(&mut self.accent),
+            "priority_low" => Some(&mut self.priority_low),
+            "priority_medium" => Some(&mut self.priority_medium),
+            "priority_high" => Some(&mut self.priority_high),
+            "border" => Some(&mut self.border),
+            "error" => Some(&mut self.error),
+            "overdue" => Some(&mut self.overdue),
+            _ => None,
+        }
+    }
+}
@@ -1,40 +1,63 @@
+use std::collections::HashMap;
+
 use chrono::{Datelike, Duration as ChronoDuration, NaiveDate};
 use ratatui::style::{Modifier, Style};
-use ratatui::text::Line;
+use ratatui::text::{Line, Span};
 use uuid::Uuid;
 
 use crate::entity::todo;
-use crate::service::config::WeekStart;
-
-use super::palette;
+use crate::service::config::{ViewSpan, WeekStart};
+pub use crate::service::todo::BACKLOG_COLUMNS;
+use crate::service::todo::MAX_PRIORITY;
 
-pub const BACKLOG_COLUMNS: usize = 4;
+use super::palette::Palette;
 
 pub struct WeekState {
+    /// Date of the first column.
     pub week_start: NaiveDate,
+    pub span: ViewSpan,
     pub columns: Vec<ColumnMeta>,
 }
 
 impl WeekState {
-    pub fn new(today: NaiveDate, preference: WeekStart) -> Self {
-        let week_start = start_of_week(today, preference);
+    /// The page of `span` days that holds `today`. A weekday view opened on a
+    /// weekend shows the coming week.
+    pub fn new(today: NaiveDate, preference: WeekStart, span: ViewSpan) -> Self {
+        let week_start = match span {
+            ViewSpan::Week => start_of_week(today, preference),
+            ViewSpan::Weekdays => {
+                let workday = match today.weekday() {
+                    chrono::Weekday::Sat => today + ChronoDuration::days(2),
+                    chrono::Weekday::Sun => today + ChronoDuration::days(1),
+                    _ => today,
+                };
+
+                start_of_week(workday, WeekStart::Monday)
+            }
+            ViewSpan::Days(_) => today,
+        };
 
         Self {
             week_start,
-            columns: build_columns(week_start),
+            span,
+            columns: build_columns(week_start, span.day_count()),
         }
     }
 
     pub fn prev_week(&mut self) {
-        self.week_start -= ChronoDuration::days(7);
-
-        self.columns = build_columns(self.week_start);
+        self.shift_weeks(-1);
     }
 
     pub fn next_week(&mut self) {
-        self.week_start += ChronoDuration::days(7);
+        self.shift_weeks(1);
+    }
 
-        self.columns = build_columns(self.week_start);
+    /// Move `delta` pages forward (or back when negative). A page is a week,
+    /// or the span's length for a `Days` view.
+    pub fn shift_weeks(&mut self, delta: i32) {
+        self.week_start += ChronoDuration::days(self.span.page_days() * delta as i64);
+
+        self.columns = build_columns(self.week_start, self.span.day_count());
     }
 
     pub fn column_index(&self, date: NaiveDate) -> Option<usize> {
@@ -50,6 +73,12 @@ pub struct ColumnMeta {
 
 pub struct BoardData {
     pub days: Vec<Vec<TodoView>>,
+    /// Done todos folded out of each day; when non-zero the day ends with one
+    /// extra navigable summary row.
+    pub hidden_done: Vec<usize>,
+    /// Done todos left off each day entirely while done todos are hidden.
+    /// Only the header counts see them.
+    pub omitted_done: Vec<usize>,
     pub backlog_columns: [Vec<TodoView>; BACKLOG_COLUMNS],
 }
 
@@ -57,12 +86,16 @@ impl BoardData {
     pub fn new(num_days: usize) -> Self {
         Self {
             days: vec![Vec::new(); num_days],
+            hidden_done: vec![0; num_days],
+            omitted_done: vec![0; num_days],
             backlog_columns: Default::default(),
         }
     }
 
     pub fn reset(&mut self, num_days: usize) {
         self.days = vec![Vec::new(); num_days];
+        self.hidden_done = vec![0; num_days];
+        self.omitted_done = vec![0; num_days];
 
         for col in &mut self.backlog_columns {
             col.clear();
@@ -75,10 +108,67 @@ impl BoardData {
         }
 
         self.days[idx] = todos;
+        self.set_hidden_done(idx, 0);
+
+        if idx >= self.omitted_done.len() {
+            self.omitted_done.resize(idx + 1, 0);
+        }
+
+        self.omitted_done[idx] = 0;
+    }
+
+    /// Like `set_day`, but replaces the done todos with a count line.
+    pub fn set_day_collapsed(&mut self, idx: usize, todos: Vec<TodoView>) {
+        let (done, pending): (Vec<_>, Vec<_>) =
+            todos.into_iter().partition(|todo| todo.status == "done");
+
+        self.set_day(idx, pending);
+        self.set_hidden_done(idx, done.len());
+    }
+
+    /// Like `set_day`, but leaves the done todos out without a trace on the
+    /// board beyond the header counts.
+    pub fn set_day_without_done(&mut self, idx: usize, todos: Vec<TodoView>) {
+        let (done, pending): (Vec<_>, Vec<_>) =
+            todos.into_iter().partition(|todo| todo.status == "done");
+
+        self.set_day(idx, pending);
+        self.omitted_done[idx] = done.len();
+    }
+
+    fn set_hidden_done(&mut self, idx: usize, count: usize) {
+        if idx >= self.hidden_done.len() {
+            self.hidden_done.resize(idx + 1, 0);
+        }
+
+        self.hidden_done[idx] = count;
     }
 
     pub fn day_len(&self, idx: usize) -> usize {
-        self.days.get(idx).map(|d| d.len()).unwrap_or(0)
+        let todos = self.days.get(idx).map(|d| d.len()).unwrap_or(0);
+
+        todos + usize::from(self.hidden_done_in(idx) > 0)
+    }
+
+    /// Done and total todos on day `idx`, counting folded and hidden done
+    /// todos, or `None` for an empty day.
+    pub fn day_counts(&self, idx: usize) -> Option<(usize, usize)> {
+        let todos = self.days.get(idx)?;
+        let hidden = self.hidden_done_in(idx) + self.omitted_done.get(idx).copied().unwrap_or(0);
+
+        let done = todos.iter().filter(|todo| todo.status == "done").count() + hidden;
+        let total = todos.len() + hidden;
+
+        (total > 0).then_some((done, total))
+    }
+
+    pub fn hidden_done_in(&self, idx: usize) -> usize {
+        self.hidden_done.get(idx).copied().unwrap_or(0)
+    }
+
+    /// Row of the collapsed-done summary line, if the day has one.
+    pub fn summary_row(&self, idx: usize) -> Option<usize> {
+        (self.hidden_done_in(idx) > 0).then(|| self.days.get(idx).map_or(0, |d| d.len()))
     }
 
     pub fn day_todo_id_at(&self, col: usize, row: usize) -> Option<Uuid> {
@@ -144,64 +234,356 @@ impl BoardData {
     }
 }
 
+/// Days the agenda lists, starting today.
+pub const AGENDA_DAYS: i64 = 14;
+
+/// A todo in the agenda together with the day it is scheduled for.
+#[derive(Clone)]
+pub struct AgendaItem {
+    pub date: NaiveDate,
+    pub todo: TodoView,
+}
+
+/// The agenda's todos in day order. Date headers are added while drawing.
+#[derive(Default)]
+pub struct AgendaData {
+    pub items: Vec<AgendaItem>,
+}
+
+impl AgendaData {
+    pub fn position_of(&self, id: Uuid) -> Option<usize> {
+        self.items.iter().position(|item| item.todo.id == id)
+    }
+
+    pub fn status_of(&self, id: Uuid) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|item| item.todo.id == id)
+            .map(|item| item.todo.status.as_str())
+    }
+}
+
+/// Names behind the active board scoping, resolved whenever it changes.
+#[derive(Clone, Default)]
+pub struct ScopeLabels {
+    pub workspace: Option<String>,
+    pub project: Option<String>,
+}
+
+impl ScopeLabels {
+    pub fn is_scoped(&self) -> bool {
+        self.workspace.is_some() || self.project.is_some()
+    }
+}
+
+/// Titles keep at least this many columns before the project tag is dropped.
+const MIN_TITLE_WIDTH: usize = 8;
+
 #[derive(Clone)]
 pub struct TodoView {
     pub id: Uuid,
     pub title: String,
     pub status: String,
+    pub has_link: bool,
+    /// Name of the todo's project, shown as a dim ` [name]` suffix.
+    pub project: Option<String>,
+    pub priority: i32,
+    /// Notes, when there are any besides whitespace; their first line can be
+    /// previewed under the title.
+    pub notes: Option<String>,
 }
 
 impl TodoView {
-    pub fn to_line_with_prefix(&self, selected: bool) -> Line<'_> {
-        let text = if selected {
+    /// Build a view of `model`, naming its project from `project_names`.
+    pub fn new(model: todo::Model, project_names: &HashMap<Uuid, String>) -> Self {
+        Self {
+            id: model.id,
+            project: model
+                .project_id
+                .and_then(|id| project_names.get(&id).cloned()),
+            title: model.title,
+            status: model.status,
+            has_link: model.url.is_some(),
+            priority: model.priority,
+            notes: model.notes.filter(|notes| !notes.trim().is_empty()),
+        }
+    }
+
+    /// First non-blank line of the notes, dimmed, indented and cut to
+    /// `width` columns, for the board's note preview.
+    pub fn note_preview(&self, width: u16, palette: &Palette) -> Option<Line<'static>> {
+        let first = self
+            .notes
+            .as_deref()?
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())?;
+
+        let room = (width as usize).saturating_sub(2);
+
+        let text = if Span::raw(first).width() > room {
+            let mut cut: String = first.chars().take(room.saturating_sub(1)).collect();
+            cut.push('…');
+            cut
+        } else {
+            first.to_string()
+        };
+
+        Some(
+            Line::from(format!("  {text}")).style(
+                Style::default()
+                    .fg(palette.text_dim)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        )
+    }
+
+    /// The todo as one line of at most `width` columns, led by its priority
+    /// marker. A long title is cut with `…`, and the project tag goes first
+    /// when even that won't fit.
+    pub fn to_line_with_prefix(&self, selected: bool, width: u16, palette: &Palette) -> Line<'_> {
+        let marker = if self.priority > 0 {
+            priority_marker(self.priority, palette)
+        } else {
+            Span::raw("")
+        };
+
+        let width =
+            (width as usize).saturating_sub(marker.width() + usize::from(self.priority > 0));
+
+        let mut title = if selected {
             format!("› {}", self.title)
         } else {
             self.title.clone()
         };
 
-        let mut line = Line::from(text);
+        let link = if self.has_link { " 🔗" } else { "" };
+        let link_width = Span::raw(link).width();
 
-        if self.status == "done" {
-            line.style = Style::default()
-                .fg(palette::TEXT_DIM)
-                .add_modifier(Modifier::CROSSED_OUT | Modifier::DIM);
-        } else {
-            line.style = Style::default().fg(palette::TEXT);
+        let mut tag = self
+            .project
+            .as_ref()
+            .map(|name| format!(" [{name}]"))
+            .unwrap_or_default();
+
+        if Span::raw(title.as_str()).width() + link_width + tag.chars().count() > width
+            && width.saturating_sub(link_width + tag.chars().count()) <= MIN_TITLE_WIDTH
+        {
+            tag.clear();
+        }
+
+        let room = width.saturating_sub(link_width + tag.chars().count());
+
+        if Span::raw(title.as_str()).width() > room {
+            title = title.chars().take(room.saturating_sub(1)).collect();
+            title.push('…');
         }
 
+        title.push_str(link);
+
+        // The marker sits between the selection pointer and the title.
+        let (pointer, title) = match title.strip_prefix("› ") {
+            Some(rest) if selected => ("› ", rest.to_string()),
+            _ => ("", title),
+        };
+
+        let gap = if self.priority > 0 { " " } else { "" };
+
+        let mut line = Line::from(vec![
+            Span::raw(pointer),
+            marker,
+            Span::raw(gap),
+            Span::raw(title),
+            Span::styled(tag, Style::default().fg(palette.text_dim)),
+        ]);
+
+        line.style = self.base_style(palette);
+
         line
     }
+
+    /// The todo as one or more lines of at most `width` columns. With `wrap`
+    /// the title breaks at word boundaries, continuation lines indented past
+    /// the pointer and priority marker; without it this is the single cut-off
+    /// line of `to_line_with_prefix`.
+    pub fn to_lines(
+        &self,
+        selected: bool,
+        width: u16,
+        wrap: bool,
+        palette: &Palette,
+    ) -> Vec<Line<'_>> {
+        if !wrap {
+            return vec![self.to_line_with_prefix(selected, width, palette)];
+        }
+
+        let pointer = if selected { "› " } else { "" };
+
+        let marker = if self.priority > 0 {
+            priority_marker(self.priority, palette)
+        } else {
+            Span::raw("")
+        };
+
+        let gap = if self.priority > 0 { " " } else { "" };
+
+        let indent = Span::raw(pointer).width() + marker.width() + gap.len();
+        let room = (width as usize).saturating_sub(indent).max(1);
+
+        let mut title = self.title.clone();
+
+        if self.has_link {
+            title.push_str(" 🔗");
+        }
+
+        let mut rows = wrap_words(&title, room);
+
+        let tag = self
+            .project
+            .as_ref()
+            .map(|name| format!(" [{name}]"))
+            .unwrap_or_default();
+
+        // The project tag follows the title, on a line of its own when the
+        // last one is full, and is dropped when it can't fit even there.
+        let tag_width = Span::raw(tag.as_str()).width();
+        let last_width = rows.last().map_or(0, |row| Span::raw(row.as_str()).width());
+
+        let tag_line = if tag.is_empty() {
+            None
+        } else if last_width + tag_width <= room {
+            Some(rows.len() - 1)
+        } else if tag_width.saturating_sub(1) <= room {
+            rows.push(String::new());
+
+            Some(rows.len() - 1)
+        } else {
+            None
+        };
+
+        let style = self.base_style(palette);
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut spans = if i == 0 {
+                    vec![Span::raw(pointer), marker.clone(), Span::raw(gap)]
+                } else {
+                    vec![Span::raw(" ".repeat(indent))]
+                };
+
+                if tag_line == Some(i) && row.is_empty() {
+                    spans.push(Span::styled(
+                        tag.trim_start().to_string(),
+                        Style::default().fg(palette.text_dim),
+                    ));
+                } else {
+                    spans.push(Span::raw(row));
+
+                    if tag_line == Some(i) {
+                        spans.push(Span::styled(
+                            tag.clone(),
+                            Style::default().fg(palette.text_dim),
+                        ));
+                    }
+                }
+
+                Line::from(spans).style(style)
+            })
+            .collect()
+    }
+
+    fn base_style(&self, palette: &Palette) -> Style {
+        if self.status == "done" {
+            Style::default()
+                .fg(palette.text_dim)
+                .add_modifier(Modifier::CROSSED_OUT | Modifier::DIM)
+        } else {
+            Style::default().fg(palette.text)
+        }
+    }
 }
 
-impl From<todo::Model> for TodoView {
-    fn from(model: todo::Model) -> Self {
-        Self {
-            id: model.id,
-            title: model.title,
-            status: model.status,
+/// Break `text` into lines of at most `width` columns at spaces, splitting
+/// words that are wider than a whole line. Always returns at least one line.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let word_width = Span::raw(word).width();
+        let current_width = Span::raw(current.as_str()).width();
+
+        if !current.is_empty() && current_width + 1 + word_width <= width {
+            current.push(' ');
+            current.push_str(word);
+
+            continue;
+        }
+
+        if !current.is_empty() {
+            rows.push(std::mem::take(&mut current));
+        }
+
+        if word_width <= width {
+            current.push_str(word);
+
+            continue;
+        }
+
+        for ch in word.chars() {
+            let ch_width = Span::raw(ch.to_string()).width();
+
+            if !current.is_empty() && Span::raw(current.as_str()).width() + ch_width > width {
+                rows.push(std::mem::take(&mut current));
+            }
+
+            current.push(ch);
         }
     }
+
+    rows.push(current);
+
+    rows
 }
 
-fn build_columns(week_start: NaiveDate) -> Vec<ColumnMeta> {
-    let mut cols = Vec::with_capacity(7);
+/// `!`, `!!` or `!!!` in the priority's color, capped at `MAX_PRIORITY`.
+pub fn priority_marker(priority: i32, palette: &Palette) -> Span<'static> {
+    let level = priority.clamp(1, MAX_PRIORITY);
 
-    for offset in 0..7 {
-        let date = week_start + ChronoDuration::days(offset);
+    Span::styled(
+        "!".repeat(level as usize),
+        Style::default()
+            .fg(palette.priority(level))
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+fn build_columns(week_start: NaiveDate, day_count: usize) -> Vec<ColumnMeta> {
+    let mut cols = Vec::with_capacity(day_count);
 
-        let title = format!(
-            "{} {:02}/{:02}",
-            weekday_label(date.weekday()),
-            date.month(),
-            date.day()
-        );
+    for offset in 0..day_count as i64 {
+        let date = week_start + ChronoDuration::days(offset);
 
-        cols.push(ColumnMeta { title, date });
+        cols.push(ColumnMeta {
+            title: day_title(date),
+            date,
+        });
     }
 
     cols
 }
 
+/// `Mon 10/12`: how a day is named above its column or agenda group.
+pub fn day_title(date: NaiveDate) -> String {
+    format!(
+        "{} {:02}/{:02}",
+        weekday_label(date.weekday()),
+        date.month(),
+        date.day()
+    )
+}
+
 fn weekday_label(day: chrono::Weekday) -> &'static str {
     match day {
         chrono::Weekday::Mon => "Mon",
@@ -215,12 +597,5 @@ fn weekday_label(day: chrono::Weekday) -> &'static str {
 }
 
 pub fn start_of_week(date: NaiveDate, preference: WeekStart) -> NaiveDate {
-    let weekday = date.weekday();
-
-    let offset = match preference {
-        WeekStart::Sunday => weekday.num_days_from_sunday() as i64,
-        WeekStart::Monday => weekday.num_days_from_monday() as i64,
-    };
-
-    date - ChronoDuration::days(offset)
+    preference.week_of(date)
 }
@@ -1,6 +1,7 @@
 use std::io;
 
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -14,7 +15,7 @@ pub fn setup_terminal() -> miette::Result<Terminal<CrosstermBackend<io::Stdout>>
 
     let mut stdout = io::stdout();
 
-    execute!(stdout, EnterAlternateScreen)
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
         .into_diagnostic()
         .wrap_err("failed to enter alternate screen")?;
 
@@ -33,6 +34,6 @@ impl Drop for TerminalGuard {
 
         let mut stdout = io::stdout();
 
-        let _ = execute!(stdout, LeaveAlternateScreen);
+        let _ = execute!(stdout, DisableMouseCapture, LeaveAlternateScreen);
     }
 }
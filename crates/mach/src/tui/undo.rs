@@ -0,0 +1,31 @@
+use crate::entity::todo;
+
+/// Most actions `u` can walk back; older ones fall off.
+const UNDO_LIMIT: usize = 20;
+
+/// A TUI change `u` can reverse, holding the todo as it was beforehand.
+pub enum UndoAction {
+    /// Put a moved, completed or archived todo back where, and how, it was.
+    Update(Box<todo::Model>),
+    /// Undo a batch operation on the multi-selection in one step.
+    Batch(Vec<todo::Model>),
+}
+
+#[derive(Default)]
+pub struct UndoStack {
+    actions: Vec<UndoAction>,
+}
+
+impl UndoStack {
+    pub fn push(&mut self, action: UndoAction) {
+        if self.actions.len() == UNDO_LIMIT {
+            self.actions.remove(0);
+        }
+
+        self.actions.push(action);
+    }
+
+    pub fn pop(&mut self) -> Option<UndoAction> {
+        self.actions.pop()
+    }
+}
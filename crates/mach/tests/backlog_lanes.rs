@@ -0,0 +1,157 @@
+use chrono::NaiveDate;
+use machich::service::{
+    config::ConfigService,
+    connection::init_memory_database,
+    todo::{ListScope, MovePlacement, TodoService},
+};
+use uuid::Uuid;
+
+async fn lane_todo(todos: &TodoService, title: &str, lane: i64) -> Uuid {
+    let todo = todos
+        .add(title, None, None, None, None, None)
+        .await
+        .unwrap();
+
+    todos.set_backlog_column(todo.id, lane).await.unwrap();
+
+    todo.id
+}
+
+async fn lane_of(todos: &TodoService, id: Uuid) -> i64 {
+    todos.get(id).await.unwrap().backlog_column
+}
+
+#[tokio::test]
+async fn moving_a_lane_right_shifts_the_ones_it_passes() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+
+    let first = lane_todo(&todos, "first", 0).await;
+    let second = lane_todo(&todos, "second", 1).await;
+    let third = lane_todo(&todos, "third", 2).await;
+    let fourth = lane_todo(&todos, "fourth", 3).await;
+
+    todos.move_backlog_column(0, 2).await.unwrap();
+
+    assert_eq!(lane_of(&todos, first).await, 2);
+    assert_eq!(lane_of(&todos, second).await, 0);
+    assert_eq!(lane_of(&todos, third).await, 1);
+    assert_eq!(lane_of(&todos, fourth).await, 3);
+}
+
+#[tokio::test]
+async fn moving_a_lane_left_shifts_the_ones_it_passes() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+
+    let first = lane_todo(&todos, "first", 0).await;
+    let second = lane_todo(&todos, "second", 1).await;
+    let fourth = lane_todo(&todos, "fourth", 3).await;
+
+    todos.move_backlog_column(3, 0).await.unwrap();
+
+    assert_eq!(lane_of(&todos, fourth).await, 0);
+    assert_eq!(lane_of(&todos, first).await, 1);
+    assert_eq!(lane_of(&todos, second).await, 2);
+}
+
+#[tokio::test]
+async fn every_item_of_a_lane_moves_and_scheduled_todos_stay() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+
+    let a = lane_todo(&todos, "a", 1).await;
+    let b = lane_todo(&todos, "b", 1).await;
+    let scheduled = todos
+        .add(
+            "on a day",
+            NaiveDate::from_ymd_opt(2026, 6, 1),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    todos.move_backlog_column(1, 0).await.unwrap();
+
+    assert_eq!(lane_of(&todos, a).await, 0);
+    assert_eq!(lane_of(&todos, b).await, 0);
+    assert_eq!(lane_of(&todos, scheduled.id).await, 0);
+
+    todos.move_backlog_column(0, 1).await.unwrap();
+
+    assert_eq!(lane_of(&todos, a).await, 1);
+    assert_eq!(lane_of(&todos, scheduled.id).await, 0);
+}
+
+#[tokio::test]
+async fn labels_follow_their_lane() {
+    let config = ConfigService::new(init_memory_database().await.unwrap());
+
+    config.save_backlog_label(0, "Ideas").await.unwrap();
+    config.save_backlog_label(1, "Errands").await.unwrap();
+
+    config.swap_backlog_labels(0, 1).await.unwrap();
+
+    let labels = config.load_backlog_labels().await.unwrap();
+
+    assert_eq!(labels[0], "Errands");
+    assert_eq!(labels[1], "Ideas");
+
+    // An unset title stays unset, so it reads as its new position.
+    config.swap_backlog_labels(1, 2).await.unwrap();
+
+    let labels = config.load_backlog_labels().await.unwrap();
+
+    assert_eq!(labels[1], "Column 2");
+    assert_eq!(labels[2], "Ideas");
+}
+
+#[tokio::test]
+async fn a_finished_lane_todo_comes_back_to_the_first_lane() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+
+    let id = lane_todo(&todos, "errand", 2).await;
+
+    let done = todos.mark_done(id, today).await.unwrap();
+
+    assert_eq!(done.scheduled_for, Some(today));
+    assert_eq!(done.backlog_column, 0);
+
+    todos.mark_pending(id, false).await.unwrap();
+
+    let back = todos
+        .move_to_scope(id, ListScope::Backlog, MovePlacement::Bottom)
+        .await
+        .unwrap();
+
+    assert_eq!(back.scheduled_for, None);
+    assert_eq!(back.backlog_column, 0);
+}
+
+#[tokio::test]
+async fn scheduling_a_lane_todo_clears_its_lane() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let today = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+
+    let moved = lane_todo(&todos, "moved", 1).await;
+    let swept = lane_todo(&todos, "swept", 2).await;
+
+    todos
+        .move_to_scope(moved, ListScope::Day(today), MovePlacement::Top)
+        .await
+        .unwrap();
+    todos
+        .move_all(
+            ListScope::Backlog,
+            ListScope::Day(today),
+            Some(2),
+            MovePlacement::Bottom,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(lane_of(&todos, moved).await, 0);
+    assert_eq!(lane_of(&todos, swept).await, 0);
+    assert_eq!(todos.get(swept).await.unwrap().scheduled_for, Some(today));
+}
@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use machich::service::{
+    config::{ConfigService, WeekStart},
+    connection::{init_database, init_memory_database},
+};
+use uuid::Uuid;
+
+/// A database file of its own under the temp dir, removed on drop.
+struct TempDb(PathBuf);
+
+impl TempDb {
+    fn new() -> Self {
+        Self(std::env::temp_dir().join(format!("mach-test-{}.db", Uuid::new_v4())))
+    }
+
+    async fn open(&self) -> ConfigService {
+        ConfigService::new(init_database(&self.0).await.unwrap())
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[tokio::test]
+async fn settings_survive_reopening_the_database() {
+    let db = TempDb::new();
+
+    let config = db.open().await;
+
+    config.set("theme", "solarized").await.unwrap();
+    config.set("visible_days", "5").await.unwrap();
+    config.set_as("favourites", &vec![1, 2, 3]).await.unwrap();
+    config.save_week_start(WeekStart::Sunday).await.unwrap();
+
+    drop(config);
+
+    let config = db.open().await;
+
+    assert_eq!(
+        config.get("theme").await.unwrap().as_deref(),
+        Some("solarized")
+    );
+    assert_eq!(config.get_as::<u32>("visible_days").await.unwrap(), Some(5));
+    assert_eq!(
+        config.get_as::<Vec<u32>>("favourites").await.unwrap(),
+        Some(vec![1, 2, 3])
+    );
+    assert_eq!(config.load_week_start().await.unwrap(), WeekStart::Sunday);
+}
+
+#[tokio::test]
+async fn setting_a_key_again_overwrites_it() {
+    let config = ConfigService::new(init_memory_database().await.unwrap());
+
+    config.set("theme", "light").await.unwrap();
+    config.set("theme", "dark").await.unwrap();
+
+    assert_eq!(config.get("theme").await.unwrap().as_deref(), Some("dark"));
+    assert_eq!(config.get("missing").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn typed_helpers_share_the_generic_store() {
+    let config = ConfigService::new(init_memory_database().await.unwrap());
+
+    config.set("confirm_deletes", "false").await.unwrap();
+
+    assert!(!config.load_confirm_deletes().await.unwrap());
+
+    config.save_week_start(WeekStart::Monday).await.unwrap();
+
+    assert_eq!(
+        config.get("week_start").await.unwrap().as_deref(),
+        Some(WeekStart::Monday.as_str())
+    );
+
+    // Text that isn't JSON is kept as a string, and a wrong shape reads as unset.
+    config.set("theme", "dark mode").await.unwrap();
+
+    assert_eq!(config.get_as::<u32>("theme").await.unwrap(), None);
+}
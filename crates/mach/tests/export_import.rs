@@ -0,0 +1,184 @@
+use chrono::NaiveDate;
+use machich::entity::todo;
+use machich::service::{
+    connection::init_memory_database,
+    recurrence::Recurrence,
+    todo::{ImportMode, TodoService},
+    workspace::WorkspaceService,
+};
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+}
+
+/// What `mach export --done` writes: every todo, as JSON.
+async fn export(todos: &TodoService) -> String {
+    let all = todos.list_all(true, None).await.unwrap();
+
+    serde_json::to_string_pretty(&all).unwrap()
+}
+
+/// What `mach import` reads back.
+fn parse(json: &str) -> Vec<todo::Model> {
+    serde_json::from_str(json).unwrap()
+}
+
+async fn seed(todos: &TodoService) {
+    let scheduled = todos
+        .add(
+            "Write report",
+            Some(day(1)),
+            Some("draft first".into()),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    todos.update_priority(scheduled.id, 2).await.unwrap();
+    todos.set_tags(scheduled.id, &["work"]).await.unwrap();
+    todos
+        .update_url(scheduled.id, Some("https://example.com".into()))
+        .await
+        .unwrap();
+
+    let done = todos
+        .add("Call mom", Some(day(2)), None, None, None, None)
+        .await
+        .unwrap();
+    todos.mark_done(done.id, day(2)).await.unwrap();
+
+    todos
+        .add(
+            "Water plants",
+            Some(day(3)),
+            None,
+            None,
+            None,
+            Some(Recurrence::Weekly),
+        )
+        .await
+        .unwrap();
+
+    todos
+        .add("Someday", None, None, None, None, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn export_then_import_into_a_fresh_database_round_trips() {
+    let source = TodoService::new(init_memory_database().await.unwrap());
+    seed(&source).await;
+
+    let json = export(&source).await;
+
+    let target = TodoService::new(init_memory_database().await.unwrap());
+    let summary = target
+        .import(parse(&json), ImportMode::Merge)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.imported, 4);
+    assert_eq!(summary.skipped, 0);
+    assert_eq!(
+        target.list_all(true, None).await.unwrap(),
+        source.list_all(true, None).await.unwrap()
+    );
+    assert_eq!(export(&target).await, json);
+}
+
+#[tokio::test]
+async fn merge_skips_todos_that_are_already_there() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    seed(&todos).await;
+
+    let before = todos.list_all(true, None).await.unwrap();
+    let json = export(&todos).await;
+
+    let summary = todos.import(parse(&json), ImportMode::Merge).await.unwrap();
+
+    assert_eq!(summary.imported, 0);
+    assert_eq!(summary.skipped, before.len());
+    assert_eq!(todos.list_all(true, None).await.unwrap(), before);
+}
+
+#[tokio::test]
+async fn replace_puts_the_export_back_exactly() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    seed(&todos).await;
+
+    let before = todos.list_all(true, None).await.unwrap();
+    let json = export(&todos).await;
+
+    for model in &before {
+        todos.delete(model.id).await.unwrap();
+    }
+    todos
+        .add(
+            "Added after the export",
+            Some(day(4)),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    todos
+        .import(parse(&json), ImportMode::Replace)
+        .await
+        .unwrap();
+
+    assert_eq!(todos.list_all(true, None).await.unwrap(), before);
+}
+
+#[tokio::test]
+async fn import_keeps_workspaces_when_they_exist() {
+    let conn = init_memory_database().await.unwrap();
+    let todos = TodoService::new(conn.clone());
+    let workspaces = WorkspaceService::new(conn);
+
+    let work = workspaces.create("work").await.unwrap();
+    todos
+        .add("Standup", Some(day(1)), None, Some(work.id), None, None)
+        .await
+        .unwrap();
+
+    let json = export(&todos).await;
+    let before = todos.list_all(true, None).await.unwrap();
+
+    todos
+        .import(parse(&json), ImportMode::Replace)
+        .await
+        .unwrap();
+
+    assert_eq!(todos.list_all(true, None).await.unwrap(), before);
+    assert_eq!(before[0].workspace_id, Some(work.id));
+}
+
+#[tokio::test]
+async fn import_with_a_missing_workspace_writes_nothing() {
+    let conn = init_memory_database().await.unwrap();
+    let todos = TodoService::new(conn.clone());
+    let workspaces = WorkspaceService::new(conn);
+
+    let work = workspaces.create("work").await.unwrap();
+    todos
+        .add("Standup", Some(day(1)), None, Some(work.id), None, None)
+        .await
+        .unwrap();
+
+    let json = export(&todos).await;
+
+    let target = TodoService::new(init_memory_database().await.unwrap());
+
+    assert!(
+        target
+            .import(parse(&json), ImportMode::Merge)
+            .await
+            .is_err()
+    );
+    assert_eq!(target.count().await.unwrap(), 0);
+}
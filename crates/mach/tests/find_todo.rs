@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use machich::entity::todo;
+use machich::service::{
+    connection::init_memory_database, todo::TodoService, workspace::WorkspaceService,
+};
+use uuid::Uuid;
+
+struct Fixture {
+    todos: TodoService,
+    home: Uuid,
+    work: Uuid,
+}
+
+async fn fixture() -> Fixture {
+    let conn = init_memory_database().await.unwrap();
+    let workspaces = WorkspaceService::new(conn.clone());
+
+    Fixture {
+        todos: TodoService::new(conn),
+        home: workspaces.create("home").await.unwrap().id,
+        work: workspaces.create("work").await.unwrap().id,
+    }
+}
+
+async fn add(todos: &TodoService, title: &str, workspace: Option<Uuid>) -> todo::Model {
+    todos
+        .add(
+            title,
+            NaiveDate::from_ymd_opt(2026, 6, 1),
+            None,
+            workspace,
+            None,
+            None,
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn a_match_in_the_active_workspace_wins() {
+    let f = fixture().await;
+
+    let at_home = add(&f.todos, "Pay bills", Some(f.home)).await;
+    let at_work = add(&f.todos, "Pay bills", Some(f.work)).await;
+
+    let found = f
+        .todos
+        .find_by_title_or_id("Pay bills", Some(f.home))
+        .await
+        .unwrap();
+
+    assert_eq!(found.map(|t| t.id), Some(at_home.id));
+
+    let found = f
+        .todos
+        .find_by_title_or_id("Pay bills", Some(f.work))
+        .await
+        .unwrap();
+
+    assert_eq!(found.map(|t| t.id), Some(at_work.id));
+}
+
+#[tokio::test]
+async fn falls_back_to_other_workspaces() {
+    let f = fixture().await;
+
+    let at_work = add(&f.todos, "Standup", Some(f.work)).await;
+
+    let found = f
+        .todos
+        .find_by_title_or_id("Standup", Some(f.home))
+        .await
+        .unwrap();
+
+    assert_eq!(found.map(|t| t.id), Some(at_work.id));
+}
+
+#[tokio::test]
+async fn an_ambiguous_title_outside_the_workspace_is_an_error() {
+    let f = fixture().await;
+
+    add(&f.todos, "Pay bills", Some(f.home)).await;
+    add(&f.todos, "Pay bills", None).await;
+
+    assert!(
+        f.todos
+            .find_by_title_or_id("Pay bills", Some(f.work))
+            .await
+            .is_err()
+    );
+    assert!(
+        f.todos
+            .find_by_title_or_id("Pay bills", None)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn ids_match_in_any_workspace() {
+    let f = fixture().await;
+
+    let at_work = add(&f.todos, "Standup", Some(f.work)).await;
+    add(&f.todos, &at_work.id.to_string(), Some(f.home)).await;
+
+    let found = f
+        .todos
+        .find_by_title_or_id(&at_work.id.to_string(), Some(f.home))
+        .await
+        .unwrap();
+
+    assert_eq!(found.map(|t| t.id), Some(at_work.id));
+}
+
+#[tokio::test]
+async fn nothing_found_is_none() {
+    let f = fixture().await;
+
+    add(&f.todos, "Standup", Some(f.work)).await;
+
+    let found = f
+        .todos
+        .find_by_title_or_id("Retro", Some(f.work))
+        .await
+        .unwrap();
+
+    assert!(found.is_none());
+}
+
+#[tokio::test]
+async fn archived_todos_are_not_found() {
+    let f = fixture().await;
+
+    let archived = add(&f.todos, "Old errand", Some(f.home)).await;
+
+    f.todos.archive(archived.id).await.unwrap();
+
+    for reference in ["Old errand".to_string(), archived.id.to_string()] {
+        let found = f
+            .todos
+            .find_by_title_or_id(&reference, Some(f.home))
+            .await
+            .unwrap();
+
+        assert!(found.is_none(), "'{reference}'");
+    }
+}
+
+#[tokio::test]
+async fn an_archived_twin_does_not_make_a_title_ambiguous() {
+    let f = fixture().await;
+
+    let archived = add(&f.todos, "Standup", None).await;
+    let live = add(&f.todos, "Standup", None).await;
+
+    f.todos.archive(archived.id).await.unwrap();
+
+    let found = f.todos.find_by_title_or_id("Standup", None).await.unwrap();
+
+    assert_eq!(found.map(|t| t.id), Some(live.id));
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use machich::service::{
+    connection::init_memory_database,
+    todo::{ListOptions, MovePlacement, NewTodo, ProjectFilter, TodoService},
+};
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+}
+
+async fn service() -> TodoService {
+    TodoService::new(init_memory_database().await.unwrap())
+}
+
+async fn add(todos: &TodoService, title: &str, date: Option<NaiveDate>) {
+    todos
+        .add_with_placement(title, date, MovePlacement::Bottom, NewTodo::default())
+        .await
+        .unwrap();
+}
+
+async fn range(todos: &TodoService, from: NaiveDate, to: NaiveDate) -> Vec<String> {
+    todos
+        .list_range(from, to, true, ProjectFilter::Any)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.title)
+        .collect()
+}
+
+#[tokio::test]
+async fn both_ends_are_included() {
+    let todos = service().await;
+
+    add(&todos, "before", Some(day(1))).await;
+    add(&todos, "first", Some(day(2))).await;
+    add(&todos, "middle", Some(day(3))).await;
+    add(&todos, "last", Some(day(4))).await;
+    add(&todos, "after", Some(day(5))).await;
+    add(&todos, "someday", None).await;
+
+    assert_eq!(
+        range(&todos, day(2), day(4)).await,
+        ["first", "middle", "last"]
+    );
+    assert_eq!(range(&todos, day(3), day(3)).await, ["middle"]);
+}
+
+#[tokio::test]
+async fn empty_and_backwards_ranges_list_nothing() {
+    let todos = service().await;
+
+    add(&todos, "first", Some(day(2))).await;
+    add(&todos, "last", Some(day(4))).await;
+
+    assert!(range(&todos, day(10), day(20)).await.is_empty());
+    assert!(range(&todos, day(4), day(2)).await.is_empty());
+}
+
+#[tokio::test]
+async fn ordered_by_day_then_column_with_done_last_in_each_day() {
+    let todos = service().await;
+
+    // Added out of day order, so insertion order can't pass for day order.
+    add(&todos, "3a", Some(day(3))).await;
+    add(&todos, "2a", Some(day(2))).await;
+    add(&todos, "2b", Some(day(2))).await;
+    add(&todos, "3b", Some(day(3))).await;
+
+    let done = todos
+        .add("2 done", Some(day(2)), None, None, None, None)
+        .await
+        .unwrap();
+
+    todos.mark_done(done.id, day(2)).await.unwrap();
+
+    assert_eq!(
+        range(&todos, day(1), day(5)).await,
+        ["2a", "2b", "2 done", "3a", "3b"]
+    );
+
+    let pending: Vec<String> = todos
+        .list_range(day(1), day(5), false, ProjectFilter::Any)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.title)
+        .collect();
+
+    assert_eq!(pending, ["2a", "2b", "3a", "3b"]);
+}
+
+#[tokio::test]
+async fn single_day_lists_are_unchanged() {
+    let todos = service().await;
+
+    add(&todos, "a", Some(day(2))).await;
+    add(&todos, "b", Some(day(2))).await;
+    add(&todos, "other day", Some(day(3))).await;
+
+    let titles: Vec<String> = todos
+        .list(ListOptions::today(day(2)))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.title)
+        .collect();
+
+    assert_eq!(titles, ["a", "b"]);
+}
@@ -0,0 +1,242 @@
+use machich::service::{
+    Services,
+    config::TodoTemplate,
+    connection::init_memory_database,
+    project::ProjectService,
+    todo::{ListOptions, ListScope, ProjectFilter, SortKey, TagFilter, TodoService},
+    workspace::WorkspaceService,
+};
+use uuid::Uuid;
+
+async fn setup() -> (ProjectService, Uuid) {
+    let conn = init_memory_database().await.unwrap();
+    let workspace = WorkspaceService::new(conn.clone())
+        .create("work")
+        .await
+        .unwrap();
+
+    (ProjectService::new(conn), workspace.id)
+}
+
+/// Projects, todos and workspaces over one database.
+async fn setup_with_todos() -> (ProjectService, TodoService, WorkspaceService) {
+    let conn = init_memory_database().await.unwrap();
+
+    (
+        ProjectService::new(conn.clone()),
+        TodoService::new(conn.clone()),
+        WorkspaceService::new(conn),
+    )
+}
+
+async fn add_to(todos: &TodoService, title: &str, project: Option<Uuid>) -> Uuid {
+    todos
+        .add(title, None, None, None, project, None)
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn names_match_whatever_the_case_or_spacing() {
+    let (projects, ws) = setup().await;
+
+    let app = projects.create("MyApp", ws, "active").await.unwrap();
+
+    for name in ["MyApp", "myapp", "MYAPP", "  myapp ", "\tMyApp\n"] {
+        let found = projects.find_by_name_or_id(name).await.unwrap();
+
+        assert_eq!(found.map(|p| p.id), Some(app.id), "'{name}'");
+    }
+
+    assert!(
+        projects
+            .find_by_name_or_id("my app")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn ids_still_match() {
+    let (projects, ws) = setup().await;
+
+    let app = projects.create("MyApp", ws, "active").await.unwrap();
+    let found = projects
+        .find_by_name_or_id(&app.id.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(found.map(|p| p.id), Some(app.id));
+}
+
+#[tokio::test]
+async fn names_outside_ascii_match_themselves() {
+    let (projects, ws) = setup().await;
+
+    let uber = projects.create("Über", ws, "active").await.unwrap();
+
+    for name in ["Über", " Über ", "ÜBER"] {
+        let found = projects.find_by_name_or_id(name).await.unwrap();
+
+        assert_eq!(found.map(|p| p.id), Some(uber.id), "'{name}'");
+    }
+}
+
+#[tokio::test]
+async fn names_are_stored_trimmed_with_their_case() {
+    let (projects, ws) = setup().await;
+
+    let app = projects.create("  MyApp  ", ws, "active").await.unwrap();
+
+    assert_eq!(app.name, "MyApp");
+}
+
+#[tokio::test]
+async fn a_variant_of_an_existing_name_is_refused() {
+    let (projects, ws) = setup().await;
+
+    projects.create("MyApp", ws, "active").await.unwrap();
+
+    assert!(projects.create("myapp", ws, "active").await.is_err());
+    assert!(projects.create(" MYAPP ", ws, "active").await.is_err());
+    assert!(projects.create("   ", ws, "active").await.is_err());
+    assert_eq!(projects.list().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn renaming_checks_other_projects_only() {
+    let (projects, ws) = setup().await;
+
+    let app = projects.create("MyApp", ws, "active").await.unwrap();
+    let site = projects.create("Site", ws, "active").await.unwrap();
+
+    // Changing only the case of its own name is fine.
+    let renamed = projects.update_name(app.id, " myapp ").await.unwrap();
+
+    assert_eq!(renamed.name, "myapp");
+    assert!(projects.update_name(site.id, "MYAPP").await.is_err());
+}
+
+#[tokio::test]
+async fn deleting_with_reassign_moves_todos_and_their_workspace() {
+    let (projects, todos, workspaces) = setup_with_todos().await;
+    let work = workspaces.create("work").await.unwrap();
+    let home = workspaces.create("home").await.unwrap();
+
+    let old = projects.create("Old", work.id, "active").await.unwrap();
+    let new = projects.create("New", home.id, "active").await.unwrap();
+
+    let a = add_to(&todos, "a", Some(old.id)).await;
+    let b = add_to(&todos, "b", Some(old.id)).await;
+    let other = add_to(&todos, "other", None).await;
+
+    todos.archive(b).await.unwrap();
+
+    assert_eq!(todos.count_by_project(old.id).await.unwrap(), 2);
+    assert_eq!(todos.reassign_project(old.id, Some(&new)).await.unwrap(), 2);
+    assert!(projects.delete(old.id).await.unwrap());
+
+    for id in [a, b] {
+        let todo = todos.get(id).await.unwrap();
+
+        assert_eq!(todo.project_id, Some(new.id));
+        assert_eq!(todo.workspace_id, Some(home.id));
+    }
+
+    assert_eq!(todos.get(other).await.unwrap().project_id, None);
+    assert_eq!(todos.count_by_project(old.id).await.unwrap(), 0);
+    assert!(projects.get(old.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn deleting_with_clear_detaches_todos() {
+    let (projects, todos, workspaces) = setup_with_todos().await;
+    let ws = workspaces.create("work").await.unwrap().id;
+
+    let old = projects.create("Old", ws, "active").await.unwrap();
+    let keep = projects.create("Keep", ws, "active").await.unwrap();
+
+    let a = add_to(&todos, "a", Some(old.id)).await;
+    let kept = add_to(&todos, "kept", Some(keep.id)).await;
+
+    assert_eq!(todos.reassign_project(old.id, None).await.unwrap(), 1);
+    assert!(projects.delete(old.id).await.unwrap());
+
+    assert_eq!(todos.get(a).await.unwrap().project_id, None);
+    assert_eq!(todos.get(kept).await.unwrap().project_id, Some(keep.id));
+    assert_eq!(todos.count_by_project(keep.id).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn renaming_keeps_todos_and_templates_with_the_project() {
+    let services = Services::from_connection(init_memory_database().await.unwrap())
+        .await
+        .unwrap();
+    let ws = services.workspaces.create("work").await.unwrap().id;
+
+    let old = services.projects.create("Old", ws, "active").await.unwrap();
+    let a = add_to(&services.todos, "a", Some(old.id)).await;
+    let b = add_to(&services.todos, "b", Some(old.id)).await;
+
+    services
+        .config
+        .save_template(
+            "bug",
+            TodoTemplate {
+                project: Some("old".to_string()),
+                ..TodoTemplate::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let (renamed, templates) = services.rename_project(old.id, "New").await.unwrap();
+
+    assert_eq!(renamed.id, old.id);
+    assert_eq!(renamed.name, "New");
+    assert_eq!(templates, 1);
+
+    let found = services
+        .projects
+        .find_by_name_or_id("new")
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(found.id, old.id);
+    assert!(
+        services
+            .projects
+            .find_by_name_or_id("Old")
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let mut listed: Vec<Uuid> = services
+        .todos
+        .list(ListOptions {
+            scope: ListScope::Backlog,
+            include_done: false,
+            project: ProjectFilter::Equals(found.id),
+            tag: TagFilter::Any,
+            sort: SortKey::Manual,
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.id)
+        .collect();
+    let mut expected = vec![a, b];
+
+    listed.sort();
+    expected.sort();
+
+    assert_eq!(listed, expected);
+
+    let template = services.config.load_template("bug").await.unwrap().unwrap();
+
+    assert_eq!(template.project.as_deref(), Some("New"));
+}
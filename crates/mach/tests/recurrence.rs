@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use machich::service::{
+    connection::init_memory_database, recurrence::Recurrence, todo::TodoService,
+};
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+}
+
+async fn weekly_todo(todos: &TodoService) -> machich::entity::todo::Model {
+    todos
+        .add(
+            "Water plants",
+            Some(day(1)),
+            None,
+            None,
+            None,
+            Some(Recurrence::Weekly),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn mark_done_spawns_the_next_occurrence() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let todo = weekly_todo(&todos).await;
+
+    let done = todos.mark_done(todo.id, day(1)).await.unwrap();
+
+    assert_eq!(done.recurrence, None);
+
+    let all = todos.list_all(true, None).await.unwrap();
+    let next = all.iter().find(|t| t.id != todo.id).unwrap();
+
+    assert_eq!(all.len(), 2);
+    assert_eq!(next.scheduled_for, Some(day(8)));
+    assert_eq!(next.recurrence.as_deref(), Some("weekly"));
+}
+
+#[tokio::test]
+async fn mark_pending_takes_back_the_spawned_copy() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let todo = weekly_todo(&todos).await;
+
+    todos.mark_done(todo.id, day(1)).await.unwrap();
+    let reopened = todos.mark_pending(todo.id, true).await.unwrap();
+
+    assert_eq!(reopened.recurrence.as_deref(), Some("weekly"));
+    assert_eq!(todos.list_all(true, None).await.unwrap().len(), 1);
+
+    // Finishing it again spawns exactly one copy.
+    todos.mark_done(todo.id, day(1)).await.unwrap();
+
+    assert_eq!(todos.list_all(true, None).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn restore_undoes_the_spawn() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let todo = weekly_todo(&todos).await;
+
+    todos.mark_done(todo.id, day(1)).await.unwrap();
+    let restored = todos.restore(todo.clone()).await.unwrap();
+
+    assert_eq!(restored.status, "pending");
+    assert_eq!(restored.recurrence.as_deref(), Some("weekly"));
+    assert_eq!(todos.list_all(true, None).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn a_finished_copy_is_left_alone() {
+    let todos = TodoService::new(init_memory_database().await.unwrap());
+    let todo = weekly_todo(&todos).await;
+
+    todos.mark_done(todo.id, day(1)).await.unwrap();
+
+    let next = todos
+        .list_all(true, None)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|t| t.id != todo.id)
+        .unwrap();
+
+    todos.mark_done(next.id, day(8)).await.unwrap();
+
+    let reopened = todos.mark_pending(todo.id, true).await.unwrap();
+
+    assert_eq!(reopened.recurrence, None);
+    assert!(todos.get(next.id).await.is_ok());
+}
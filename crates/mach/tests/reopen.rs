@@ -0,0 +1,112 @@
+use chrono::NaiveDate;
+use machich::service::{
+    connection::init_memory_database,
+    todo::{ListOptions, MovePlacement, NewTodo, ReorderDirection, TodoService},
+};
+use uuid::Uuid;
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 6, d).unwrap()
+}
+
+async fn service() -> TodoService {
+    TodoService::new(init_memory_database().await.unwrap())
+}
+
+/// Add `titles` to the bottom of day 1, in order.
+async fn column(todos: &TodoService, titles: &[&str]) -> Vec<Uuid> {
+    let mut ids = Vec::new();
+
+    for title in titles {
+        let todo = todos
+            .add_with_placement(
+                *title,
+                Some(day(1)),
+                MovePlacement::Bottom,
+                NewTodo::default(),
+            )
+            .await
+            .unwrap();
+
+        ids.push(todo.id);
+    }
+
+    ids
+}
+
+/// Pending titles of `date`, top to bottom.
+async fn pending(todos: &TodoService, date: NaiveDate) -> Vec<String> {
+    todos
+        .list(ListOptions::today(date))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.title)
+        .collect()
+}
+
+#[tokio::test]
+async fn keep_position_returns_to_the_old_slot() {
+    let todos = service().await;
+    let ids = column(&todos, &["a", "b", "c"]).await;
+
+    todos.mark_done(ids[1], day(1)).await.unwrap();
+
+    assert_eq!(pending(&todos, day(1)).await, ["a", "c"]);
+
+    todos.mark_pending(ids[1], true).await.unwrap();
+
+    assert_eq!(pending(&todos, day(1)).await, ["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn without_keep_position_it_goes_to_the_top() {
+    let todos = service().await;
+    let ids = column(&todos, &["a", "b", "c"]).await;
+
+    todos.mark_done(ids[2], day(1)).await.unwrap();
+    todos.mark_pending(ids[2], false).await.unwrap();
+
+    assert_eq!(pending(&todos, day(1)).await, ["c", "a", "b"]);
+}
+
+#[tokio::test]
+async fn keep_position_survives_a_reorder_in_between() {
+    let todos = service().await;
+    let ids = column(&todos, &["a", "b", "c"]).await;
+
+    todos.mark_done(ids[0], day(1)).await.unwrap();
+    todos.reorder(ids[2], ReorderDirection::Up).await.unwrap();
+    todos.mark_pending(ids[0], true).await.unwrap();
+
+    assert_eq!(pending(&todos, day(1)).await, ["a", "c", "b"]);
+}
+
+#[tokio::test]
+async fn a_finished_backlog_todo_reopens_at_the_bottom() {
+    let todos = service().await;
+    column(&todos, &["a", "b"]).await;
+
+    let someday = todos
+        .add("someday", None, None, None, None, None)
+        .await
+        .unwrap();
+
+    // Finishing a backlog todo files it under today, with no slot there.
+    todos.mark_done(someday.id, day(1)).await.unwrap();
+    todos.mark_pending(someday.id, true).await.unwrap();
+
+    assert_eq!(pending(&todos, day(1)).await, ["a", "b", "someday"]);
+}
+
+#[tokio::test]
+async fn reopening_forgets_the_slot() {
+    let todos = service().await;
+    let ids = column(&todos, &["a", "b"]).await;
+
+    todos.mark_done(ids[0], day(1)).await.unwrap();
+    let reopened = todos.mark_pending(ids[0], true).await.unwrap();
+
+    assert!(reopened.metadata.get("prev_order_index").is_none());
+    assert_eq!(reopened.completed_at, None);
+}
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use machich::service::connection::init_database;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+struct TempDb(PathBuf);
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[tokio::test]
+async fn unknown_columns_only_warn_by_default() {
+    let db = TempDb(std::env::temp_dir().join(format!("mach-test-{}.db", Uuid::new_v4())));
+
+    let conn = init_database(&db.0).await.unwrap();
+
+    // What a newer mach would have added.
+    conn.execute_unprepared("ALTER TABLE todos ADD COLUMN from_the_future TEXT")
+        .await
+        .unwrap();
+
+    drop(conn);
+
+    assert!(init_database(&db.0).await.is_ok());
+}